@@ -42,15 +42,56 @@
 //! The state space is theoretically infinite (counters can grow arbitrarily large),
 //! but the automaton remains deterministic and efficiently processable.
 //!
+//! # Tolerant Balance Checking
+//!
+//! [`CounterAutomatonBlueprint::with_tolerance`] creates a [`TolerantCounterBlueprint`], which
+//! relaxes the classic language: symbols may appear in any order, and a word is accepted when
+//! the final balance (occurrences of the first symbol minus occurrences of the second) lies in
+//! a given range. For example `0..=2` accepts words with up to two more first symbols than
+//! second symbols. Its signed balance is held in a separate [`TolerantCounterState`], so the
+//! states of the classic language are unchanged.
+//!
+//! ```
+//! use deterministic_automata::{DeterministicAutomatonBlueprint, BasicStateSort};
+//! use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+//!
+//! let at_least_as_many_opens = CounterAutomatonBlueprint::with_tolerance('(', ')', 0..=i64::MAX);
+//!
+//! assert_eq!(at_least_as_many_opens.characterise(&['(', '(', ')']).unwrap(), BasicStateSort::Accept);
+//! assert_eq!(at_least_as_many_opens.characterise(&[')', '(']).unwrap(), BasicStateSort::Accept);
+//! assert_eq!(at_least_as_many_opens.characterise(&[')']).unwrap(), BasicStateSort::Reject);
+//! ```
+//!
+//! # Detailed Classification
+//!
+//! [`CounterAutomatonBlueprint::detailed`] turns a blueprint into a
+//! [`DetailedCounterAutomatonBlueprint`], whose [`CounterSort`] reports how far from balanced
+//! an input is instead of just Accept/Reject. This is useful for error messages in bracket
+//! checkers built on top of the counter automaton.
+//!
+//! ```
+//! use deterministic_automata::DeterministicAutomatonBlueprint;
+//! use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterSort};
+//!
+//! let brackets = CounterAutomatonBlueprint::new('(', ')').detailed();
+//!
+//! assert_eq!(brackets.characterise(&['(', '(', ')']).unwrap(), CounterSort::PendingOpen(1));
+//! assert_eq!(brackets.characterise(&['(', ')']).unwrap(), CounterSort::Balanced);
+//! assert_eq!(brackets.characterise(&[')']).unwrap(), CounterSort::Rejected);
+//! ```
+//!
 //! # Framework Benefits
 //!
 //! This example showcases several advantages of the framework:
 //! - **Expressiveness**: Can handle non-regular languages
 //! - **Determinism**: No backtracking or ambiguity in state transitions
 //! - **Composability**: Can be combined with other automata using product operations
-//! - **Type Safety**: Counter overflow could be caught at runtime depending on build configuration
+//! - **Type Safety**: Counter overflow is reported as an error, or clamped when configured
+//!   with [`CounterOverflowPolicy::Saturate`]
+
+use std::ops::RangeInclusive;
 
-use crate::{DeterministicAutomatonBlueprint, BasicStateSort};
+use crate::{DeterministicAutomatonBlueprint, BasicStateSort, DescribableAutomaton};
 
 /// A blueprint for an automaton that recognizes the language a^n b^n.
 ///
@@ -61,7 +102,8 @@ use crate::{DeterministicAutomatonBlueprint, BasicStateSort};
 #[derive(Debug, Clone, PartialEq)]
 pub struct CounterAutomatonBlueprint<Alphabet> {
     first: Alphabet,
-    second: Alphabet
+    second: Alphabet,
+    overflow: CounterOverflowPolicy
 }
 
 impl<Alphabet> CounterAutomatonBlueprint<Alphabet> {
@@ -72,15 +114,113 @@ impl<Alphabet> CounterAutomatonBlueprint<Alphabet> {
     /// * `first` - The symbol that must appear first (the 'a' in a^n b^n)
     /// * `second` - The symbol that must appear second (the 'b' in a^n b^n)
     pub fn new(first: Alphabet, second: Alphabet) -> Self {
-        Self { first, second }
+        Self { first, second, overflow: CounterOverflowPolicy::Error }
+    }
+
+    /// Creates a counter automaton blueprint accepting when the final balance lies in a range.
+    ///
+    /// This is a shorthand for [`TolerantCounterBlueprint::new`]; see there for the semantics.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - The symbol that increments the balance
+    /// * `second` - The symbol that decrements the balance
+    /// * `accept_range` - The final balances that are accepted
+    pub fn with_tolerance(first: Alphabet, second: Alphabet, accept_range: RangeInclusive<i64>) -> TolerantCounterBlueprint<Alphabet> {
+        TolerantCounterBlueprint::new(first, second, accept_range)
+    }
+
+    /// Sets how the counter behaves when it would overflow.
+    ///
+    /// Blueprints use [`CounterOverflowPolicy::Error`] unless configured otherwise. See
+    /// [`CounterOverflowPolicy`] for the semantics of each policy.
+    ///
+    /// # Parameters
+    ///
+    /// * `policy` - The behaviour when the counter reaches the bounds of its integer type
+    pub fn with_overflow_policy(self, policy: CounterOverflowPolicy) -> Self {
+        Self { overflow: policy, ..self }
+    }
+
+    /// Converts this blueprint into one reporting a [`CounterSort`] instead of a [`BasicStateSort`].
+    ///
+    /// The resulting blueprint has the same states and transitions; only the classification
+    /// differs. See [`DetailedCounterAutomatonBlueprint`].
+    pub fn detailed(self) -> DetailedCounterAutomatonBlueprint<Alphabet> {
+        DetailedCounterAutomatonBlueprint { inner: self }
     }
 }
 
+impl<Alphabet: PartialEq> CounterAutomatonBlueprint<Alphabet> {
+    /// Classifies `word` and returns the total cost of the run alongside the classification.
+    ///
+    /// Each symbol read contributes `cost(symbol)` to the total, so the result is the cost of
+    /// the single deterministic path the word takes. This is the simplest instance of a
+    /// weighted automaton: with only one run per word, the minimum-cost interpretation reduces
+    /// to summing along that run. The total saturates at `u64::MAX` rather than overflowing.
+    ///
+    /// # Parameters
+    ///
+    /// * `word` - The input to classify
+    /// * `cost` - The cost charged for reading each symbol
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::BasicStateSort;
+    /// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+    ///
+    /// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    /// let cost = |c: &char| if *c == 'a' { 1 } else { 2 };
+    ///
+    /// assert_eq!(blueprint.cost_characterise(&['a', 'a', 'b', 'b'], cost).unwrap(), (BasicStateSort::Accept, 6));
+    /// assert_eq!(blueprint.cost_characterise(&['b', 'a'], cost).unwrap(), (BasicStateSort::Reject, 3));
+    /// ```
+    pub fn cost_characterise<F: Fn(&Alphabet) -> u64>(&self, word: &[Alphabet], cost: F) -> Result<(BasicStateSort, u64), String> {
+        cost_run(self, word, cost)
+    }
+}
+
+/// Runs `blueprint` on `word`, summing `cost` over the symbols read and saturating at `u64::MAX`.
+fn cost_run<Blueprint, F>(blueprint: &Blueprint, word: &[Blueprint::Alphabet], cost: F) -> Result<(Blueprint::StateSort, u64), Blueprint::ErrorType>
+where
+    Blueprint: DeterministicAutomatonBlueprint,
+    F: Fn(&Blueprint::Alphabet) -> u64
+{
+    let mut state = blueprint.initial_state();
+    let mut total: u64 = 0;
+    for character in word {
+        state = blueprint.transition_map(&state, character)?;
+        total = total.saturating_add(cost(character));
+    }
+    Ok((blueprint.state_sort_map(&state)?, total))
+}
+
+/// How a counter automaton handles its counter reaching the bounds of its type.
+///
+/// The counter in [`CounterState::Start`] is a `usize`, and the balance of a
+/// [`TolerantCounterState`] is an `i64`. Words long enough to exceed these are rarely seen in
+/// practice, but streaming input can in principle reach them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CounterOverflowPolicy {
+    /// Overflowing the counter returns an error from the transition.
+    #[default]
+    Error,
+
+    /// The counter clamps at the bound of its type instead of overflowing.
+    ///
+    /// Under this policy the automaton no longer tracks the exact count once clamped, so
+    /// words beyond the bound may be classified as if they were shorter. For the classic
+    /// language, a clamped `Start(usize::MAX)` can only become balanced after `usize::MAX`
+    /// second symbols, so in practice such words are rejected.
+    Saturate
+}
+
 /// The state type for the counter automaton.
 ///
 /// This enum represents the different phases of processing input in the a^n b^n
 /// language recognizer, with states carrying counter information.
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum CounterState {
     /// Reading the first symbol ('a'), counting occurrences.
     ///
@@ -126,7 +266,11 @@ where
         Ok(match state {
             CounterState::Start(counter) => {
                 if *character == self.first {
-                    CounterState::Start(counter+1)
+                    CounterState::Start(match (counter.checked_add(1), self.overflow) {
+                        (Some(next), _) => next,
+                        (None, CounterOverflowPolicy::Saturate) => usize::MAX,
+                        (None, CounterOverflowPolicy::Error) => return Err("Counter overflow".to_string()),
+                    })
                 } else if *character == self.second && *counter > 0 {
                     CounterState::End(*counter - 1)
                 } else {
@@ -143,4 +287,276 @@ where
             CounterState::Reject => CounterState::Reject,
         })
     }
-}
\ No newline at end of file
+}
+
+/// The longest example [`TolerantCounterBlueprint::example_accepted`] builds.
+///
+/// Tolerance ranges far from zero would otherwise require allocating words of up to
+/// `i64::MAX` symbols, which is neither cheap nor possible.
+pub const MAX_EXAMPLE_LENGTH: u64 = 1 << 16;
+
+impl<Alphabet> DescribableAutomaton for CounterAutomatonBlueprint<Alphabet>
+where
+    Alphabet: PartialEq + Clone
+{
+    /// Returns the empty word, which the classic language always accepts.
+    fn example_accepted(&self) -> Option<Vec<Alphabet>> {
+        Some(Vec::new())
+    }
+}
+
+/// A blueprint for a counter automaton accepting when the final balance lies in a range.
+///
+/// The balance is the number of first symbols minus the number of second symbols, tracked as
+/// a signed counter. Unlike [`CounterAutomatonBlueprint`], symbols may appear in any order and
+/// reading the second symbol before the first does not reject; only the final balance
+/// matters. Any other symbol rejects.
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) or [`CounterAutomatonBlueprint::with_tolerance`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TolerantCounterBlueprint<Alphabet> {
+    first: Alphabet,
+    second: Alphabet,
+    accept_range: RangeInclusive<i64>,
+    overflow: CounterOverflowPolicy
+}
+
+impl<Alphabet> TolerantCounterBlueprint<Alphabet> {
+    /// Creates a counter automaton blueprint accepting when the final balance lies in a range.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - The symbol that increments the balance
+    /// * `second` - The symbol that decrements the balance
+    /// * `accept_range` - The final balances that are accepted
+    pub fn new(first: Alphabet, second: Alphabet, accept_range: RangeInclusive<i64>) -> Self {
+        Self { first, second, accept_range, overflow: CounterOverflowPolicy::Error }
+    }
+
+    /// Sets how the balance behaves when it would overflow.
+    ///
+    /// Blueprints use [`CounterOverflowPolicy::Error`] unless configured otherwise.
+    ///
+    /// # Parameters
+    ///
+    /// * `policy` - The behaviour when the balance reaches the bounds of `i64`
+    pub fn with_overflow_policy(self, policy: CounterOverflowPolicy) -> Self {
+        Self { overflow: policy, ..self }
+    }
+
+    /// Converts this blueprint into one reporting a [`CounterSort`] instead of a [`BasicStateSort`].
+    ///
+    /// See [`DetailedTolerantCounterBlueprint`].
+    pub fn detailed(self) -> DetailedTolerantCounterBlueprint<Alphabet> {
+        DetailedTolerantCounterBlueprint { inner: self }
+    }
+}
+
+impl<Alphabet: PartialEq> TolerantCounterBlueprint<Alphabet> {
+    /// Classifies `word` and returns the total cost of the run alongside the classification.
+    ///
+    /// See [`CounterAutomatonBlueprint::cost_characterise`].
+    pub fn cost_characterise<F: Fn(&Alphabet) -> u64>(&self, word: &[Alphabet], cost: F) -> Result<(BasicStateSort, u64), String> {
+        cost_run(self, word, cost)
+    }
+}
+
+/// The state type for a [`TolerantCounterBlueprint`].
+///
+/// Holds the signed balance of first symbols over second symbols, until an unrecognized
+/// symbol rejects the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TolerantCounterState(TolerantPhase);
+
+/// The phases of a [`TolerantCounterState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TolerantPhase {
+    Balance(i64),
+    Reject
+}
+
+impl TolerantCounterState {
+    /// The state after an unrecognized symbol has been read.
+    pub const REJECT: Self = Self(TolerantPhase::Reject);
+
+    /// Creates the state with the given balance.
+    pub fn with_balance(balance: i64) -> Self {
+        Self(TolerantPhase::Balance(balance))
+    }
+
+    /// Returns the balance, or `None` once an unrecognized symbol has been read.
+    pub fn balance(&self) -> Option<i64> {
+        match self.0 {
+            TolerantPhase::Balance(balance) => Some(balance),
+            TolerantPhase::Reject => None,
+        }
+    }
+}
+
+impl<Alphabet> DeterministicAutomatonBlueprint for TolerantCounterBlueprint<Alphabet>
+where
+    Alphabet: PartialEq
+{
+    type State = TolerantCounterState;
+    type Alphabet = Alphabet;
+    type StateSort = BasicStateSort;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        TolerantCounterState::with_balance(0)
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort,Self::ErrorType> {
+        Ok(BasicStateSort::from(state.balance().is_some_and(|balance| self.accept_range.contains(&balance))))
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        let Some(balance) = state.balance() else {
+            return Ok(TolerantCounterState::REJECT);
+        };
+        let step = if *character == self.first {
+            1
+        } else if *character == self.second {
+            -1
+        } else {
+            return Ok(TolerantCounterState::REJECT);
+        };
+        Ok(TolerantCounterState::with_balance(match self.overflow {
+            CounterOverflowPolicy::Saturate => balance.saturating_add(step),
+            CounterOverflowPolicy::Error => balance.checked_add(step).ok_or("Counter overflow".to_string())?,
+        }))
+    }
+}
+
+impl<Alphabet> DescribableAutomaton for TolerantCounterBlueprint<Alphabet>
+where
+    Alphabet: PartialEq + Clone
+{
+    /// Returns the empty word when zero is in the accept range. Otherwise returns the shortest
+    /// run of a single symbol reaching the range, or `None` if that would exceed
+    /// [`MAX_EXAMPLE_LENGTH`] symbols.
+    fn example_accepted(&self) -> Option<Vec<Alphabet>> {
+        let range = &self.accept_range;
+        let (symbol, length) = if range.contains(&0) {
+            return Some(Vec::new());
+        } else if *range.start() > 0 {
+            (&self.first, range.start().unsigned_abs())
+        } else {
+            (&self.second, range.end().unsigned_abs())
+        };
+        if range.is_empty() || length > MAX_EXAMPLE_LENGTH {
+            return None;
+        }
+        Some(vec![symbol.clone(); usize::try_from(length).ok()?])
+    }
+}
+
+/// A detailed classification of counter automaton states.
+///
+/// Reports how unbalanced an input is rather than only whether it is accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CounterSort {
+    /// Every first symbol has been matched by a second symbol.
+    Balanced,
+
+    /// The given number of first symbols are still waiting for a matching second symbol.
+    ///
+    /// For tolerant blueprints the count saturates at `usize::MAX` on targets where `usize`
+    /// cannot hold every `i64` balance.
+    PendingOpen(usize),
+
+    /// The given number of second symbols have no matching first symbol.
+    ///
+    /// Only reachable for a [`TolerantCounterBlueprint`]; the classic language rejects as soon
+    /// as a second symbol is unmatched. The count saturates like that of
+    /// [`PendingOpen`](Self::PendingOpen).
+    PendingClose(usize),
+
+    /// The input can no longer be balanced, e.g. a symbol appeared out of order or an
+    /// unrecognized symbol was read.
+    Rejected
+}
+
+/// A counter automaton blueprint classifying states by [`CounterSort`].
+///
+/// Shares states and transitions with the wrapped [`CounterAutomatonBlueprint`].
+///
+/// # Construction
+///
+/// Use [`CounterAutomatonBlueprint::detailed`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetailedCounterAutomatonBlueprint<Alphabet> {
+    inner: CounterAutomatonBlueprint<Alphabet>
+}
+
+impl<Alphabet> DeterministicAutomatonBlueprint for DetailedCounterAutomatonBlueprint<Alphabet>
+where
+    Alphabet: PartialEq
+{
+    type State = CounterState;
+    type Alphabet = Alphabet;
+    type StateSort = CounterSort;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        self.inner.initial_state()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort,Self::ErrorType> {
+        Ok(match state {
+            CounterState::Start(0) | CounterState::End(0) => CounterSort::Balanced,
+            CounterState::Start(x) | CounterState::End(x) => CounterSort::PendingOpen(*x),
+            CounterState::Reject => CounterSort::Rejected,
+        })
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        self.inner.transition_map(state, character)
+    }
+}
+
+/// A tolerant counter automaton blueprint classifying states by [`CounterSort`].
+///
+/// Shares states and transitions with the wrapped [`TolerantCounterBlueprint`]. The sort
+/// reflects the signed balance, independently of the configured accept range.
+///
+/// # Construction
+///
+/// Use [`TolerantCounterBlueprint::detailed`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetailedTolerantCounterBlueprint<Alphabet> {
+    inner: TolerantCounterBlueprint<Alphabet>
+}
+
+impl<Alphabet> DeterministicAutomatonBlueprint for DetailedTolerantCounterBlueprint<Alphabet>
+where
+    Alphabet: PartialEq
+{
+    type State = TolerantCounterState;
+    type Alphabet = Alphabet;
+    type StateSort = CounterSort;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        self.inner.initial_state()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort,Self::ErrorType> {
+        let Some(balance) = state.balance() else {
+            return Ok(CounterSort::Rejected);
+        };
+        // Saturates on targets where usize is narrower than the i64 balance
+        let magnitude = usize::try_from(balance.unsigned_abs()).unwrap_or(usize::MAX);
+        Ok(match balance.signum() {
+            0 => CounterSort::Balanced,
+            1 => CounterSort::PendingOpen(magnitude),
+            _ => CounterSort::PendingClose(magnitude),
+        })
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        self.inner.transition_map(state, character)
+    }
+}
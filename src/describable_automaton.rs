@@ -0,0 +1,37 @@
+//! Blueprints that can describe themselves with a canonical accepted example.
+//!
+//! Documentation and tooling layers often want a sample input each recognizer accepts. Many
+//! blueprints know such an example without any search, such as the empty word for the
+//! counter automaton. The opt-in [`DescribableAutomaton`] trait exposes it, so tooling can
+//! generate examples without exploring the state space.
+//!
+//! # Example
+//!
+//! ```
+//! use deterministic_automata::{BasicStateSort, DescribableAutomaton, DeterministicAutomatonBlueprint};
+//! use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+//!
+//! let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+//! let example = blueprint.example_accepted().unwrap();
+//!
+//! assert_eq!(blueprint.characterise(&example).unwrap(), BasicStateSort::Accept);
+//! ```
+
+use crate::{BasicStateSort, MutationAutomatonBlueprint};
+
+/// A [`BasicStateSort`] blueprint that knows a canonical word it accepts.
+///
+/// Implemented for blueprints of either paradigm, since every deterministic blueprint is a
+/// mutation blueprint.
+///
+/// # Required Methods
+///
+/// * [`example_accepted`](Self::example_accepted) - Returns an accepted word, if one is known
+pub trait DescribableAutomaton: MutationAutomatonBlueprint<StateSort = BasicStateSort> {
+    /// Returns a word accepted by this automaton, or `None` if no example is cheaply known.
+    ///
+    /// `None` does not mean the language is empty, only that the blueprint cannot produce an
+    /// example without searching. Implementations should be cheap and never run the automaton
+    /// over many candidate inputs.
+    fn example_accepted(&self) -> Option<Vec<Self::Alphabet>>;
+}
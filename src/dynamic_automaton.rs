@@ -24,6 +24,32 @@
 //! - **[`ErasedAutomaton`]**: Dyn-compatible runtime automaton trait
 //! - **Universal Coverage**: All mutation automata (including deterministic ones) work seamlessly
 //!
+//! # State Fingerprints
+//!
+//! With the `fingerprint` feature enabled, [`StatefulErasedAutomatonBlueprint`] and
+//! [`StatefulErasedAutomaton`] extend the erased traits with a hash of the hidden state.
+//! This is available for any blueprint whose state implements [`Hash`](std::hash::Hash),
+//! and allows revisited states to be detected during long runs without knowing the state type.
+//!
+//! # Runtime Composition
+//!
+//! Because dynamic blueprints can be stored in collections, they can be composed from a set
+//! of rules assembled at runtime. [`dyn_intersection_all`] combines any number of boxed
+//! blueprints with [`BasicStateSort`] into a single blueprint accepting exactly when every
+//! component accepts, and [`dyn_union_all`] into one accepting when any component accepts.
+//! [`dyn_threshold_all`] and [`dyn_majority_all`] accept when at least `k`, or more than
+//! half, of the components accept.
+//! [`dyn_map_error`] converts the error type of a boxed blueprint, so
+//! automata with different error types can be normalized as they are collected together.
+//! [`dyn_reduce`] combines two boxed blueprints whose classification implements
+//! [`OrderedSort`], keeping the lower- or higher-ranked result, which generalizes
+//! intersection and union to any totally ordered classification.
+//!
+//! [`BoxedBlueprint`] leads back to the static traits: it owns a blueprint behind a trait
+//! object and implements [`DeterministicAutomatonBlueprint`] for it, so that a dynamically
+//! chosen automaton can be stored in a field and used with the product constructions and
+//! adapters.
+//!
 //! # Example: Heterogeneous State Types in Same Language Context
 //!
 //! ```
@@ -62,7 +88,9 @@
 //! }
 //! ```
 
-use crate::{MutationAutomaton, MutationAutomatonBlueprint};
+use std::rc::Rc;
+
+use crate::{BasicStateSort, DeterministicAutomatonBlueprint, MutationAutomaton, MutationAutomatonBlueprint};
 
 /// A dyn-compatible blueprint for defining automata with erased state types.
 ///
@@ -83,6 +111,11 @@ use crate::{MutationAutomaton, MutationAutomatonBlueprint};
 /// * [`automaton`](Self::automaton) - Creates a runtime automaton instance with erased state
 /// * [`characterise`](Self::characterise) - Processes an entire input sequence
 ///
+/// # Provided Methods
+///
+/// * [`prepared_automaton`](Self::prepared_automaton) - Creates a runtime automaton that can be reset
+/// * [`characterise_with`](Self::characterise_with) - Processes an input sequence on a reused runtime
+///
 /// # Universal Implementation
 ///
 /// All types implementing [`MutationAutomatonBlueprint`] (including deterministic
@@ -115,7 +148,53 @@ pub trait ErasedAutomatonBlueprint {
     /// Creates a runtime automaton, processes the input sequence, and returns
     /// the classification of the final state. Propagates any validation errors
     /// encountered during state transitions.
-    fn characterise(&self, word: &[Self::Alphabet]) -> Result<Self::StateSort, Self::ErrorType>;
+    fn characterise(&self, word: &[Self::Alphabet]) -> Result<Self::StateSort, Self::ErrorType> {
+        let mut automaton = self.automaton();
+        for character in word {
+            automaton.update_state(character)?;
+        }
+        automaton.current_state_sort()
+    }
+
+    /// Creates a runtime automaton instance that can be returned to its initial state.
+    ///
+    /// The default implementation wraps [`automaton`](Self::automaton) and resets by creating
+    /// a fresh runtime, which allocates. The implementations in this crate reset in place.
+    fn prepared_automaton<'a>(&'a self) -> Box<dyn ResettableErasedAutomaton<'a, Alphabet = Self::Alphabet, StateSort = Self::StateSort, ErrorType = Self::ErrorType> + 'a> {
+        Box::new(RebuiltAutomaton { blueprint: self, automaton: self.automaton() })
+    }
+
+    /// Processes an entire input sequence on a caller-held runtime and returns the final
+    /// state classification.
+    ///
+    /// The runtime is [`reset`](ResettableErasedAutomaton::reset) before the input is read,
+    /// so a single box obtained once from [`prepared_automaton`](Self::prepared_automaton)
+    /// can classify many inputs without allocating a fresh runtime for each. The runtime
+    /// should have been created by this blueprint; it is reset to the initial state of
+    /// whichever blueprint created it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::{BasicStateSort, DynamicAutomatonBlueprint};
+    /// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+    ///
+    /// let counter = CounterAutomatonBlueprint::new('a', 'b');
+    /// let blueprint: &DynamicAutomatonBlueprint<char, BasicStateSort, String> = &counter;
+    ///
+    /// let mut automaton = blueprint.prepared_automaton();
+    /// for (word, expected) in [("ab", BasicStateSort::Accept), ("aab", BasicStateSort::Reject), ("", BasicStateSort::Accept)] {
+    ///     let input: Vec<char> = word.chars().collect();
+    ///     assert_eq!(blueprint.characterise_with(automaton.as_mut(), &input).unwrap(), expected);
+    /// }
+    /// ```
+    fn characterise_with<'a>(&'a self, automaton: &mut (dyn ResettableErasedAutomaton<'a, Alphabet = Self::Alphabet, StateSort = Self::StateSort, ErrorType = Self::ErrorType> + 'a), word: &[Self::Alphabet]) -> Result<Self::StateSort, Self::ErrorType> {
+        automaton.reset();
+        for character in word {
+            automaton.update_state(character)?;
+        }
+        automaton.current_state_sort()
+    }
 }
 
 /// A dyn-compatible runtime instance of an automaton with erased state type.
@@ -189,6 +268,47 @@ pub trait ErasedAutomaton<'a> {
     }
 }
 
+/// A dyn-compatible runtime automaton with erased state type that can be reset.
+///
+/// Obtained from [`ErasedAutomatonBlueprint::prepared_automaton`], and used by
+/// [`ErasedAutomatonBlueprint::characterise_with`] to classify many inputs on one runtime.
+pub trait ResettableErasedAutomaton<'a>: ErasedAutomaton<'a> {
+    /// Returns the automaton to the initial state of the blueprint it was created from.
+    ///
+    /// This allows one boxed runtime to be reused for several independent inputs, avoiding an
+    /// allocation per input. See
+    /// [`characterise_with`](ErasedAutomatonBlueprint::characterise_with).
+    fn reset(&mut self);
+}
+
+/// The default [`ResettableErasedAutomaton`], resetting by creating a fresh runtime.
+struct RebuiltAutomaton<'a, Blueprint: ErasedAutomatonBlueprint + ?Sized> {
+    blueprint: &'a Blueprint,
+    automaton: Box<dyn ErasedAutomaton<'a, Alphabet = Blueprint::Alphabet, StateSort = Blueprint::StateSort, ErrorType = Blueprint::ErrorType> + 'a>
+}
+
+impl<'a, Blueprint: ErasedAutomatonBlueprint + ?Sized> ErasedAutomaton<'a> for RebuiltAutomaton<'a, Blueprint> {
+    type Alphabet = Blueprint::Alphabet;
+
+    type ErrorType = Blueprint::ErrorType;
+
+    type StateSort = Blueprint::StateSort;
+
+    fn update_state(&mut self, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        self.automaton.update_state(character)
+    }
+
+    fn current_state_sort(&self) -> Result<Self::StateSort,Self::ErrorType> {
+        self.automaton.current_state_sort()
+    }
+}
+
+impl<'a, Blueprint: ErasedAutomatonBlueprint + ?Sized> ResettableErasedAutomaton<'a> for RebuiltAutomaton<'a, Blueprint> {
+    fn reset(&mut self) {
+        self.automaton = self.blueprint.automaton();
+    }
+}
+
 impl<'a, Blueprint: MutationAutomatonBlueprint> ErasedAutomaton<'a> for MutationAutomaton<'a, Blueprint> {
     type Alphabet = Blueprint::Alphabet;
 
@@ -205,6 +325,12 @@ impl<'a, Blueprint: MutationAutomatonBlueprint> ErasedAutomaton<'a> for Mutation
     }
 }
 
+impl<'a, Blueprint: MutationAutomatonBlueprint> ResettableErasedAutomaton<'a> for MutationAutomaton<'a, Blueprint> {
+    fn reset(&mut self) {
+        self.reset()
+    }
+}
+
 impl<Blueprint: MutationAutomatonBlueprint> ErasedAutomatonBlueprint for Blueprint {
     type Alphabet = Blueprint::Alphabet;
 
@@ -219,8 +345,945 @@ impl<Blueprint: MutationAutomatonBlueprint> ErasedAutomatonBlueprint for Bluepri
     fn characterise(&self, word: &[Self::Alphabet]) -> Result<Self::StateSort, Self::ErrorType> {
         self.mutation_characterise(word)
     }
+
+    fn prepared_automaton<'a>(&'a self) -> Box<dyn ResettableErasedAutomaton<'a, Alphabet = Self::Alphabet, StateSort = Self::StateSort, ErrorType = Self::ErrorType> + 'a> {
+        Box::new(self.mutation_automaton())
+    }
 }
 
 pub type DynamicAutomatonBlueprint<Alphabet,StateSort,ErrorType> = dyn ErasedAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>;
 
 pub type DynamicAutomaton<'a,Alphabet,StateSort,ErrorType> = dyn ErasedAutomaton<'a,Alphabet = Alphabet, ErrorType = ErrorType, StateSort = StateSort>;
+
+#[cfg(feature = "fingerprint")]
+pub type DynamicStatefulAutomatonBlueprint<Alphabet,StateSort,ErrorType> = dyn StatefulErasedAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>;
+
+#[cfg(feature = "fingerprint")]
+pub type DynamicStatefulAutomaton<'a,Alphabet,StateSort,ErrorType> = dyn StatefulErasedAutomaton<'a,Alphabet = Alphabet, ErrorType = ErrorType, StateSort = StateSort>;
+
+/// A dyn-compatible blueprint whose runtime automata expose a fingerprint of their erased state.
+///
+/// This trait extends [`ErasedAutomatonBlueprint`] for blueprints whose state type implements
+/// [`Hash`](std::hash::Hash). It is available with the `fingerprint` feature.
+///
+/// # Universal Implementation
+///
+/// All types implementing [`MutationAutomatonBlueprint`] with a hashable state automatically
+/// implement this trait.
+///
+/// # Example: Detecting a Revisited State
+///
+/// ```
+/// use std::collections::HashSet;
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::dynamic_automaton::{DynamicStatefulAutomatonBlueprint, StatefulErasedAutomaton};
+///
+/// struct Balance;
+///
+/// impl DeterministicAutomatonBlueprint for Balance {
+///     type State = i32;
+///     type Alphabet = char;
+///     type StateSort = BasicStateSort;
+///     type ErrorType = String;
+///
+///     fn initial_state(&self) -> Self::State { 0 }
+///
+///     fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+///         Ok(if *state == 0 { BasicStateSort::Accept } else { BasicStateSort::Reject })
+///     }
+///
+///     fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+///         Ok(if *character == 'a' { state + 1 } else { state - 1 })
+///     }
+/// }
+///
+/// let blueprint: &DynamicStatefulAutomatonBlueprint<char, BasicStateSort, String> = &Balance;
+///
+/// let mut automaton = blueprint.stateful_automaton();
+/// let mut seen = HashSet::from([automaton.state_fingerprint()]);
+/// let mut revisited_at = None;
+/// for (index, character) in ['a', 'b', 'a'].iter().enumerate() {
+///     automaton.update_state(character).unwrap();
+///     if !seen.insert(automaton.state_fingerprint()) {
+///         revisited_at = Some(index);
+///         break;
+///     }
+/// }
+///
+/// assert_eq!(revisited_at, Some(1));
+/// ```
+#[cfg(feature = "fingerprint")]
+pub trait StatefulErasedAutomatonBlueprint: ErasedAutomatonBlueprint {
+    /// Creates a runtime automaton instance whose erased state can be fingerprinted.
+    fn stateful_automaton<'a>(&'a self) -> Box<dyn StatefulErasedAutomaton<'a, Alphabet = Self::Alphabet, StateSort = Self::StateSort, ErrorType = Self::ErrorType> + 'a>;
+}
+
+/// A dyn-compatible runtime automaton exposing a fingerprint of its erased state.
+///
+/// This trait extends [`ErasedAutomaton`] with [`state_fingerprint`](Self::state_fingerprint),
+/// a hash of the current state. Equal states always produce equal fingerprints, so a repeated
+/// fingerprint indicates a state has (very likely) been revisited. It is available with the
+/// `fingerprint` feature.
+#[cfg(feature = "fingerprint")]
+pub trait StatefulErasedAutomaton<'a>: ErasedAutomaton<'a> {
+    /// Returns a hash of the current state.
+    ///
+    /// Fingerprints are stable within a single process but are not guaranteed to be
+    /// stable across Rust versions, so they should not be persisted.
+    fn state_fingerprint(&self) -> u64;
+}
+
+#[cfg(feature = "fingerprint")]
+impl<'a, Blueprint> StatefulErasedAutomaton<'a> for MutationAutomaton<'a, Blueprint>
+where
+    Blueprint: MutationAutomatonBlueprint,
+    Blueprint::State: std::hash::Hash
+{
+    fn state_fingerprint(&self) -> u64 {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        self.view_state().hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[cfg(feature = "fingerprint")]
+impl<Blueprint> StatefulErasedAutomatonBlueprint for Blueprint
+where
+    Blueprint: MutationAutomatonBlueprint,
+    Blueprint::State: std::hash::Hash
+{
+    fn stateful_automaton<'a>(&'a self) -> Box<dyn StatefulErasedAutomaton<'a, Alphabet = Self::Alphabet, StateSort = Self::StateSort, ErrorType = Self::ErrorType> + 'a> {
+        Box::new(self.mutation_automaton())
+    }
+}
+
+/// A dynamic blueprint for the intersection (logical AND) of any number of dynamic blueprints.
+///
+/// This blueprint runs every component blueprint in parallel on the same input and accepts
+/// only if **all** of them accept, recognizing the intersection of their languages. Unlike the
+/// product constructions in [`product_automaton`](crate::product_automaton), the components
+/// are held as trait objects, so the set of components can be decided at runtime.
+///
+/// An intersection of no components accepts every input.
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) or [`dyn_intersection_all`] to create an instance from boxed blueprints.
+pub struct DynamicIntersectionAutomatonBlueprint<Alphabet, ErrorType> {
+    components: Vec<Box<DynamicAutomatonBlueprint<Alphabet, BasicStateSort, ErrorType>>>
+}
+
+impl<Alphabet, ErrorType> DynamicIntersectionAutomatonBlueprint<Alphabet, ErrorType> {
+    /// Creates a new intersection blueprint from a collection of boxed dynamic blueprints.
+    pub fn new(components: Vec<Box<DynamicAutomatonBlueprint<Alphabet, BasicStateSort, ErrorType>>>) -> Self {
+        Self { components }
+    }
+
+    /// Creates the runtime instance, with resettable component runtimes.
+    fn runtime(&self) -> DynamicIntersectionAutomaton<'_, Alphabet, ErrorType>
+    where
+        Alphabet: PartialEq
+    {
+        DynamicIntersectionAutomaton {
+            components: self.components.iter().map(|component| component.prepared_automaton()).collect()
+        }
+    }
+}
+
+impl<Alphabet: PartialEq, ErrorType> ErasedAutomatonBlueprint for DynamicIntersectionAutomatonBlueprint<Alphabet, ErrorType> {
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn automaton<'a>(&'a self) -> Box<dyn ErasedAutomaton<'a, Alphabet = Self::Alphabet, StateSort = Self::StateSort, ErrorType = Self::ErrorType> + 'a> {
+        Box::new(self.runtime())
+    }
+
+    fn prepared_automaton<'a>(&'a self) -> Box<dyn ResettableErasedAutomaton<'a, Alphabet = Self::Alphabet, StateSort = Self::StateSort, ErrorType = Self::ErrorType> + 'a> {
+        Box::new(self.runtime())
+    }
+}
+
+/// The runtime instance of a [`DynamicIntersectionAutomatonBlueprint`].
+struct DynamicIntersectionAutomaton<'a, Alphabet, ErrorType> {
+    components: Vec<Box<dyn ResettableErasedAutomaton<'a, Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType> + 'a>>
+}
+
+impl<'a, Alphabet: PartialEq, ErrorType> ErasedAutomaton<'a> for DynamicIntersectionAutomaton<'a, Alphabet, ErrorType> {
+    type Alphabet = Alphabet;
+
+    type ErrorType = ErrorType;
+
+    type StateSort = BasicStateSort;
+
+    fn update_state(&mut self, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        for component in self.components.iter_mut() {
+            component.update_state(character)?;
+        }
+        Ok(())
+    }
+
+    fn current_state_sort(&self) -> Result<Self::StateSort,Self::ErrorType> {
+        let mut sort = BasicStateSort::Accept;
+        for component in self.components.iter() {
+            if component.current_state_sort()? == BasicStateSort::Reject {
+                sort = BasicStateSort::Reject;
+            }
+        }
+        Ok(sort)
+    }
+}
+
+impl<'a, Alphabet: PartialEq, ErrorType> ResettableErasedAutomaton<'a> for DynamicIntersectionAutomaton<'a, Alphabet, ErrorType> {
+    fn reset(&mut self) {
+        for component in self.components.iter_mut() {
+            component.reset();
+        }
+    }
+}
+
+/// Combines boxed dynamic blueprints into a single blueprint accepting iff all of them accept.
+///
+/// This enables conjunctions of rules assembled at runtime, which the statically typed
+/// product constructions cannot express. Errors from any component are propagated.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DynamicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::dynamic_automaton::dyn_intersection_all;
+///
+/// let rules: Vec<Box<DynamicAutomatonBlueprint<char, BasicStateSort, String>>> = vec![
+///     Box::new(CounterAutomatonBlueprint::new('a', 'b')),
+///     Box::new(CounterAutomatonBlueprint::new('a', 'c')),
+/// ];
+/// let all = dyn_intersection_all(rules);
+///
+/// assert_eq!(all.characterise(&[]).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(all.characterise(&['a', 'b']).unwrap(), BasicStateSort::Reject);
+/// ```
+pub fn dyn_intersection_all<Alphabet, ErrorType>(blueprints: Vec<Box<DynamicAutomatonBlueprint<Alphabet, BasicStateSort, ErrorType>>>) -> Box<DynamicAutomatonBlueprint<Alphabet, BasicStateSort, ErrorType>>
+where
+    Alphabet: PartialEq + 'static,
+    ErrorType: 'static
+{
+    Box::new(DynamicIntersectionAutomatonBlueprint::new(blueprints))
+}
+
+/// A dynamic blueprint for the union (logical OR) of any number of dynamic blueprints.
+///
+/// The counterpart of [`DynamicIntersectionAutomatonBlueprint`]: every component runs in
+/// parallel on the same input and the union accepts if **any** of them accepts. Components
+/// may have different concrete types and state types, since they are held as trait objects.
+///
+/// A union of no components rejects every input.
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) or [`dyn_union_all`] to create an instance from boxed blueprints.
+pub struct DynamicUnionAutomatonBlueprint<Alphabet, ErrorType> {
+    components: Vec<Box<DynamicAutomatonBlueprint<Alphabet, BasicStateSort, ErrorType>>>
+}
+
+impl<Alphabet, ErrorType> DynamicUnionAutomatonBlueprint<Alphabet, ErrorType> {
+    /// Creates a new union blueprint from a collection of boxed dynamic blueprints.
+    pub fn new(components: Vec<Box<DynamicAutomatonBlueprint<Alphabet, BasicStateSort, ErrorType>>>) -> Self {
+        Self { components }
+    }
+
+    /// Creates the runtime instance, with resettable component runtimes.
+    fn runtime(&self) -> DynamicUnionAutomaton<'_, Alphabet, ErrorType>
+    where
+        Alphabet: PartialEq
+    {
+        DynamicUnionAutomaton {
+            components: self.components.iter().map(|component| component.prepared_automaton()).collect()
+        }
+    }
+}
+
+impl<Alphabet: PartialEq, ErrorType> ErasedAutomatonBlueprint for DynamicUnionAutomatonBlueprint<Alphabet, ErrorType> {
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn automaton<'a>(&'a self) -> Box<dyn ErasedAutomaton<'a, Alphabet = Self::Alphabet, StateSort = Self::StateSort, ErrorType = Self::ErrorType> + 'a> {
+        Box::new(self.runtime())
+    }
+
+    fn prepared_automaton<'a>(&'a self) -> Box<dyn ResettableErasedAutomaton<'a, Alphabet = Self::Alphabet, StateSort = Self::StateSort, ErrorType = Self::ErrorType> + 'a> {
+        Box::new(self.runtime())
+    }
+}
+
+/// The runtime instance of a [`DynamicUnionAutomatonBlueprint`].
+struct DynamicUnionAutomaton<'a, Alphabet, ErrorType> {
+    components: Vec<Box<dyn ResettableErasedAutomaton<'a, Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType> + 'a>>
+}
+
+impl<'a, Alphabet: PartialEq, ErrorType> ErasedAutomaton<'a> for DynamicUnionAutomaton<'a, Alphabet, ErrorType> {
+    type Alphabet = Alphabet;
+
+    type ErrorType = ErrorType;
+
+    type StateSort = BasicStateSort;
+
+    fn update_state(&mut self, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        for component in self.components.iter_mut() {
+            component.update_state(character)?;
+        }
+        Ok(())
+    }
+
+    fn current_state_sort(&self) -> Result<Self::StateSort,Self::ErrorType> {
+        let mut sort = BasicStateSort::Reject;
+        for component in self.components.iter() {
+            if component.current_state_sort()? == BasicStateSort::Accept {
+                sort = BasicStateSort::Accept;
+            }
+        }
+        Ok(sort)
+    }
+}
+
+impl<'a, Alphabet: PartialEq, ErrorType> ResettableErasedAutomaton<'a> for DynamicUnionAutomaton<'a, Alphabet, ErrorType> {
+    fn reset(&mut self) {
+        for component in self.components.iter_mut() {
+            component.reset();
+        }
+    }
+}
+
+/// Combines boxed dynamic blueprints into a single blueprint accepting iff any of them accepts.
+///
+/// The disjunctive counterpart of [`dyn_intersection_all`], for rule engines accepting input
+/// that satisfies at least one of a set of validators of different kinds. Errors from any
+/// component are propagated.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DynamicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::dynamic_automaton::{dyn_map_error, dyn_union_all};
+/// use deterministic_automata::window_automaton::WindowBlueprint;
+///
+/// let ends_with_c = WindowBlueprint::new(1, |window| {
+///     if window.back() == Some(&'c') { BasicStateSort::Accept } else { BasicStateSort::Reject }
+/// });
+/// let rules: Vec<Box<DynamicAutomatonBlueprint<char, BasicStateSort, String>>> = vec![
+///     Box::new(CounterAutomatonBlueprint::new('a', 'b')),
+///     dyn_map_error(Box::new(ends_with_c), |never| match never {}),
+/// ];
+/// let any = dyn_union_all(rules);
+///
+/// assert_eq!(any.characterise(&['a', 'b']).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(any.characterise(&['b', 'c']).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(any.characterise(&['b', 'a']).unwrap(), BasicStateSort::Reject);
+/// ```
+pub fn dyn_union_all<Alphabet, ErrorType>(blueprints: Vec<Box<DynamicAutomatonBlueprint<Alphabet, BasicStateSort, ErrorType>>>) -> Box<DynamicAutomatonBlueprint<Alphabet, BasicStateSort, ErrorType>>
+where
+    Alphabet: PartialEq + 'static,
+    ErrorType: 'static
+{
+    Box::new(DynamicUnionAutomatonBlueprint::new(blueprints))
+}
+
+/// A dynamic blueprint accepting when at least a threshold number of dynamic blueprints accept.
+///
+/// Every component runs in parallel on the same input, and the classification counts how
+/// many of them accept. With a threshold of one this is [`DynamicUnionAutomatonBlueprint`],
+/// and with the number of components it is [`DynamicIntersectionAutomatonBlueprint`]; values
+/// in between give k-out-of-n voting that neither can express. A threshold of zero accepts
+/// every input, and one above the number of components rejects every input.
+///
+/// # Construction
+///
+/// Use [`new`](Self::new), [`dyn_threshold_all`] or [`dyn_majority_all`].
+pub struct DynamicThresholdAutomatonBlueprint<Alphabet, ErrorType> {
+    components: Vec<Box<DynamicAutomatonBlueprint<Alphabet, BasicStateSort, ErrorType>>>,
+    threshold: usize
+}
+
+impl<Alphabet, ErrorType> DynamicThresholdAutomatonBlueprint<Alphabet, ErrorType> {
+    /// Creates a new threshold blueprint accepting when at least `threshold` components accept.
+    pub fn new(components: Vec<Box<DynamicAutomatonBlueprint<Alphabet, BasicStateSort, ErrorType>>>, threshold: usize) -> Self {
+        Self { components, threshold }
+    }
+
+    /// Creates the runtime instance, with resettable component runtimes.
+    fn runtime(&self) -> DynamicThresholdAutomaton<'_, Alphabet, ErrorType>
+    where
+        Alphabet: PartialEq
+    {
+        DynamicThresholdAutomaton {
+            components: self.components.iter().map(|component| component.prepared_automaton()).collect(),
+            threshold: self.threshold
+        }
+    }
+}
+
+impl<Alphabet: PartialEq, ErrorType> ErasedAutomatonBlueprint for DynamicThresholdAutomatonBlueprint<Alphabet, ErrorType> {
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn automaton<'a>(&'a self) -> Box<dyn ErasedAutomaton<'a, Alphabet = Self::Alphabet, StateSort = Self::StateSort, ErrorType = Self::ErrorType> + 'a> {
+        Box::new(self.runtime())
+    }
+
+    fn prepared_automaton<'a>(&'a self) -> Box<dyn ResettableErasedAutomaton<'a, Alphabet = Self::Alphabet, StateSort = Self::StateSort, ErrorType = Self::ErrorType> + 'a> {
+        Box::new(self.runtime())
+    }
+}
+
+/// The runtime instance of a [`DynamicThresholdAutomatonBlueprint`].
+struct DynamicThresholdAutomaton<'a, Alphabet, ErrorType> {
+    components: Vec<Box<dyn ResettableErasedAutomaton<'a, Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType> + 'a>>,
+    threshold: usize
+}
+
+impl<'a, Alphabet: PartialEq, ErrorType> ErasedAutomaton<'a> for DynamicThresholdAutomaton<'a, Alphabet, ErrorType> {
+    type Alphabet = Alphabet;
+
+    type ErrorType = ErrorType;
+
+    type StateSort = BasicStateSort;
+
+    fn update_state(&mut self, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        for component in self.components.iter_mut() {
+            component.update_state(character)?;
+        }
+        Ok(())
+    }
+
+    fn current_state_sort(&self) -> Result<Self::StateSort,Self::ErrorType> {
+        let mut accepting = 0;
+        for component in self.components.iter() {
+            if component.current_state_sort()? == BasicStateSort::Accept {
+                accepting += 1;
+            }
+        }
+        Ok(BasicStateSort::from(accepting >= self.threshold))
+    }
+}
+
+impl<'a, Alphabet: PartialEq, ErrorType> ResettableErasedAutomaton<'a> for DynamicThresholdAutomaton<'a, Alphabet, ErrorType> {
+    fn reset(&mut self) {
+        for component in self.components.iter_mut() {
+            component.reset();
+        }
+    }
+}
+
+/// Combines boxed dynamic blueprints into a single blueprint accepting iff at least `threshold` of them accept.
+///
+/// Suited to voting-style validators, such as accepting input that passes at least three of
+/// five rules. Errors from any component are propagated.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DynamicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::dynamic_automaton::dyn_threshold_all;
+///
+/// let rules: Vec<Box<DynamicAutomatonBlueprint<char, BasicStateSort, String>>> = vec![
+///     Box::new(CounterAutomatonBlueprint::new('a', 'b')),
+///     Box::new(CounterAutomatonBlueprint::new('a', 'c')),
+///     Box::new(CounterAutomatonBlueprint::with_tolerance('a', 'b', 0..=1)),
+/// ];
+/// let two_of_three = dyn_threshold_all(rules, 2);
+///
+/// assert_eq!(two_of_three.characterise(&['a', 'b']).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(two_of_three.characterise(&['a', 'c']).unwrap(), BasicStateSort::Reject);
+/// ```
+pub fn dyn_threshold_all<Alphabet, ErrorType>(blueprints: Vec<Box<DynamicAutomatonBlueprint<Alphabet, BasicStateSort, ErrorType>>>, threshold: usize) -> Box<DynamicAutomatonBlueprint<Alphabet, BasicStateSort, ErrorType>>
+where
+    Alphabet: PartialEq + 'static,
+    ErrorType: 'static
+{
+    Box::new(DynamicThresholdAutomatonBlueprint::new(blueprints, threshold))
+}
+
+/// Combines boxed dynamic blueprints into a single blueprint accepting iff more than half of them accept.
+///
+/// Equivalent to [`dyn_threshold_all`] with a threshold of `n / 2 + 1` for `n` components.
+pub fn dyn_majority_all<Alphabet, ErrorType>(blueprints: Vec<Box<DynamicAutomatonBlueprint<Alphabet, BasicStateSort, ErrorType>>>) -> Box<DynamicAutomatonBlueprint<Alphabet, BasicStateSort, ErrorType>>
+where
+    Alphabet: PartialEq + 'static,
+    ErrorType: 'static
+{
+    let threshold = blueprints.len() / 2 + 1;
+    dyn_threshold_all(blueprints, threshold)
+}
+
+/// A dynamic blueprint converting the errors of a boxed dynamic blueprint.
+///
+/// Every transition and classification is delegated to the wrapped blueprint, and each
+/// error it returns is passed through the conversion function.
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) or [`dyn_map_error`].
+pub struct DynamicMapErrorAutomatonBlueprint<Alphabet, StateSort, ErrorType, F> {
+    inner: Box<DynamicAutomatonBlueprint<Alphabet, StateSort, ErrorType>>,
+    conversion: F
+}
+
+impl<Alphabet, StateSort, ErrorType, F> DynamicMapErrorAutomatonBlueprint<Alphabet, StateSort, ErrorType, F> {
+    /// Creates a new blueprint converting the errors of `inner` with `conversion`.
+    pub fn new(inner: Box<DynamicAutomatonBlueprint<Alphabet, StateSort, ErrorType>>, conversion: F) -> Self {
+        Self { inner, conversion }
+    }
+
+    /// Creates the runtime instance, with resettable component runtimes.
+    fn runtime(&self) -> DynamicMapErrorAutomaton<'_, Alphabet, StateSort, ErrorType, F>
+    where
+        Alphabet: PartialEq
+    {
+        DynamicMapErrorAutomaton {
+            inner: self.inner.prepared_automaton(),
+            conversion: &self.conversion
+        }
+    }
+}
+
+impl<Alphabet, StateSort, ErrorType, NewErrorType, F> ErasedAutomatonBlueprint for DynamicMapErrorAutomatonBlueprint<Alphabet, StateSort, ErrorType, F>
+where
+    Alphabet: PartialEq,
+    F: Fn(ErrorType) -> NewErrorType
+{
+    type Alphabet = Alphabet;
+
+    type StateSort = StateSort;
+
+    type ErrorType = NewErrorType;
+
+    fn automaton<'a>(&'a self) -> Box<dyn ErasedAutomaton<'a, Alphabet = Self::Alphabet, StateSort = Self::StateSort, ErrorType = Self::ErrorType> + 'a> {
+        Box::new(self.runtime())
+    }
+
+    fn characterise(&self, word: &[Self::Alphabet]) -> Result<Self::StateSort, Self::ErrorType> {
+        self.inner.characterise(word).map_err(&self.conversion)
+    }
+
+    fn prepared_automaton<'a>(&'a self) -> Box<dyn ResettableErasedAutomaton<'a, Alphabet = Self::Alphabet, StateSort = Self::StateSort, ErrorType = Self::ErrorType> + 'a> {
+        Box::new(self.runtime())
+    }
+}
+
+/// The runtime instance of a [`DynamicMapErrorAutomatonBlueprint`].
+struct DynamicMapErrorAutomaton<'a, Alphabet, StateSort, ErrorType, F> {
+    inner: Box<dyn ResettableErasedAutomaton<'a, Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType> + 'a>,
+    conversion: &'a F
+}
+
+impl<'a, Alphabet, StateSort, ErrorType, NewErrorType, F> ErasedAutomaton<'a> for DynamicMapErrorAutomaton<'a, Alphabet, StateSort, ErrorType, F>
+where
+    Alphabet: PartialEq,
+    F: Fn(ErrorType) -> NewErrorType
+{
+    type Alphabet = Alphabet;
+
+    type ErrorType = NewErrorType;
+
+    type StateSort = StateSort;
+
+    fn update_state(&mut self, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        self.inner.update_state(character).map_err(self.conversion)
+    }
+
+    fn current_state_sort(&self) -> Result<Self::StateSort,Self::ErrorType> {
+        self.inner.current_state_sort().map_err(self.conversion)
+    }
+}
+
+impl<'a, Alphabet, StateSort, ErrorType, NewErrorType, F> ResettableErasedAutomaton<'a> for DynamicMapErrorAutomaton<'a, Alphabet, StateSort, ErrorType, F>
+where
+    Alphabet: PartialEq,
+    F: Fn(ErrorType) -> NewErrorType
+{
+    fn reset(&mut self) {
+        self.inner.reset()
+    }
+}
+
+/// Converts the error type of a boxed dynamic blueprint.
+///
+/// Dynamic blueprints collected into one container must share an error type. This wraps a
+/// boxed blueprint so that each error it produces is converted with `conversion`, allowing
+/// error types to be normalized as heterogeneous automata are collected. It is a free
+/// function rather than a trait method because generic methods would make
+/// [`ErasedAutomatonBlueprint`] no longer dyn-compatible.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DynamicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::dynamic_automaton::dyn_map_error;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct RuleError(String);
+///
+/// let counter: Box<DynamicAutomatonBlueprint<char, BasicStateSort, String>> =
+///     Box::new(CounterAutomatonBlueprint::new('a', 'b'));
+///
+/// let rules: Vec<Box<DynamicAutomatonBlueprint<char, BasicStateSort, RuleError>>> = vec![
+///     dyn_map_error(counter, RuleError),
+/// ];
+///
+/// assert_eq!(rules[0].characterise(&['a', 'b']), Ok(BasicStateSort::Accept));
+/// ```
+pub fn dyn_map_error<Alphabet, StateSort, ErrorType, NewErrorType, F>(blueprint: Box<DynamicAutomatonBlueprint<Alphabet, StateSort, ErrorType>>, conversion: F) -> Box<DynamicAutomatonBlueprint<Alphabet, StateSort, NewErrorType>>
+where
+    Alphabet: PartialEq + 'static,
+    StateSort: 'static,
+    ErrorType: 'static,
+    F: Fn(ErrorType) -> NewErrorType + 'static
+{
+    Box::new(DynamicMapErrorAutomatonBlueprint::new(blueprint, conversion))
+}
+
+/// An owned, type-erased blueprint presented as a [`DeterministicAutomatonBlueprint`].
+///
+/// [`DynamicAutomatonBlueprint`] hides the state type behind `dyn`, which means it cannot be
+/// passed where the static traits are expected, such as to the product constructions or the
+/// adapters in [`operations`](crate::operations). This newtype owns a blueprint behind a
+/// trait object and implements [`DeterministicAutomatonBlueprint`] for it, and therefore
+/// [`MutationAutomatonBlueprint`] through the blanket implementation, so a struct field can
+/// hold "some automaton over this alphabet" and still be composed statically.
+///
+/// # State Representation
+///
+/// The state is an opaque [`BoxedState`]: a run of the wrapped blueprint that owns its
+/// concrete state together with a shared handle to the blueprint. Each transition is a
+/// single step of the wrapped blueprint, so errors are reported by the transition that
+/// caused them. The state has no `PartialEq` or `Hash`, so operations that enumerate
+/// states do not apply.
+///
+/// # Construction
+///
+/// Use [`new`](Self::new).
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::dynamic_automaton::BoxedBlueprint;
+/// use deterministic_automata::operations::complement;
+///
+/// struct Validator {
+///     rule: BoxedBlueprint<char, BasicStateSort, String>
+/// }
+///
+/// let validator = Validator { rule: BoxedBlueprint::new(CounterAutomatonBlueprint::new('a', 'b')) };
+///
+/// // The boxed rule works with operations requiring the static trait
+/// let unbalanced = complement(&validator.rule);
+/// assert_eq!(unbalanced.characterise(&['a']).unwrap(), BasicStateSort::Accept);
+/// ```
+pub struct BoxedBlueprint<Alphabet, StateSort, ErrorType> {
+    inner: Rc<dyn OwnedBlueprint<Alphabet, StateSort, ErrorType>>
+}
+
+impl<Alphabet, StateSort, ErrorType> BoxedBlueprint<Alphabet, StateSort, ErrorType> {
+    /// Creates a new blueprint owning `inner` behind a trait object.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - The blueprint to delegate to
+    pub fn new<Blueprint>(inner: Blueprint) -> Self
+    where
+        Blueprint: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType> + 'static,
+        Blueprint::State: 'static
+    {
+        Self { inner: Rc::new(inner) }
+    }
+}
+
+impl<Alphabet: PartialEq, StateSort, ErrorType> DeterministicAutomatonBlueprint for BoxedBlueprint<Alphabet, StateSort, ErrorType> {
+    type State = BoxedState<Alphabet, StateSort, ErrorType>;
+
+    type Alphabet = Alphabet;
+
+    type StateSort = StateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        BoxedState { run: Rc::clone(&self.inner).start() }
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        state.run.state_sort()
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        let mut next = state.clone();
+        next.run.step(character)?;
+        Ok(next)
+    }
+}
+
+/// The opaque state of a [`BoxedBlueprint`].
+///
+/// Holds the concrete state of the wrapped blueprint together with a shared handle to that
+/// blueprint, so it can be cloned and stepped without knowing the state type.
+pub struct BoxedState<Alphabet, StateSort, ErrorType> {
+    run: Box<dyn OwnedRun<Alphabet, StateSort, ErrorType>>
+}
+
+impl<Alphabet, StateSort, ErrorType> Clone for BoxedState<Alphabet, StateSort, ErrorType> {
+    fn clone(&self) -> Self {
+        Self { run: self.run.clone_box() }
+    }
+}
+
+impl<Alphabet, StateSort, ErrorType> std::fmt::Debug for BoxedState<Alphabet, StateSort, ErrorType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BoxedState").finish_non_exhaustive()
+    }
+}
+
+/// A blueprint that can start an owned run of itself, the erased part of a [`BoxedBlueprint`].
+trait OwnedBlueprint<Alphabet, StateSort, ErrorType> {
+    fn start(self: Rc<Self>) -> Box<dyn OwnedRun<Alphabet, StateSort, ErrorType>>;
+}
+
+impl<Blueprint> OwnedBlueprint<Blueprint::Alphabet, Blueprint::StateSort, Blueprint::ErrorType> for Blueprint
+where
+    Blueprint: MutationAutomatonBlueprint + 'static,
+    Blueprint::State: 'static
+{
+    fn start(self: Rc<Self>) -> Box<dyn OwnedRun<Blueprint::Alphabet, Blueprint::StateSort, Blueprint::ErrorType>> {
+        let state = self.initial_mutation_state();
+        Box::new(OwnedMutationRun { blueprint: self, state })
+    }
+}
+
+/// A run of an erased blueprint that owns its state, the contents of a [`BoxedState`].
+trait OwnedRun<Alphabet, StateSort, ErrorType> {
+    fn state_sort(&self) -> Result<StateSort, ErrorType>;
+
+    fn step(&mut self, character: &Alphabet) -> Result<(), ErrorType>;
+
+    fn clone_box(&self) -> Box<dyn OwnedRun<Alphabet, StateSort, ErrorType>>;
+}
+
+/// The [`OwnedRun`] of a mutation blueprint.
+struct OwnedMutationRun<Blueprint: MutationAutomatonBlueprint> {
+    blueprint: Rc<Blueprint>,
+    state: Blueprint::State
+}
+
+impl<Blueprint> OwnedRun<Blueprint::Alphabet, Blueprint::StateSort, Blueprint::ErrorType> for OwnedMutationRun<Blueprint>
+where
+    Blueprint: MutationAutomatonBlueprint + 'static,
+    Blueprint::State: 'static
+{
+    fn state_sort(&self) -> Result<Blueprint::StateSort, Blueprint::ErrorType> {
+        self.blueprint.mutation_state_sort_map(&self.state)
+    }
+
+    fn step(&mut self, character: &Blueprint::Alphabet) -> Result<(), Blueprint::ErrorType> {
+        self.blueprint.mutation_transition_map(&mut self.state, character)
+    }
+
+    fn clone_box(&self) -> Box<dyn OwnedRun<Blueprint::Alphabet, Blueprint::StateSort, Blueprint::ErrorType>> {
+        Box::new(OwnedMutationRun { blueprint: Rc::clone(&self.blueprint), state: self.state.clone() })
+    }
+}
+
+/// A classification type with a total order given by a numeric rank.
+///
+/// Ranking a state sort lets dynamic blueprints with arbitrary classifications be combined by
+/// keeping the lower- or higher-ranked result, see [`dyn_reduce`]. For [`BasicStateSort`],
+/// `Reject` ranks below `Accept`, so keeping the maximum is union and keeping the minimum
+/// is intersection.
+pub trait OrderedSort {
+    /// Returns the position of this classification in the order; higher ranks are greater.
+    fn rank(&self) -> u8;
+}
+
+impl OrderedSort for BasicStateSort {
+    fn rank(&self) -> u8 {
+        match self {
+            BasicStateSort::Reject => 0,
+            BasicStateSort::Accept => 1,
+        }
+    }
+}
+
+/// How a [`DynamicReduceAutomatonBlueprint`] combines the classifications of its components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Reduce {
+    /// Keep the lower-ranked classification, generalizing intersection.
+    Min,
+    /// Keep the higher-ranked classification, generalizing union.
+    Max
+}
+
+/// A dynamic blueprint combining the classifications of two dynamic blueprints by rank.
+///
+/// Both components run in parallel on the same input, and the classification reported is
+/// the lower- or higher-ranked of the two according to [`Reduce`]. When both rank equally,
+/// the first component's classification is reported.
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) or [`dyn_reduce`].
+pub struct DynamicReduceAutomatonBlueprint<Alphabet, StateSort, ErrorType> {
+    first: Box<DynamicAutomatonBlueprint<Alphabet, StateSort, ErrorType>>,
+    second: Box<DynamicAutomatonBlueprint<Alphabet, StateSort, ErrorType>>,
+    reduce: Reduce
+}
+
+impl<Alphabet, StateSort, ErrorType> DynamicReduceAutomatonBlueprint<Alphabet, StateSort, ErrorType> {
+    /// Creates a new blueprint combining `first` and `second` according to `reduce`.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - The first component blueprint, preferred when ranks tie
+    /// * `second` - The second component blueprint
+    /// * `reduce` - Whether the lower or higher ranked classification is kept
+    pub fn new(first: Box<DynamicAutomatonBlueprint<Alphabet, StateSort, ErrorType>>, second: Box<DynamicAutomatonBlueprint<Alphabet, StateSort, ErrorType>>, reduce: Reduce) -> Self {
+        Self { first, second, reduce }
+    }
+
+    /// Creates the runtime instance, with resettable component runtimes.
+    fn runtime(&self) -> DynamicReduceAutomaton<'_, Alphabet, StateSort, ErrorType>
+    where
+        Alphabet: PartialEq
+    {
+        DynamicReduceAutomaton {
+            first: self.first.prepared_automaton(),
+            second: self.second.prepared_automaton(),
+            reduce: self.reduce
+        }
+    }
+}
+
+impl<Alphabet: PartialEq, StateSort: OrderedSort, ErrorType> ErasedAutomatonBlueprint for DynamicReduceAutomatonBlueprint<Alphabet, StateSort, ErrorType> {
+    type Alphabet = Alphabet;
+
+    type StateSort = StateSort;
+
+    type ErrorType = ErrorType;
+
+    fn automaton<'a>(&'a self) -> Box<dyn ErasedAutomaton<'a, Alphabet = Self::Alphabet, StateSort = Self::StateSort, ErrorType = Self::ErrorType> + 'a> {
+        Box::new(self.runtime())
+    }
+
+    fn prepared_automaton<'a>(&'a self) -> Box<dyn ResettableErasedAutomaton<'a, Alphabet = Self::Alphabet, StateSort = Self::StateSort, ErrorType = Self::ErrorType> + 'a> {
+        Box::new(self.runtime())
+    }
+}
+
+/// The runtime instance of a [`DynamicReduceAutomatonBlueprint`].
+struct DynamicReduceAutomaton<'a, Alphabet, StateSort, ErrorType> {
+    first: Box<dyn ResettableErasedAutomaton<'a, Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType> + 'a>,
+    second: Box<dyn ResettableErasedAutomaton<'a, Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType> + 'a>,
+    reduce: Reduce
+}
+
+impl<'a, Alphabet: PartialEq, StateSort: OrderedSort, ErrorType> ErasedAutomaton<'a> for DynamicReduceAutomaton<'a, Alphabet, StateSort, ErrorType> {
+    type Alphabet = Alphabet;
+
+    type ErrorType = ErrorType;
+
+    type StateSort = StateSort;
+
+    fn update_state(&mut self, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        self.first.update_state(character)?;
+        self.second.update_state(character)
+    }
+
+    fn current_state_sort(&self) -> Result<Self::StateSort,Self::ErrorType> {
+        let first = self.first.current_state_sort()?;
+        let second = self.second.current_state_sort()?;
+        let keep_second = match self.reduce {
+            Reduce::Min => second.rank() < first.rank(),
+            Reduce::Max => second.rank() > first.rank(),
+        };
+        Ok(if keep_second { second } else { first })
+    }
+}
+
+impl<'a, Alphabet: PartialEq, StateSort: OrderedSort, ErrorType> ResettableErasedAutomaton<'a> for DynamicReduceAutomaton<'a, Alphabet, StateSort, ErrorType> {
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+    }
+}
+
+/// Combines two boxed dynamic blueprints, keeping the lower or higher ranked classification.
+///
+/// This generalizes boolean union and intersection to any totally ordered classification
+/// implementing [`OrderedSort`]. Errors from either component are propagated.
+///
+/// # Example: Severity Levels
+///
+/// ```
+/// use deterministic_automata::{DeterministicAutomatonBlueprint, DynamicAutomatonBlueprint};
+/// use deterministic_automata::dynamic_automaton::{dyn_reduce, OrderedSort, Reduce};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Severity { Info, Warn, Error }
+///
+/// impl OrderedSort for Severity {
+///     fn rank(&self) -> u8 { *self as u8 }
+/// }
+///
+/// // Warns on any '!' seen, errors once three have been seen
+/// struct Exclamations;
+///
+/// impl DeterministicAutomatonBlueprint for Exclamations {
+///     type State = u8; type Alphabet = char; type StateSort = Severity; type ErrorType = String;
+///     fn initial_state(&self) -> u8 { 0 }
+///     fn state_sort_map(&self, count: &u8) -> Result<Severity, String> {
+///         Ok(match count { 0 => Severity::Info, 1 | 2 => Severity::Warn, _ => Severity::Error })
+///     }
+///     fn transition_map(&self, count: &u8, c: &char) -> Result<u8, String> {
+///         Ok(if *c == '!' { count.saturating_add(1) } else { *count })
+///     }
+/// }
+///
+/// // Errors on an empty input, otherwise informational
+/// struct NonEmpty;
+///
+/// impl DeterministicAutomatonBlueprint for NonEmpty {
+///     type State = bool; type Alphabet = char; type StateSort = Severity; type ErrorType = String;
+///     fn initial_state(&self) -> bool { false }
+///     fn state_sort_map(&self, seen: &bool) -> Result<Severity, String> {
+///         Ok(if *seen { Severity::Info } else { Severity::Error })
+///     }
+///     fn transition_map(&self, _: &bool, _: &char) -> Result<bool, String> { Ok(true) }
+/// }
+///
+/// let worst: Box<DynamicAutomatonBlueprint<char, Severity, String>> =
+///     dyn_reduce(Box::new(Exclamations), Box::new(NonEmpty), Reduce::Max);
+///
+/// assert_eq!(worst.characterise(&[]), Ok(Severity::Error));
+/// assert_eq!(worst.characterise(&['h', 'i']), Ok(Severity::Info));
+/// assert_eq!(worst.characterise(&['h', 'i', '!']), Ok(Severity::Warn));
+/// ```
+pub fn dyn_reduce<Alphabet, StateSort, ErrorType>(first: Box<DynamicAutomatonBlueprint<Alphabet, StateSort, ErrorType>>, second: Box<DynamicAutomatonBlueprint<Alphabet, StateSort, ErrorType>>, reduce: Reduce) -> Box<DynamicAutomatonBlueprint<Alphabet, StateSort, ErrorType>>
+where
+    Alphabet: PartialEq + 'static,
+    StateSort: OrderedSort + 'static,
+    ErrorType: 'static
+{
+    Box::new(DynamicReduceAutomatonBlueprint::new(first, second, reduce))
+}
@@ -26,8 +26,55 @@
 //!     Either::Right(union_automaton)
 //! };
 //! ```
+//!
+//! # Combined Runtime
+//!
+//! Running an [`Either`] through [`DeterministicAutomaton`] pairs the blueprint with an
+//! `Either` state, so the blueprint implementation must handle a state from the other side,
+//! which it reports as `ErrorType::default()`. [`EitherAutomaton`] instead fixes the side
+//! once at construction and holds a runtime for that side only, so a mismatch cannot occur
+//! and no `Default` bound on the error type is needed.
+//!
+//! ```
+//! use deterministic_automata::BasicStateSort;
+//! use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+//! use deterministic_automata::either_automaton::deterministic::Either;
+//!
+//! let chosen: Either<_, CounterAutomatonBlueprint<char>> = Either::Left(CounterAutomatonBlueprint::new('a', 'b'));
+//! let mut automaton = chosen.either_automaton();
+//!
+//! assert_eq!(automaton.update_sort_state(&'a').unwrap(), BasicStateSort::Reject);
+//! assert_eq!(automaton.update_sort_state(&'b').unwrap(), BasicStateSort::Accept);
+//! ```
+//!
+//! # Inside Products
+//!
+//! Because [`Either`] is itself a blueprint, a runtime choice can be one component of a
+//! product alongside a fixed automaton. The product combinators require both components to
+//! share an alphabet and error type, and [`Either`] additionally requires that error type to
+//! implement [`Default`] for the mismatch case above; `String` and `()` both qualify.
+//! Automata whose error type has no sensible default cannot be placed inside an [`Either`]
+//! blueprint, so run them through [`EitherAutomaton`] and combine results by hand instead.
+//!
+//! ```
+//! use deterministic_automata::{DeterministicAutomatonBlueprint, BasicStateSort};
+//! use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, TolerantCounterBlueprint};
+//! use deterministic_automata::product_automaton::BasicIntersectionAutomatonBlueprint;
+//! use deterministic_automata::either_automaton::deterministic::Either;
+//!
+//! let fixed = CounterAutomatonBlueprint::new('a', 'b');
+//! let strict_mode = false;
+//! let chosen: Either<TolerantCounterBlueprint<char>, CounterAutomatonBlueprint<char>> = if strict_mode {
+//!     Either::Left(CounterAutomatonBlueprint::with_tolerance('a', 'b', 0..=0))
+//! } else {
+//!     Either::Right(CounterAutomatonBlueprint::new('a', 'b'))
+//! };
+//!
+//! let both = BasicIntersectionAutomatonBlueprint::new(&fixed, &chosen);
+//! assert_eq!(both.characterise(&['a', 'b']).unwrap(), BasicStateSort::Accept);
+//! ```
 
-use crate::DeterministicAutomatonBlueprint;
+use crate::{DeterministicAutomaton, DeterministicAutomatonBlueprint};
 
 /// A sum type representing a choice between two values for deterministic automata.
 ///
@@ -82,4 +129,95 @@ where
             (Either::Right(blueprint), Either::Right(state)) => Ok(Either::Right(blueprint.transition_map(state, character)?)),
         }
     }
-}
\ No newline at end of file
+
+    fn transition_in_place(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        match (self,state) {
+            (Either::Left(blueprint), Either::Left(state)) => blueprint.transition_in_place(state, character),
+            (Either::Left(_), Either::Right(_)) => Err(Default::default()),
+            (Either::Right(_), Either::Left(_)) => Err(Default::default()),
+            (Either::Right(blueprint), Either::Right(state)) => blueprint.transition_in_place(state, character),
+        }
+    }
+}
+
+impl<A, B, StateSort, Alphabet, ErrorType> Either<A, B>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = StateSort, Alphabet = Alphabet, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<StateSort = StateSort, Alphabet = Alphabet, ErrorType = ErrorType>
+{
+    /// Creates a runtime for the chosen side of this blueprint.
+    ///
+    /// See [`EitherAutomaton`] for how this differs from running the blueprint through
+    /// [`DeterministicAutomaton`].
+    pub fn either_automaton(&self) -> EitherAutomaton<'_, A, B> {
+        EitherAutomaton::new(self)
+    }
+}
+
+/// A runtime instance for the chosen side of an [`Either`] blueprint.
+///
+/// The side is fixed once at construction and only that side's runtime is stored, so a
+/// blueprint can never be paired with the other side's state. Unlike running an `Either`
+/// through [`DeterministicAutomaton`], this requires no `Default` bound on the error type.
+pub enum EitherAutomaton<'a, A, B>
+where
+    A: DeterministicAutomatonBlueprint,
+    B: DeterministicAutomatonBlueprint
+{
+    /// A runtime for the left blueprint.
+    Left(DeterministicAutomaton<'a, A>),
+    /// A runtime for the right blueprint.
+    Right(DeterministicAutomaton<'a, B>)
+}
+
+impl<'a, A, B, StateSort, Alphabet, ErrorType> EitherAutomaton<'a, A, B>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = StateSort, Alphabet = Alphabet, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<StateSort = StateSort, Alphabet = Alphabet, ErrorType = ErrorType>
+{
+    /// Creates a runtime for the chosen side of `blueprint`, starting in its initial state.
+    pub fn new(blueprint: &'a Either<A, B>) -> Self {
+        match blueprint {
+            Either::Left(blueprint) => EitherAutomaton::Left(blueprint.automaton()),
+            Either::Right(blueprint) => EitherAutomaton::Right(blueprint.automaton()),
+        }
+    }
+
+    /// Returns the classification of the current state.
+    pub fn current_state_sort(&self) -> Result<StateSort, ErrorType> {
+        match self {
+            EitherAutomaton::Left(automaton) => automaton.current_state_sort(),
+            EitherAutomaton::Right(automaton) => automaton.current_state_sort(),
+        }
+    }
+
+    /// Processes a single input symbol, updating the current state.
+    pub fn update_state(&mut self, character: &Alphabet) -> Result<(), ErrorType> {
+        match self {
+            EitherAutomaton::Left(automaton) => automaton.update_state(character),
+            EitherAutomaton::Right(automaton) => automaton.update_state(character),
+        }
+    }
+
+    /// Processes a single input symbol and returns the new state classification.
+    pub fn update_sort_state(&mut self, character: &Alphabet) -> Result<StateSort, ErrorType> {
+        self.update_state(character)?;
+        self.current_state_sort()
+    }
+
+    /// Returns the automaton to the chosen blueprint's initial state.
+    pub fn reset(&mut self) {
+        match self {
+            EitherAutomaton::Left(automaton) => automaton.reset(),
+            EitherAutomaton::Right(automaton) => automaton.reset(),
+        }
+    }
+
+    /// Returns the classification the automaton would have after a symbol, without consuming it.
+    pub fn try_transition(&self, character: &Alphabet) -> Result<StateSort, ErrorType> {
+        match self {
+            EitherAutomaton::Left(automaton) => automaton.try_transition(character),
+            EitherAutomaton::Right(automaton) => automaton.try_transition(character),
+        }
+    }
+}
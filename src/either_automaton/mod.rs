@@ -9,6 +9,14 @@
 //! * [`deterministic`] - Either type for deterministic automaton blueprints
 //! * [`mutation`] - Either type for mutation automaton blueprints
 //!
+//! # Provided Methods
+//!
+//! Each `Either` implements its paradigm's blueprint trait, so every provided method of that
+//! trait, such as [`characterise_zip`](crate::DeterministicAutomatonBlueprint::characterise_zip)
+//! or [`state_path`](crate::DeterministicAutomatonBlueprint::state_path), works on a chosen
+//! blueprint without any Either-specific code. The `EitherAutomaton` runtimes offer the
+//! stepping and dry-run methods of the base runtimes.
+//!
 //! # Example: Runtime Selection Between Different Automaton Types
 //!
 //! ```
@@ -36,8 +36,14 @@
 //!     Either::Right(complex_automaton)
 //! };
 //! ```
+//!
+//! # Combined Runtime
+//!
+//! As in the [`deterministic`](super::deterministic) submodule, [`EitherAutomaton`] fixes the
+//! chosen side once at construction, so a blueprint is never paired with the other side's
+//! state and no `Default` bound on the error type is needed.
 
-use crate::MutationAutomatonBlueprint;
+use crate::{MutationAutomaton, MutationAutomatonBlueprint};
 
 /// A sum type representing a choice between two values for mutation automata.
 ///
@@ -91,4 +97,86 @@ where
             (Either::Right(blueprint), Either::Right(state)) => blueprint.mutation_transition_map(state, character),
         }
     }
-}
\ No newline at end of file
+}
+
+impl<A, B, StateSort, Alphabet, ErrorType> Either<A, B>
+where
+    A: MutationAutomatonBlueprint<StateSort = StateSort, Alphabet = Alphabet, ErrorType = ErrorType>,
+    B: MutationAutomatonBlueprint<StateSort = StateSort, Alphabet = Alphabet, ErrorType = ErrorType>
+{
+    /// Creates a runtime for the chosen side of this blueprint.
+    ///
+    /// See [`EitherAutomaton`] for how this differs from running the blueprint through
+    /// [`MutationAutomaton`].
+    pub fn either_automaton(&self) -> EitherAutomaton<'_, A, B> {
+        EitherAutomaton::new(self)
+    }
+}
+
+/// A runtime instance for the chosen side of a mutation [`Either`] blueprint.
+///
+/// The side is fixed once at construction and only that side's runtime is stored, so a
+/// blueprint can never be paired with the other side's state. Unlike running an `Either`
+/// through [`MutationAutomaton`], this requires no `Default` bound on the error type.
+pub enum EitherAutomaton<'a, A, B>
+where
+    A: MutationAutomatonBlueprint,
+    B: MutationAutomatonBlueprint
+{
+    /// A runtime for the left blueprint.
+    Left(MutationAutomaton<'a, A>),
+    /// A runtime for the right blueprint.
+    Right(MutationAutomaton<'a, B>)
+}
+
+impl<'a, A, B, StateSort, Alphabet, ErrorType> EitherAutomaton<'a, A, B>
+where
+    A: MutationAutomatonBlueprint<StateSort = StateSort, Alphabet = Alphabet, ErrorType = ErrorType>,
+    B: MutationAutomatonBlueprint<StateSort = StateSort, Alphabet = Alphabet, ErrorType = ErrorType>
+{
+    /// Creates a runtime for the chosen side of `blueprint`, starting in its initial state.
+    pub fn new(blueprint: &'a Either<A, B>) -> Self {
+        match blueprint {
+            Either::Left(blueprint) => EitherAutomaton::Left(blueprint.mutation_automaton()),
+            Either::Right(blueprint) => EitherAutomaton::Right(blueprint.mutation_automaton()),
+        }
+    }
+
+    /// Returns the classification of the current state.
+    pub fn current_state_sort(&self) -> Result<StateSort, ErrorType> {
+        match self {
+            EitherAutomaton::Left(automaton) => automaton.current_state_sort(),
+            EitherAutomaton::Right(automaton) => automaton.current_state_sort(),
+        }
+    }
+
+    /// Processes a single input symbol, updating the current state in-place.
+    pub fn update_state(&mut self, character: &Alphabet) -> Result<(), ErrorType> {
+        match self {
+            EitherAutomaton::Left(automaton) => automaton.update_state(character),
+            EitherAutomaton::Right(automaton) => automaton.update_state(character),
+        }
+    }
+
+    /// Processes a single input symbol and returns the new state classification.
+    pub fn update_sort_state(&mut self, character: &Alphabet) -> Result<StateSort, ErrorType> {
+        self.update_state(character)?;
+        self.current_state_sort()
+    }
+
+    /// Returns the automaton to the chosen blueprint's initial state.
+    pub fn reset(&mut self) {
+        match self {
+            EitherAutomaton::Left(automaton) => automaton.reset(),
+            EitherAutomaton::Right(automaton) => automaton.reset(),
+        }
+    }
+
+    /// Returns the classification the automaton would have after a symbol, without consuming it.
+    pub fn try_transition(&self, character: &Alphabet) -> Result<StateSort, ErrorType> {
+        match self {
+            EitherAutomaton::Left(automaton) => automaton.try_transition(character),
+            EitherAutomaton::Right(automaton) => automaton.try_transition(character),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Declaring small, closed alphabets so they can be enumerated automatically.
+//!
+//! Operations that explore the state space of an automaton, such as those in
+//! [`operations`](crate::operations), take every symbol that can be read as an `alphabet`
+//! slice. For alphabets that are small and closed, such as `bool`, bytes or a small enum,
+//! the [`FiniteAlphabet`] trait lists every symbol once, so that slice can be produced from
+//! the type with [`alphabet`](FiniteAlphabet::alphabet) instead of being written out by hand.
+//!
+//! # Example
+//!
+//! ```
+//! use deterministic_automata::FiniteAlphabet;
+//!
+//! #[derive(Clone, PartialEq, Debug)]
+//! enum Bracket {
+//!     Open,
+//!     Close,
+//! }
+//!
+//! impl FiniteAlphabet for Bracket {
+//!     fn alphabet() -> Vec<Self> {
+//!         vec![Bracket::Open, Bracket::Close]
+//!     }
+//! }
+//!
+//! assert_eq!(Bracket::alphabet().len(), 2);
+//! assert_eq!(u8::alphabet().len(), 256);
+//!
+//! // Supplies the alphabet argument of the enumeration operations
+//! use deterministic_automata::operations::state_count;
+//! use deterministic_automata::primitives::LengthModBlueprint;
+//!
+//! let triples: LengthModBlueprint<bool> = LengthModBlueprint::new(3);
+//! assert_eq!(state_count(&triples, &bool::alphabet(), None), Ok(Some(3)));
+//! ```
+
+/// A symbol type with finitely many values, all of which can be listed.
+///
+/// # Required Methods
+///
+/// * [`alphabet`](Self::alphabet) - Returns every symbol exactly once
+pub trait FiniteAlphabet: Sized + PartialEq {
+    /// Returns every value of this type exactly once.
+    ///
+    /// The order is unspecified but should be stable, so that enumeration-based operations
+    /// produce deterministic output.
+    fn alphabet() -> Vec<Self>;
+}
+
+impl FiniteAlphabet for () {
+    fn alphabet() -> Vec<Self> {
+        vec![()]
+    }
+}
+
+impl FiniteAlphabet for bool {
+    fn alphabet() -> Vec<Self> {
+        vec![false, true]
+    }
+}
+
+impl FiniteAlphabet for u8 {
+    fn alphabet() -> Vec<Self> {
+        (0..=u8::MAX).collect()
+    }
+}
+
+impl FiniteAlphabet for i8 {
+    fn alphabet() -> Vec<Self> {
+        (i8::MIN..=i8::MAX).collect()
+    }
+}
+
+impl<T: FiniteAlphabet> FiniteAlphabet for Option<T> {
+    fn alphabet() -> Vec<Self> {
+        std::iter::once(None).chain(T::alphabet().into_iter().map(Some)).collect()
+    }
+}
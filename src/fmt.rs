@@ -0,0 +1,65 @@
+//! Plain-text rendering of recognition traces.
+//!
+//! When a recognizer misbehaves, the most useful thing to look at is usually the
+//! classification after each symbol. [`format_trace`] lays out the symbols and
+//! classifications produced by
+//! [`characterise_zip`](crate::DeterministicAutomatonBlueprint::characterise_zip) or
+//! [`characterise_trace_record`](crate::DeterministicAutomatonBlueprint::characterise_trace_record)
+//! as a two-row table that can be pasted into a bug report or a slide.
+//!
+//! # Example
+//!
+//! ```
+//! use deterministic_automata::DeterministicAutomatonBlueprint;
+//! use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+//! use deterministic_automata::fmt::format_trace;
+//!
+//! let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+//! let trace = blueprint.characterise_trace_record(&['a', 'b']).unwrap();
+//!
+//! assert_eq!(format_trace(&trace.symbols, &trace.sorts), "\
+//! a      | b
+//! Reject | Accept");
+//! ```
+
+use std::fmt::Display;
+
+/// Renders symbols and the classifications after them as an aligned two-row table.
+///
+/// Column `i` holds `symbols[i]` above `sorts[i]`, padded to the wider of the two, with
+/// columns separated by ` | `. If the slices have different lengths, the missing cells are
+/// left blank. Trailing spaces are omitted and the rows are joined by a newline without a
+/// final one, so an empty trace renders as two empty rows.
+///
+/// # Parameters
+///
+/// * `symbols` - The input symbols, shown in the top row
+/// * `sorts` - The classifications after each symbol, shown in the bottom row
+pub fn format_trace<A: Display, SS: Display>(symbols: &[A], sorts: &[SS]) -> String {
+    let top: Vec<String> = symbols.iter().map(ToString::to_string).collect();
+    let bottom: Vec<String> = sorts.iter().map(ToString::to_string).collect();
+    let columns = top.len().max(bottom.len());
+    let widths: Vec<usize> = (0..columns)
+        .map(|i| {
+            let cell_width = |row: &[String]| row.get(i).map_or(0, |cell| cell.chars().count());
+            cell_width(&top).max(cell_width(&bottom))
+        })
+        .collect();
+
+    let render_row = |row: &[String]| {
+        let mut line = String::new();
+        for (i, width) in widths.iter().enumerate() {
+            if i > 0 {
+                line.push_str(" | ");
+            }
+            let cell = row.get(i).map_or("", String::as_str);
+            line.push_str(cell);
+            if i + 1 < columns {
+                line.extend(std::iter::repeat_n(' ', width - cell.chars().count()));
+            }
+        }
+        line.trim_end().to_string()
+    };
+
+    format!("{}\n{}", render_row(&top), render_row(&bottom))
+}
@@ -0,0 +1,256 @@
+//! Closure-based blueprints for defining automata inline.
+//!
+//! Implementing [`DeterministicAutomatonBlueprint`] normally requires declaring a struct and an
+//! impl block. For quick prototypes, tests and examples this module provides [`FnBlueprint`],
+//! which holds one closure per required method and implements the trait by calling them.
+//! [`FnMutationBlueprint`] is the mutation paradigm counterpart, implementing
+//! [`MutationAutomatonBlueprint`] with an in-place transition closure.
+//!
+//! # Type Inference
+//!
+//! The closure argument types are inferred from the bounds on [`FnBlueprint::new`], so only
+//! the types that cannot be deduced need annotating. Typically this is the error type when
+//! every closure returns `Ok`, which can be fixed with `Ok::<_, String>(...)` in one of them.
+//! The same applies to [`FnMutationBlueprint::new`].
+//!
+//! # Example
+//!
+//! ```
+//! use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+//! use deterministic_automata::fn_automaton::FnBlueprint;
+//!
+//! // Accepts words over {'0', '1'} whose binary value is divisible by three
+//! let divisible_by_three = FnBlueprint::new(
+//!     || 0u8,
+//!     |remainder| Ok(if *remainder == 0 { BasicStateSort::Accept } else { BasicStateSort::Reject }),
+//!     |remainder, digit: &char| match digit {
+//!         '0' => Ok((remainder * 2) % 3),
+//!         '1' => Ok((remainder * 2 + 1) % 3),
+//!         _ => Err(format!("Not a binary digit: {}", digit)),
+//!     },
+//! );
+//!
+//! assert_eq!(divisible_by_three.characterise(&['1', '1', '0']).unwrap(), BasicStateSort::Accept);
+//! assert_eq!(divisible_by_three.characterise(&['1', '0', '0']).unwrap(), BasicStateSort::Reject);
+//! assert!(divisible_by_three.characterise(&['2']).is_err());
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::{DeterministicAutomatonBlueprint, MutationAutomatonBlueprint};
+
+/// A deterministic automaton blueprint defined by three closures.
+///
+/// # Type Parameters
+///
+/// * `State`, `Alphabet`, `StateSort`, `ErrorType` - The associated types of the blueprint
+/// * `I` - Closure returning the initial state
+/// * `S` - Closure classifying a state
+/// * `T` - Closure computing the next state
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) with the three closures.
+pub struct FnBlueprint<State, Alphabet, StateSort, ErrorType, I, S, T>
+where
+    I: Fn() -> State,
+    S: Fn(&State) -> Result<StateSort, ErrorType>,
+    T: Fn(&State, &Alphabet) -> Result<State, ErrorType>
+{
+    initial: I,
+    sort: S,
+    transition: T,
+    #[allow(clippy::type_complexity)]
+    types: PhantomData<fn(&State, &Alphabet) -> (State, StateSort, ErrorType)>
+}
+
+impl<State, Alphabet, StateSort, ErrorType, I, S, T> FnBlueprint<State, Alphabet, StateSort, ErrorType, I, S, T>
+where
+    I: Fn() -> State,
+    S: Fn(&State) -> Result<StateSort, ErrorType>,
+    T: Fn(&State, &Alphabet) -> Result<State, ErrorType>
+{
+    /// Creates a new blueprint from closures for each required method.
+    ///
+    /// # Parameters
+    ///
+    /// * `initial` - Returns the initial state, as [`initial_state`](DeterministicAutomatonBlueprint::initial_state)
+    /// * `sort` - Classifies a state, as [`state_sort_map`](DeterministicAutomatonBlueprint::state_sort_map)
+    /// * `transition` - Computes the next state, as [`transition_map`](DeterministicAutomatonBlueprint::transition_map)
+    pub fn new(initial: I, sort: S, transition: T) -> Self {
+        Self {
+            initial,
+            sort,
+            transition,
+            types: PhantomData
+        }
+    }
+}
+
+// Written by hand so that only the closures need to be cloneable: a derive would also
+// require the phantom state, alphabet, sort and error types to be `Clone`.
+impl<State, Alphabet, StateSort, ErrorType, I, S, T> Clone for FnBlueprint<State, Alphabet, StateSort, ErrorType, I, S, T>
+where
+    I: Fn() -> State + Clone,
+    S: Fn(&State) -> Result<StateSort, ErrorType> + Clone,
+    T: Fn(&State, &Alphabet) -> Result<State, ErrorType> + Clone
+{
+    fn clone(&self) -> Self {
+        Self {
+            initial: self.initial.clone(),
+            sort: self.sort.clone(),
+            transition: self.transition.clone(),
+            types: PhantomData
+        }
+    }
+}
+
+impl<State, Alphabet, StateSort, ErrorType, I, S, T> DeterministicAutomatonBlueprint for FnBlueprint<State, Alphabet, StateSort, ErrorType, I, S, T>
+where
+    State: Clone,
+    Alphabet: PartialEq,
+    I: Fn() -> State,
+    S: Fn(&State) -> Result<StateSort, ErrorType>,
+    T: Fn(&State, &Alphabet) -> Result<State, ErrorType>
+{
+    type State = State;
+
+    type Alphabet = Alphabet;
+
+    type StateSort = StateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        (self.initial)()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort,Self::ErrorType> {
+        (self.sort)(state)
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        (self.transition)(state, character)
+    }
+}
+
+/// A mutation automaton blueprint defined by three closures.
+///
+/// The mutation paradigm counterpart of [`FnBlueprint`]: the transition closure receives the
+/// state by mutable reference and updates it in-place.
+///
+/// # Type Parameters
+///
+/// * `State`, `Alphabet`, `StateSort`, `ErrorType` - The associated types of the blueprint
+/// * `I` - Closure returning the initial state
+/// * `S` - Closure classifying a state
+/// * `T` - Closure updating the state in-place
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) with the three closures.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, MutationAutomatonBlueprint};
+/// use deterministic_automata::fn_automaton::FnMutationBlueprint;
+///
+/// // Accepts words whose '+' and '-' symbols never dip below zero and end at zero
+/// let counter = FnMutationBlueprint::new(
+///     || 0i32,
+///     |count| Ok(if *count == 0 { BasicStateSort::Accept } else { BasicStateSort::Reject }),
+///     |count, symbol: &char| {
+///         match symbol {
+///             '+' => *count += 1,
+///             '-' if *count > 0 => *count -= 1,
+///             _ => return Err(format!("Unexpected {}", symbol)),
+///         }
+///         Ok(())
+///     },
+/// );
+///
+/// assert_eq!(counter.mutation_characterise(&['+', '+', '-', '-']).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(counter.mutation_characterise(&['+']).unwrap(), BasicStateSort::Reject);
+/// assert!(counter.mutation_characterise(&['-']).is_err());
+/// ```
+pub struct FnMutationBlueprint<State, Alphabet, StateSort, ErrorType, I, S, T>
+where
+    I: Fn() -> State,
+    S: Fn(&State) -> Result<StateSort, ErrorType>,
+    T: Fn(&mut State, &Alphabet) -> Result<(), ErrorType>
+{
+    initial: I,
+    sort: S,
+    transition: T,
+    #[allow(clippy::type_complexity)]
+    types: PhantomData<fn(&State, &Alphabet) -> (State, StateSort, ErrorType)>
+}
+
+impl<State, Alphabet, StateSort, ErrorType, I, S, T> FnMutationBlueprint<State, Alphabet, StateSort, ErrorType, I, S, T>
+where
+    I: Fn() -> State,
+    S: Fn(&State) -> Result<StateSort, ErrorType>,
+    T: Fn(&mut State, &Alphabet) -> Result<(), ErrorType>
+{
+    /// Creates a new mutation blueprint from closures for each required method.
+    ///
+    /// # Parameters
+    ///
+    /// * `initial` - Returns the initial state, as [`initial_mutation_state`](MutationAutomatonBlueprint::initial_mutation_state)
+    /// * `sort` - Classifies a state, as [`mutation_state_sort_map`](MutationAutomatonBlueprint::mutation_state_sort_map)
+    /// * `transition` - Updates the state in-place, as [`mutation_transition_map`](MutationAutomatonBlueprint::mutation_transition_map)
+    pub fn new(initial: I, sort: S, transition: T) -> Self {
+        Self {
+            initial,
+            sort,
+            transition,
+            types: PhantomData
+        }
+    }
+}
+
+impl<State, Alphabet, StateSort, ErrorType, I, S, T> Clone for FnMutationBlueprint<State, Alphabet, StateSort, ErrorType, I, S, T>
+where
+    I: Fn() -> State + Clone,
+    S: Fn(&State) -> Result<StateSort, ErrorType> + Clone,
+    T: Fn(&mut State, &Alphabet) -> Result<(), ErrorType> + Clone
+{
+    fn clone(&self) -> Self {
+        Self {
+            initial: self.initial.clone(),
+            sort: self.sort.clone(),
+            transition: self.transition.clone(),
+            types: PhantomData
+        }
+    }
+}
+
+impl<State, Alphabet, StateSort, ErrorType, I, S, T> MutationAutomatonBlueprint for FnMutationBlueprint<State, Alphabet, StateSort, ErrorType, I, S, T>
+where
+    State: Clone,
+    Alphabet: PartialEq,
+    I: Fn() -> State,
+    S: Fn(&State) -> Result<StateSort, ErrorType>,
+    T: Fn(&mut State, &Alphabet) -> Result<(), ErrorType>
+{
+    type State = State;
+
+    type Alphabet = Alphabet;
+
+    type StateSort = StateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_mutation_state(&self) -> Self::State {
+        (self.initial)()
+    }
+
+    fn mutation_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort,Self::ErrorType> {
+        (self.sort)(state)
+    }
+
+    fn mutation_transition_map(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(),Self::ErrorType> {
+        (self.transition)(state, character)
+    }
+}
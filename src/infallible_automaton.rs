@@ -0,0 +1,141 @@
+//! Result-free blueprints for automata that never fail validation.
+//!
+//! Many automata are total: every state is valid and every transition succeeds. For these,
+//! the `Result` plumbing of [`DeterministicAutomatonBlueprint`] is noise. This module provides
+//! the [`InfallibleAutomatonBlueprint`] trait with non-`Result` methods, together with a
+//! blanket implementation making every infallible blueprint a deterministic blueprint with
+//! [`Infallible`] as its error type.
+//!
+//! # Key Differences from Deterministic Automata
+//!
+//! - **No Error Type**: `transition` and `sort` return values directly
+//! - **Interoperability**: All infallible automata automatically work as deterministic
+//!   (and therefore mutation and dynamic) automata with `ErrorType = Infallible`
+//!
+//! # Example
+//!
+//! ```
+//! use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint, InfallibleAutomatonBlueprint};
+//!
+//! #[derive(Clone, PartialEq, Debug)]
+//! enum ContainsDoubleZeroState {
+//!     Start,
+//!     SawZero,
+//!     Found,
+//! }
+//!
+//! struct ContainsDoubleZero;
+//!
+//! impl InfallibleAutomatonBlueprint for ContainsDoubleZero {
+//!     type State = ContainsDoubleZeroState;
+//!     type Alphabet = u8;
+//!     type StateSort = BasicStateSort;
+//!
+//!     fn initial(&self) -> Self::State {
+//!         ContainsDoubleZeroState::Start
+//!     }
+//!
+//!     fn sort(&self, state: &Self::State) -> Self::StateSort {
+//!         match state {
+//!             ContainsDoubleZeroState::Found => BasicStateSort::Accept,
+//!             _ => BasicStateSort::Reject,
+//!         }
+//!     }
+//!
+//!     fn transition(&self, state: &Self::State, byte: &Self::Alphabet) -> Self::State {
+//!         match (state, *byte) {
+//!             (ContainsDoubleZeroState::Start, 0) => ContainsDoubleZeroState::SawZero,
+//!             (ContainsDoubleZeroState::Start, _) => ContainsDoubleZeroState::Start,
+//!             (ContainsDoubleZeroState::SawZero, 0) => ContainsDoubleZeroState::Found,
+//!             (ContainsDoubleZeroState::SawZero, _) => ContainsDoubleZeroState::Start,
+//!             (ContainsDoubleZeroState::Found, _) => ContainsDoubleZeroState::Found,
+//!         }
+//!     }
+//! }
+//!
+//! let dfa = ContainsDoubleZero;
+//! assert_eq!(dfa.infallible_characterise(&[1, 0, 0, 2]), BasicStateSort::Accept);
+//!
+//! // Still usable anywhere a deterministic blueprint is expected
+//! assert_eq!(dfa.characterise(&[1, 0, 1]), Ok(BasicStateSort::Reject));
+//! ```
+
+use std::convert::Infallible;
+
+use crate::DeterministicAutomatonBlueprint;
+
+/// A blueprint for defining total automata whose transitions and classifications never fail.
+///
+/// # Associated Types
+///
+/// * `State` - The type representing internal automaton states. Must be `Clone`.
+/// * `Alphabet` - The type of input symbols. Must support equality comparison.
+/// * `StateSort` - The classification type for states (e.g., Accept/Reject).
+///
+/// # Required Methods
+///
+/// * [`initial`](Self::initial) - Returns the starting state
+/// * [`sort`](Self::sort) - Classifies a state
+/// * [`transition`](Self::transition) - Defines state transitions
+///
+/// # Provided Methods
+///
+/// * [`infallible_characterise`](Self::infallible_characterise) - Processes an entire input sequence
+///
+/// # Interoperability
+///
+/// All types implementing `InfallibleAutomatonBlueprint` automatically implement
+/// [`DeterministicAutomatonBlueprint`] with `ErrorType = Infallible`, so they compose with
+/// the product constructions and other error-carrying blueprints sharing that error type.
+pub trait InfallibleAutomatonBlueprint {
+    /// The type representing internal automaton states.
+    type State: Clone;
+
+    /// The type of input symbols that the automaton processes.
+    type Alphabet: PartialEq;
+
+    /// The classification type for states.
+    type StateSort;
+
+    /// Returns the initial state of the automaton.
+    fn initial(&self) -> Self::State;
+
+    /// Maps a state to its classification.
+    fn sort(&self, state: &Self::State) -> Self::StateSort;
+
+    /// Defines the transition function.
+    ///
+    /// Given a current state and an input symbol, returns the next state.
+    fn transition(&self, state: &Self::State, character: &Self::Alphabet) -> Self::State;
+
+    /// Processes an entire input sequence and returns the final state classification.
+    fn infallible_characterise(&self, word: &[Self::Alphabet]) -> Self::StateSort {
+        let mut state = self.initial();
+        for character in word {
+            state = self.transition(&state, character);
+        }
+        self.sort(&state)
+    }
+}
+
+impl<Blueprint: InfallibleAutomatonBlueprint> DeterministicAutomatonBlueprint for Blueprint {
+    type State = Blueprint::State;
+
+    type Alphabet = Blueprint::Alphabet;
+
+    type StateSort = Blueprint::StateSort;
+
+    type ErrorType = Infallible;
+
+    fn initial_state(&self) -> Self::State {
+        self.initial()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort,Self::ErrorType> {
+        Ok(self.sort(state))
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok(self.transition(state, character))
+    }
+}
@@ -22,6 +22,11 @@
 //! Demonstrates recognition of the context-free language a^n b^n using counter-based
 //! states, showcasing capabilities beyond regular languages.
 //!
+//! ## [`mod_counter_example`]
+//!
+//! A finite-state counterpart to the counter example: recognizes digit sequences whose
+//! digit sum is divisible by a modulus, tracking the running sum modulo that modulus.
+//!
 //! ## [`product_automaton`]
 //!
 //! Provides product construction blueprints for combining automata, including general
@@ -46,6 +51,84 @@
 //! different state types. Solves the trait object compatibility problem by erasing
 //! only the state type while keeping alphabet, state sort, and error types concrete.
 //!
+//! ## [`infallible_automaton`]
+//!
+//! Provides the [`InfallibleAutomatonBlueprint`] trait for total automata that never fail
+//! validation, with a blanket implementation making them deterministic blueprints whose
+//! error type is [`Infallible`](std::convert::Infallible).
+//!
+//! ## [`fn_automaton`]
+//!
+//! Provides [`FnBlueprint`](fn_automaton::FnBlueprint) and
+//! [`FnMutationBlueprint`](fn_automaton::FnMutationBlueprint), blueprints defined by closures
+//! for each required method, for one-off automata in prototypes, tests and examples.
+//!
+//! ## [`window_automaton`]
+//!
+//! Provides [`WindowBlueprint`](window_automaton::WindowBlueprint), whose state is the last
+//! `k` symbols read, classified by a caller-supplied predicate. This covers suffix and
+//! window recognizers without a hand-written state enum.
+//!
+//! ## [`finite_alphabet`]
+//!
+//! Provides the [`FiniteAlphabet`] trait for small, closed symbol types whose values can be
+//! listed, to supply the alphabet that operations enumerating states require.
+//!
+//! ## [`describable_automaton`]
+//!
+//! Provides the opt-in [`DescribableAutomaton`] trait for blueprints that can cheaply name a
+//! canonical accepted word, for documentation and example generation.
+//!
+//! ## [`lookahead_automaton`]
+//!
+//! Provides the [`LookaheadBlueprint`] trait for recognizers whose transitions inspect a
+//! bounded window of upcoming symbols and report how many they consumed.
+//!
+//! ## [`timed_automaton`]
+//!
+//! Provides the [`TimedAutomatonBlueprint`] trait for recognizers whose transitions depend
+//! on the time elapsed between symbols, as supplied by the caller.
+//!
+//! ## [`primitives`]
+//!
+//! Provides ready-made blueprints for frequently needed structural constraints, such as
+//! [`NoRepeatsBlueprint`](primitives::NoRepeatsBlueprint) forbidding adjacent repeats or
+//! [`AtMostKBlueprint`](primitives::AtMostKBlueprint) bounding how often a symbol occurs,
+//! or [`LengthModBlueprint`](primitives::LengthModBlueprint) fixing the length modulo a number,
+//! for combining with other recognizers through intersection.
+//!
+//! ## [`fmt`]
+//!
+//! Provides [`format_trace`](fmt::format_trace), rendering a recorded run as an aligned
+//! table of symbols and classifications for bug reports and teaching material.
+//!
+//! ## `testing`
+//!
+//! Available with the `testing` feature. Provides assertions that a blueprint's transitions are
+//! deterministic and a seeded generator of random words, for fuzzing blueprints in tests.
+//!
+//! ## [`operations`]
+//!
+//! Provides lazy operations and adapters that wrap an existing blueprint without enumerating
+//! its states, such as the complement of a [`BasicStateSort`] automaton or starting a
+//! blueprint from a custom initial state.
+//!
+//! # Thread Safety
+//!
+//! None of the provided types use interior mutability or reference counting, so `Send` and
+//! `Sync` are derived automatically from their components:
+//!
+//! - Blueprints such as [`CounterAutomatonBlueprint`](counter_automaton_example::CounterAutomatonBlueprint)
+//!   are `Send`/`Sync` when their alphabet is.
+//! - Product and adapter blueprints hold shared references to their components, and are
+//!   `Send`/`Sync` when those components are `Sync`.
+//! - [`DeterministicAutomaton`] and [`MutationAutomaton`] hold a shared reference to their
+//!   blueprint and an owned state, and are `Send` when the blueprint is `Sync` and the state
+//!   is `Send`, so a run can be moved to another thread.
+//! - Dynamic blueprints built from boxed trait objects, such as
+//!   [`DynamicIntersectionAutomatonBlueprint`](dynamic_automaton::DynamicIntersectionAutomatonBlueprint),
+//!   are not `Send` or `Sync`, since the trait objects carry no such bounds.
+//!
 //! # Examples
 //!
 //! ## Simple Context-Free Language Recognition
@@ -204,13 +287,35 @@
 //! and compositions of multiple automata, maintaining deterministic behavior throughout.
 
 pub mod counter_automaton_example;
+pub mod mod_counter_example;
 pub mod product_automaton;
 pub mod either_automaton;
 pub mod mutation_automaton;
 pub mod dynamic_automaton;
+pub mod operations;
+pub mod infallible_automaton;
+pub mod fn_automaton;
+pub mod window_automaton;
+pub mod finite_alphabet;
+pub mod describable_automaton;
+pub mod lookahead_automaton;
+pub mod timed_automaton;
+pub mod primitives;
+pub mod fmt;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::ControlFlow;
 
 pub use mutation_automaton::{MutationAutomatonBlueprint, MutationAutomaton};
 pub use dynamic_automaton::{DynamicAutomaton, DynamicAutomatonBlueprint};
+pub use infallible_automaton::InfallibleAutomatonBlueprint;
+pub use finite_alphabet::FiniteAlphabet;
+pub use describable_automaton::DescribableAutomaton;
+pub use lookahead_automaton::LookaheadBlueprint;
+pub use timed_automaton::TimedAutomatonBlueprint;
 
 /// A blueprint for defining deterministic automata with custom state and alphabet types.
 ///
@@ -241,7 +346,33 @@ pub use dynamic_automaton::{DynamicAutomaton, DynamicAutomatonBlueprint};
 ///
 /// # Provided Methods
 ///
+/// * [`validate_state`](Self::validate_state) - Checks a state is valid without classifying it
+/// * [`transition_in_place`](Self::transition_in_place) - Applies a transition by mutating the state, used by the mutation paradigm
 /// * [`characterise`](Self::characterise) - Processes an entire input sequence
+/// * [`characterise_refs`](Self::characterise_refs) - Processes an input sequence given as a slice of references
+/// * [`characterise_rev`](Self::characterise_rev) - Processes an input sequence back-to-front
+/// * [`characterise_controlled`](Self::characterise_controlled) - Processes an input sequence, allowing an observer to stop early
+/// * [`characterise_early_trap`](Self::characterise_early_trap) - Processes an input sequence, stopping once a caller-identified trap state is reached
+/// * [`characterise_zip`](Self::characterise_zip) - Pairs each input symbol with the classification it produced
+/// * [`trace_diff`](Self::trace_diff) - Finds the first symbol whose classification differs from an expected trace
+/// * [`characterise_trace_record`](Self::characterise_trace_record) - Records a run as an owned [`RunTrace`]
+/// * [`characterise_records`](Self::characterise_records) - Processes each of a sequence of records independently
+/// * [`characterise_counted`](Self::characterise_counted) - Processes an input sequence, also counting the transitions performed
+/// * [`state_path`](Self::state_path) - Returns every state visited while processing an input sequence
+/// * [`accepting_snapshots`](Self::accepting_snapshots) - Returns the state at every accepting prefix
+/// * [`sort_histogram`](Self::sort_histogram) - Counts how many prefixes land in each classification
+/// * [`characterise_partial`](Self::characterise_partial) - Processes a prefix and returns the running automaton
+/// * [`validate`](Self::validate) - Processes an input sequence, treating rejection as an error
+/// * [`characterise_decoded`](Self::characterise_decoded) - Processes a raw input sequence through a fallible decoder
+/// * [`accepts_empty`](Self::accepts_empty) - Checks whether the empty word is accepted
+/// * [`with_initial`](Self::with_initial) - Wraps the blueprint to start from a given state
+/// * [`max_length`](Self::max_length) - Restricts the language to words of bounded length
+/// * [`min_length`](Self::min_length) - Restricts the language to words of a minimum length
+/// * [`non_empty`](Self::non_empty) - Removes the empty word from the language
+/// * [`optional`](Self::optional) - Adds the empty word to the language
+/// * [`case_insensitive`](Self::case_insensitive) - Also accepts words whose lowercasing is accepted
+/// * [`errors_as_reject`](Self::errors_as_reject) - Rejects instead of failing on transition errors
+/// * [`track_peak`](Self::track_peak) - Records the peak of a state metric over a run
 ///
 /// # Example: Simple Finite State Automaton
 ///
@@ -342,6 +473,32 @@ pub trait DeterministicAutomatonBlueprint {
     /// would produce an invalid state.
     fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType>;
 
+    /// Applies a transition by updating `state` in place.
+    ///
+    /// The blanket [`MutationAutomatonBlueprint`] implementation uses this for
+    /// [`mutation_transition_map`](MutationAutomatonBlueprint::mutation_transition_map). The
+    /// default implementation builds the next state with
+    /// [`transition_map`](Self::transition_map) and replaces `state` with it, so every step
+    /// constructs a fresh state. Blueprints with large states, such as buffers or maps, can
+    /// override it to mutate the existing state directly, letting mutation runtimes and the
+    /// mutation product constructions avoid that allocation. An override must leave `state`
+    /// equal to what `transition_map` would have returned, and should leave it unchanged
+    /// when returning an error.
+    fn transition_in_place(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        *state = self.transition_map(state, character)?;
+        Ok(())
+    }
+
+    /// Checks that a state is structurally valid, without classifying or running it.
+    ///
+    /// The default implementation delegates to [`state_sort_map`](Self::state_sort_map) and
+    /// discards the classification. Blueprints can override it with a cheaper check. This is
+    /// useful for rejecting externally supplied states, for example before resuming a run
+    /// with [`with_initial`](Self::with_initial).
+    fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.state_sort_map(state).map(|_| ())
+    }
+
     /// Processes an entire input sequence and returns the final state classification.
     ///
     /// Creates a runtime automaton, processes the input sequence, and returns
@@ -358,6 +515,601 @@ pub trait DeterministicAutomatonBlueprint {
         automaton.current_state_sort()
     }
 
+    /// Processes an input sequence of symbol references and returns the final state classification.
+    ///
+    /// Behaves like [`characterise`](Self::characterise), but accepts a slice of references
+    /// so symbols that are expensive to clone need not be collected into an owned slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::{DeterministicAutomatonBlueprint, BasicStateSort, counter_automaton_example::CounterAutomatonBlueprint};
+    ///
+    /// let blueprint = CounterAutomatonBlueprint::new(String::from("open"), String::from("close"));
+    /// let tokens = vec![String::from("open"), String::from("close")];
+    /// let references: Vec<&String> = tokens.iter().collect();
+    ///
+    /// assert_eq!(blueprint.characterise_refs(&references).unwrap(), BasicStateSort::Accept);
+    /// ```
+    fn characterise_refs(&self, word: &[&Self::Alphabet]) -> Result<Self::StateSort, Self::ErrorType>
+    where
+        Self: Sized
+    {
+        let mut automaton = self.automaton();
+        for character in word {
+            automaton.update_state(character)?;
+        }
+        automaton.current_state_sort()
+    }
+
+    /// Processes an input sequence back-to-front and returns the final state classification.
+    ///
+    /// Runs this same automaton over the symbols of `word` in reverse order, without
+    /// allocating a reversed copy. This is not language reversal: the automaton itself is
+    /// unchanged, which suits recognizers designed to read suffixes from the end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::{DeterministicAutomatonBlueprint, BasicStateSort, counter_automaton_example::CounterAutomatonBlueprint};
+    ///
+    /// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    ///
+    /// assert_eq!(blueprint.characterise_rev(&['b', 'a']).unwrap(), BasicStateSort::Accept);
+    /// assert_eq!(blueprint.characterise_rev(&['a', 'b']).unwrap(), BasicStateSort::Reject);
+    /// ```
+    fn characterise_rev(&self, word: &[Self::Alphabet]) -> Result<Self::StateSort, Self::ErrorType>
+    where
+        Self: Sized
+    {
+        let mut automaton = self.automaton();
+        for character in word.iter().rev() {
+            automaton.update_state(character)?;
+        }
+        automaton.current_state_sort()
+    }
+
+    /// Processes an input sequence while allowing an observer to stop early.
+    ///
+    /// After each symbol is consumed, `control` is consulted with the classification of
+    /// the new state. Returning [`ControlFlow::Break`] stops processing immediately and
+    /// returns that classification; otherwise processing continues until the input is
+    /// exhausted and the final classification is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::ops::ControlFlow;
+    /// use deterministic_automata::{DeterministicAutomatonBlueprint, BasicStateSort, counter_automaton_example::CounterAutomatonBlueprint};
+    ///
+    /// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    /// let input: Vec<char> = "abab".chars().collect();
+    ///
+    /// // Stop as soon as the automaton first accepts
+    /// let mut steps = 0;
+    /// let sort = blueprint.characterise_controlled(&input, |sort| {
+    ///     steps += 1;
+    ///     if *sort == BasicStateSort::Accept { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+    /// }).unwrap();
+    ///
+    /// assert_eq!(sort, BasicStateSort::Accept);
+    /// assert_eq!(steps, 2);
+    /// ```
+    fn characterise_controlled<F>(&self, word: &[Self::Alphabet], mut control: F) -> Result<Self::StateSort, Self::ErrorType>
+    where
+        Self: Sized,
+        F: FnMut(&Self::StateSort) -> ControlFlow<()>
+    {
+        let mut automaton = self.automaton();
+        for character in word {
+            let sort = automaton.update_sort_state(character)?;
+            if control(&sort).is_break() {
+                return Ok(sort);
+            }
+        }
+        automaton.current_state_sort()
+    }
+
+    /// Processes an input sequence, stopping as soon as a trap state is reached.
+    ///
+    /// A trap state is absorbing: every transition out of it leads back to it, so its
+    /// classification is the final one whatever input remains. The caller identifies such
+    /// states with `is_trap`, which is checked before the first symbol and after each one;
+    /// once it holds, the rest of the input is skipped and the trap state's classification is
+    /// returned. On inputs that die early this turns a full scan into a short one. If
+    /// `is_trap` holds for a state that is not absorbing, the result may differ from
+    /// [`characterise`](Self::characterise), and errors later in the input are not reported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::{DeterministicAutomatonBlueprint, BasicStateSort};
+    /// use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterState};
+    ///
+    /// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    /// let mut input = vec!['b'];
+    /// input.extend(std::iter::repeat_n('a', 1_000_000));
+    ///
+    /// // A leading 'b' rejects for good, so the million 'a's are never read
+    /// let sort = blueprint.characterise_early_trap(&input, |state| matches!(state, CounterState::Reject)).unwrap();
+    /// assert_eq!(sort, BasicStateSort::Reject);
+    /// ```
+    fn characterise_early_trap<F>(&self, word: &[Self::Alphabet], is_trap: F) -> Result<Self::StateSort, Self::ErrorType>
+    where
+        Self: Sized,
+        F: Fn(&Self::State) -> bool
+    {
+        let mut automaton = self.automaton();
+        for character in word {
+            if is_trap(automaton.view_state()) {
+                break;
+            }
+            automaton.update_state(character)?;
+        }
+        automaton.current_state_sort()
+    }
+
+    /// Processes an input sequence, pairing each symbol with the classification it produced.
+    ///
+    /// Returns one entry per input symbol, containing a clone of the symbol and the
+    /// classification of the state reached after consuming it. Keeping input and output
+    /// aligned is convenient for rendering a run as a table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::{DeterministicAutomatonBlueprint, BasicStateSort, counter_automaton_example::CounterAutomatonBlueprint};
+    ///
+    /// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    ///
+    /// assert_eq!(blueprint.characterise_zip(&['a', 'b']).unwrap(), vec![
+    ///     ('a', BasicStateSort::Reject),
+    ///     ('b', BasicStateSort::Accept),
+    /// ]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn characterise_zip(&self, word: &[Self::Alphabet]) -> Result<Vec<(Self::Alphabet, Self::StateSort)>, Self::ErrorType>
+    where
+        Self: Sized,
+        Self::Alphabet: Clone
+    {
+        let mut automaton = self.automaton();
+        let mut pairs = Vec::with_capacity(word.len());
+        for character in word {
+            pairs.push((character.clone(), automaton.update_sort_state(character)?));
+        }
+        Ok(pairs)
+    }
+
+    /// Compares the classification trace of an input sequence against an expected trace.
+    ///
+    /// Entry `i` of the trace is the classification after consuming `word[i]`, as in
+    /// [`characterise_zip`](Self::characterise_zip). Returns the first index at which the
+    /// trace disagrees with `expected`, or `None` if they agree up to the shorter of the two.
+    /// Processing stops at the first disagreement or once `expected` is exhausted, so errors
+    /// further into the input are not reported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::{DeterministicAutomatonBlueprint, BasicStateSort::*, counter_automaton_example::CounterAutomatonBlueprint};
+    ///
+    /// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    ///
+    /// assert_eq!(blueprint.trace_diff(&['a', 'b'], &[Reject, Accept]).unwrap(), None);
+    /// assert_eq!(blueprint.trace_diff(&['a', 'b', 'b'], &[Reject, Accept, Accept]).unwrap(), Some(2));
+    /// ```
+    fn trace_diff(&self, word: &[Self::Alphabet], expected: &[Self::StateSort]) -> Result<Option<usize>, Self::ErrorType>
+    where
+        Self: Sized,
+        Self::StateSort: PartialEq
+    {
+        let mut automaton = self.automaton();
+        for (index, (character, expected_sort)) in word.iter().zip(expected).enumerate() {
+            if automaton.update_sort_state(character)? != *expected_sort {
+                return Ok(Some(index));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Processes an input sequence and records the run as a [`RunTrace`].
+    ///
+    /// The trace owns a copy of the input, the initial classification and the classification
+    /// after each symbol, so it can be kept, compared or inspected after the blueprint is gone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::{DeterministicAutomatonBlueprint, BasicStateSort, counter_automaton_example::CounterAutomatonBlueprint};
+    ///
+    /// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    /// let trace = blueprint.characterise_trace_record(&['a', 'b']).unwrap();
+    ///
+    /// assert_eq!(trace.initial, BasicStateSort::Accept);
+    /// assert_eq!(trace.symbols, vec!['a', 'b']);
+    /// assert_eq!(trace.sorts, vec![BasicStateSort::Reject, BasicStateSort::Accept]);
+    /// ```
+    fn characterise_trace_record(&self, word: &[Self::Alphabet]) -> Result<RunTrace<Self::Alphabet, Self::StateSort>, Self::ErrorType>
+    where
+        Self: Sized,
+        Self::Alphabet: Clone
+    {
+        let mut automaton = self.automaton();
+        let initial = automaton.current_state_sort()?;
+        let mut sorts = Vec::with_capacity(word.len());
+        for character in word {
+            sorts.push(automaton.update_sort_state(character)?);
+        }
+        Ok(RunTrace { initial, symbols: word.to_vec(), sorts })
+    }
+
+    /// Processes each of a sequence of records independently, returning one result per record.
+    ///
+    /// Every record is read from the initial state, using a single runtime automaton that is
+    /// [`reset`](DeterministicAutomaton::reset) between records. Records are borrowed, so
+    /// large inputs split into lines or messages can be classified without copying. An error
+    /// in one record is reported in its result and does not affect the others.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::{DeterministicAutomatonBlueprint, BasicStateSort, counter_automaton_example::CounterAutomatonBlueprint};
+    ///
+    /// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    /// let input = ['a', 'b', 'a', 'a', 'b'];
+    ///
+    /// assert_eq!(blueprint.characterise_records([&input[..2], &input[2..]]), vec![
+    ///     Ok(BasicStateSort::Accept),
+    ///     Ok(BasicStateSort::Reject),
+    /// ]);
+    /// ```
+    fn characterise_records<'w, I>(&self, records: I) -> Vec<Result<Self::StateSort, Self::ErrorType>>
+    where
+        Self: Sized,
+        Self::Alphabet: 'w,
+        I: IntoIterator<Item = &'w [Self::Alphabet]>
+    {
+        let mut automaton = self.automaton();
+        records.into_iter()
+            .map(|record| {
+                automaton.reset();
+                for character in record {
+                    automaton.update_state(character)?;
+                }
+                automaton.current_state_sort()
+            })
+            .collect()
+    }
+
+    /// Processes an entire input sequence, also returning how many transitions were performed.
+    ///
+    /// The count is the number of [`transition_map`](Self::transition_map) calls made, which
+    /// for a full run is `word.len()`. It is a lightweight instrumentation hook for comparing
+    /// runs whose step counts can differ from the input length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::{DeterministicAutomatonBlueprint, BasicStateSort, counter_automaton_example::CounterAutomatonBlueprint};
+    ///
+    /// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    ///
+    /// assert_eq!(blueprint.characterise_counted(&['a', 'b']).unwrap(), (BasicStateSort::Accept, 2));
+    /// ```
+    fn characterise_counted(&self, word: &[Self::Alphabet]) -> Result<(Self::StateSort, usize), Self::ErrorType>
+    where
+        Self: Sized
+    {
+        let mut automaton = self.automaton();
+        let mut steps = 0;
+        for character in word {
+            automaton.update_state(character)?;
+            steps += 1;
+        }
+        Ok((automaton.current_state_sort()?, steps))
+    }
+
+    /// Processes an input sequence, returning every state visited.
+    ///
+    /// The path starts with the initial state and contains one further state per input
+    /// symbol, so it has `word.len() + 1` entries. This is the state-level counterpart of
+    /// [`characterise_zip`](Self::characterise_zip), useful for explaining why a word was
+    /// classified as it was when states carry data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::DeterministicAutomatonBlueprint;
+    /// use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterState};
+    ///
+    /// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    /// let path = blueprint.state_path(&['a', 'a', 'b']).unwrap();
+    ///
+    /// let counts: Vec<usize> = path.iter().map(|state| match state {
+    ///     CounterState::Start(count) | CounterState::End(count) => *count,
+    ///     _ => 0,
+    /// }).collect();
+    /// assert_eq!(counts, vec![0, 1, 2, 1]);
+    /// ```
+    fn state_path(&self, word: &[Self::Alphabet]) -> Result<Vec<Self::State>, Self::ErrorType>
+    where
+        Self: Sized
+    {
+        let mut state = self.initial_state();
+        let mut path = Vec::with_capacity(word.len() + 1);
+        for character in word {
+            let next = self.transition_map(&state, character)?;
+            path.push(state);
+            state = next;
+        }
+        path.push(state);
+        Ok(path)
+    }
+
+    /// Returns the length and state of every accepting prefix of an input sequence.
+    ///
+    /// The empty prefix is included when the initial state accepts. Unlike
+    /// [`state_path`](Self::state_path), only states at acceptance points are cloned, which
+    /// suits extracting the data carried by accepting states, such as match positions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::DeterministicAutomatonBlueprint;
+    /// use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, TolerantCounterState};
+    ///
+    /// let blueprint = CounterAutomatonBlueprint::with_tolerance('a', 'b', 0..=0);
+    /// let snapshots = blueprint.accepting_snapshots(&['a', 'b', 'b', 'a']).unwrap();
+    ///
+    /// let lengths: Vec<usize> = snapshots.iter().map(|(length, _)| *length).collect();
+    /// assert_eq!(lengths, vec![0, 2, 4]);
+    /// assert!(snapshots.iter().all(|(_, state)| *state == TolerantCounterState::with_balance(0)));
+    /// ```
+    fn accepting_snapshots(&self, word: &[Self::Alphabet]) -> Result<Vec<(usize, Self::State)>, Self::ErrorType>
+    where
+        Self: Sized + DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+    {
+        let mut automaton = self.automaton();
+        let mut snapshots = Vec::new();
+        if automaton.current_state_sort()? == BasicStateSort::Accept {
+            snapshots.push((0, automaton.view_state().clone()));
+        }
+        for (index, character) in word.iter().enumerate() {
+            if automaton.update_sort_state(character)? == BasicStateSort::Accept {
+                snapshots.push((index + 1, automaton.view_state().clone()));
+            }
+        }
+        Ok(snapshots)
+    }
+
+    /// Counts how many prefixes of `word` land in each classification.
+    ///
+    /// Tallies the classification after every prefix, including the empty prefix, so the
+    /// counts sum to `word.len() + 1`. Classifications that never occur are absent from the
+    /// map. This is most informative for sorts richer than [`BasicStateSort`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::{DeterministicAutomatonBlueprint, BasicStateSort, counter_automaton_example::CounterAutomatonBlueprint};
+    ///
+    /// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    /// let histogram = blueprint.sort_histogram(&['a', 'b', 'a', 'b']).unwrap();
+    ///
+    /// assert_eq!(histogram[&BasicStateSort::Accept], 2);
+    /// assert_eq!(histogram[&BasicStateSort::Reject], 3);
+    /// ```
+    fn sort_histogram(&self, word: &[Self::Alphabet]) -> Result<HashMap<Self::StateSort, usize>, Self::ErrorType>
+    where
+        Self: Sized,
+        Self::StateSort: Eq + Hash
+    {
+        let mut automaton = self.automaton();
+        let mut histogram = HashMap::new();
+        *histogram.entry(automaton.current_state_sort()?).or_insert(0) += 1;
+        for character in word {
+            *histogram.entry(automaton.update_sort_state(character)?).or_insert(0) += 1;
+        }
+        Ok(histogram)
+    }
+
+    /// Processes an input prefix and returns the running automaton for later continuation.
+    ///
+    /// The returned automaton is in the state reached after `word`, so further symbols can
+    /// be fed with [`update_state`](DeterministicAutomaton::update_state) as they arrive
+    /// without re-processing the prefix. This suits input arriving in bursts, such as a REPL.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::{DeterministicAutomatonBlueprint, BasicStateSort, counter_automaton_example::CounterAutomatonBlueprint};
+    ///
+    /// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    /// let mut automaton = blueprint.characterise_partial(&['a', 'a']).unwrap();
+    /// assert_eq!(automaton.current_state_sort().unwrap(), BasicStateSort::Reject);
+    ///
+    /// // More input arrives later
+    /// automaton.update_state(&'b').unwrap();
+    /// assert_eq!(automaton.update_sort_state(&'b').unwrap(), BasicStateSort::Accept);
+    /// ```
+    fn characterise_partial(&self, word: &[Self::Alphabet]) -> Result<DeterministicAutomaton<'_, Self>, Self::ErrorType>
+    where
+        Self: Sized
+    {
+        let mut automaton = self.automaton();
+        for character in word {
+            automaton.update_state(character)?;
+        }
+        Ok(automaton)
+    }
+
+    /// Processes an entire input sequence, returning `Ok(())` only if it is accepted.
+    ///
+    /// Rejection becomes [`ValidationError::Rejected`] and an automaton error becomes
+    /// [`ValidationError::Inner`], so a recognizer can be used directly in `?`-based
+    /// validation code where rejection is the failure mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::{DeterministicAutomatonBlueprint, ValidationError};
+    /// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+    ///
+    /// let brackets = CounterAutomatonBlueprint::new('(', ')');
+    ///
+    /// assert_eq!(brackets.validate(&['(', ')']), Ok(()));
+    /// assert_eq!(brackets.validate(&['(']), Err(ValidationError::Rejected));
+    /// ```
+    fn validate(&self, word: &[Self::Alphabet]) -> Result<(), ValidationError<Self::ErrorType>>
+    where
+        Self: Sized + DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+    {
+        match self.characterise(word).map_err(ValidationError::Inner)? {
+            BasicStateSort::Accept => Ok(()),
+            BasicStateSort::Reject => Err(ValidationError::Rejected),
+        }
+    }
+
+    /// Processes a raw input sequence, decoding each element into a symbol before reading it.
+    ///
+    /// Each element of `raw` is passed to `decode` just before its symbol is needed, so no
+    /// decoded copy of the input is built. A decoding failure becomes [`DecodeOrRun::Decode`]
+    /// and an automaton error becomes [`DecodeOrRun::Run`]; either stops the run, so an
+    /// error is reported for the first element at which anything fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::{BasicStateSort, DecodeOrRun, DeterministicAutomatonBlueprint};
+    /// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+    ///
+    /// let brackets = CounterAutomatonBlueprint::new('(', ')');
+    /// let ascii = |byte: &u8| if byte.is_ascii() { Ok(char::from(*byte)) } else { Err(*byte) };
+    ///
+    /// assert_eq!(brackets.characterise_decoded(b"(())", ascii), Ok(BasicStateSort::Accept));
+    /// assert_eq!(brackets.characterise_decoded(&[b'(', 0xFF, b')'], ascii), Err(DecodeOrRun::Decode(0xFF)));
+    /// ```
+    fn characterise_decoded<R, DecodeError, F>(&self, raw: &[R], decode: F) -> Result<Self::StateSort, DecodeOrRun<DecodeError, Self::ErrorType>>
+    where
+        Self: Sized,
+        F: Fn(&R) -> Result<Self::Alphabet, DecodeError>
+    {
+        let mut automaton = self.automaton();
+        for element in raw {
+            let character = decode(element).map_err(DecodeOrRun::Decode)?;
+            automaton.update_state(&character).map_err(DecodeOrRun::Run)?;
+        }
+        automaton.current_state_sort().map_err(DecodeOrRun::Run)
+    }
+
+    /// Returns whether the language contains the empty word.
+    ///
+    /// This classifies the initial state and checks for [`BasicStateSort::Accept`]. Nullability
+    /// is the property combinators such as concatenation and Kleene star need to know about
+    /// their components.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::DeterministicAutomatonBlueprint;
+    /// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+    ///
+    /// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    ///
+    /// assert!(blueprint.accepts_empty().unwrap());
+    /// assert!(!blueprint.min_length(1).accepts_empty().unwrap());
+    /// ```
+    fn accepts_empty(&self) -> Result<bool, Self::ErrorType>
+    where
+        Self: Sized + DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+    {
+        Ok(self.state_sort_map(&self.initial_state())? == BasicStateSort::Accept)
+    }
+
+    /// Returns a blueprint that behaves like this one but starts from `state`.
+    ///
+    /// See [`WithInitialBlueprint`](operations::WithInitialBlueprint) for details.
+    fn with_initial(&self, state: Self::State) -> operations::WithInitialBlueprint<'_, Self>
+    where
+        Self: Sized
+    {
+        operations::WithInitialBlueprint::new(self, state)
+    }
+
+    /// Returns a blueprint accepting only the words of this language with at most `max` symbols.
+    ///
+    /// See [`MaxLengthBlueprint`](operations::MaxLengthBlueprint) for details.
+    fn max_length(&self, max: usize) -> operations::MaxLengthBlueprint<'_, Self>
+    where
+        Self: Sized + DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+    {
+        operations::MaxLengthBlueprint::new(self, max)
+    }
+
+    /// Returns a blueprint accepting only the words of this language with at least `min` symbols.
+    ///
+    /// See [`MinLengthBlueprint`](operations::MinLengthBlueprint) for details.
+    fn min_length(&self, min: usize) -> operations::MinLengthBlueprint<'_, Self>
+    where
+        Self: Sized + DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+    {
+        operations::MinLengthBlueprint::new(self, min)
+    }
+
+    /// Returns a blueprint accepting only the non-empty words of this language.
+    ///
+    /// See [`NonEmptyBlueprint`](operations::NonEmptyBlueprint) for details.
+    fn non_empty(&self) -> operations::NonEmptyBlueprint<'_, Self>
+    where
+        Self: Sized + DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+    {
+        operations::NonEmptyBlueprint::new(self)
+    }
+
+    /// Returns a blueprint accepting this language and the empty word.
+    ///
+    /// See [`OptionalBlueprint`](operations::OptionalBlueprint) for details.
+    fn optional(&self) -> operations::OptionalBlueprint<'_, Self>
+    where
+        Self: Sized + DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+    {
+        operations::OptionalBlueprint::new(self)
+    }
+
+    /// Returns a blueprint that also accepts the words whose lowercasing this one accepts.
+    ///
+    /// See [`CaseInsensitiveBlueprint`](operations::CaseInsensitiveBlueprint) for details.
+    fn case_insensitive(&self) -> operations::CaseInsensitiveBlueprint<'_, Self>
+    where
+        Self: Sized + DeterministicAutomatonBlueprint<Alphabet = char, StateSort = BasicStateSort>
+    {
+        operations::CaseInsensitiveBlueprint::new(self)
+    }
+
+    /// Returns a blueprint rejecting, instead of failing, on any transition error.
+    ///
+    /// See [`ErrorsAsRejectBlueprint`](operations::ErrorsAsRejectBlueprint) for details,
+    /// including how to convert only some errors.
+    fn errors_as_reject(&self) -> operations::ErrorsAsRejectBlueprint<'_, Self>
+    where
+        Self: Sized + DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+    {
+        operations::ErrorsAsRejectBlueprint::new(self)
+    }
+
+    /// Returns a blueprint that also records the peak of a state metric over each run.
+    ///
+    /// See [`PeakTrackingBlueprint`](operations::PeakTrackingBlueprint) for details.
+    fn track_peak<M, F>(&self, extract: F) -> operations::PeakTrackingBlueprint<'_, Self, M, F>
+    where
+        Self: Sized,
+        M: Ord + Clone,
+        F: Fn(&Self::State) -> M
+    {
+        operations::PeakTrackingBlueprint::new(self, extract)
+    }
+
     fn automaton(&self) -> DeterministicAutomaton<'_, Self> 
     where
         Self: Sized
@@ -381,6 +1133,34 @@ pub struct DeterministicAutomaton<'a, Blueprint: DeterministicAutomatonBlueprint
     current_state: Blueprint::State,
 }
 
+/// Cloning a running automaton forks it: the clone shares the blueprint reference and
+/// continues independently from a clone of the current state, which allows speculative
+/// branching such as trying a symbol and discarding the result.
+impl<Blueprint: DeterministicAutomatonBlueprint> Clone for DeterministicAutomaton<'_, Blueprint> {
+    fn clone(&self) -> Self {
+        Self {
+            blueprint: self.blueprint,
+            current_state: self.current_state.clone()
+        }
+    }
+}
+
+/// Two running automata are equal when their current states are equal.
+///
+/// Only the state participates: the blueprint is ignored, so automata running different
+/// blueprint instances of the same type compare equal whenever their states do. This suits
+/// deduplicating explored configurations of a single blueprint, for example in a search
+/// frontier of forked runs.
+impl<Blueprint> PartialEq for DeterministicAutomaton<'_, Blueprint>
+where
+    Blueprint: DeterministicAutomatonBlueprint,
+    Blueprint::State: PartialEq
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.current_state == other.current_state
+    }
+}
+
 impl<'a, Blueprint> DeterministicAutomaton<'a, Blueprint>
 where
     Blueprint: DeterministicAutomatonBlueprint
@@ -424,13 +1204,29 @@ where
         self.current_state_sort()
     }
 
+    /// Returns the classification the automaton would have after a symbol, without consuming it.
+    ///
+    /// The next state is computed and classified but not stored, so the automaton is left
+    /// in its current state whether the dry run succeeds or fails.
+    pub fn try_transition(&self, character: &Blueprint::Alphabet) -> Result<Blueprint::StateSort, Blueprint::ErrorType> {
+        let next_state = self.blueprint.transition_map(&self.current_state, character)?;
+        self.blueprint.state_sort_map(&next_state)
+    }
+
+    /// Returns the automaton to the blueprint's initial state.
+    ///
+    /// This allows one runtime instance to be reused for several independent inputs.
+    pub fn reset(&mut self) {
+        self.current_state = self.blueprint.initial_state();
+    }
+
     /// Returns a reference to the current state.
     ///
     /// This method provides read-only access to the automaton's internal state,
     /// which can be useful for inspecting state data beyond simple classification.
     /// Unlike [`current_state_sort`](Self::current_state_sort), this returns the
     /// actual state value rather than its classification.
-    pub fn view_state(&'a self) -> &'a Blueprint::State {
+    pub fn view_state(&self) -> &Blueprint::State {
         &self.current_state
     }
 
@@ -448,14 +1244,193 @@ where
 ///
 /// This simple enum distinguishes between accepting and rejecting states,
 /// suitable for recognizing formal languages.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+///
+/// The [`Default`] is [`Reject`](Self::Reject): an input not yet shown to be acceptable is
+/// treated as unacceptable, which is the safe choice for validators.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 pub enum BasicStateSort {
     /// The state accepts the input string.
     Accept, 
     
     /// The state rejects the input string.
+    #[default]
     Reject
 }
 
+impl std::fmt::Display for BasicStateSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BasicStateSort::Accept => write!(f, "Accept"),
+            BasicStateSort::Reject => write!(f, "Reject"),
+        }
+    }
+}
+
+/// Converts a boolean verdict into a classification, with `true` accepting.
+///
+/// This lets a [`state_sort_map`](DeterministicAutomatonBlueprint::state_sort_map) built on
+/// a predicate be written as `Ok(BasicStateSort::from(predicate(state)))`.
+impl From<bool> for BasicStateSort {
+    fn from(accepted: bool) -> Self {
+        if accepted { BasicStateSort::Accept } else { BasicStateSort::Reject }
+    }
+}
+
+/// Converts a classification into a boolean verdict, with [`Accept`](BasicStateSort::Accept)
+/// mapping to `true`.
+impl From<BasicStateSort> for bool {
+    fn from(sort: BasicStateSort) -> Self {
+        sort == BasicStateSort::Accept
+    }
+}
+
+
+/// Three-valued classification for automaton states.
+///
+/// Extends [`BasicStateSort`] with [`Unknown`](Self::Unknown) for states where the verdict
+/// is not yet decided, such as a recognizer that has not seen enough input or a checker
+/// that has given up. The connectives follow Kleene's strong three-valued logic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TriStateSort {
+    /// The state accepts the input string.
+    Accept,
+
+    /// The state rejects the input string.
+    Reject,
+
+    /// The verdict for this state is undecided.
+    Unknown
+}
+
+impl TriStateSort {
+    /// Returns the Kleene negation of this classification.
+    ///
+    /// [`Accept`](Self::Accept) and [`Reject`](Self::Reject) swap, while
+    /// [`Unknown`](Self::Unknown) stays [`Unknown`](Self::Unknown): the negation of an
+    /// undecided verdict is still undecided.
+    pub fn negate(self) -> Self {
+        match self {
+            TriStateSort::Accept => TriStateSort::Reject,
+            TriStateSort::Reject => TriStateSort::Accept,
+            TriStateSort::Unknown => TriStateSort::Unknown,
+        }
+    }
+}
+
+impl std::fmt::Display for TriStateSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TriStateSort::Accept => write!(f, "Accept"),
+            TriStateSort::Reject => write!(f, "Reject"),
+            TriStateSort::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// Embeds a decided classification, with [`Accept`](BasicStateSort::Accept) and
+/// [`Reject`](BasicStateSort::Reject) mapping to their three-valued counterparts.
+impl From<BasicStateSort> for TriStateSort {
+    fn from(sort: BasicStateSort) -> Self {
+        match sort {
+            BasicStateSort::Accept => TriStateSort::Accept,
+            BasicStateSort::Reject => TriStateSort::Reject,
+        }
+    }
+}
+
+/// A recorded run of an automaton over an input sequence.
+///
+/// Produced by [`characterise_trace_record`](DeterministicAutomatonBlueprint::characterise_trace_record).
+/// `initial` is the classification of the empty prefix, and `sorts[i]` is the classification
+/// after consuming `symbols[i]`, so `symbols` and `sorts` have the same length.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RunTrace<Alphabet, StateSort> {
+    /// The classification of the initial state, before any symbol was read.
+    pub initial: StateSort,
+
+    /// The input symbols, in the order they were read.
+    pub symbols: Vec<Alphabet>,
+
+    /// The classification after each symbol.
+    pub sorts: Vec<StateSort>
+}
+
+impl<Alphabet, StateSort> RunTrace<Alphabet, StateSort> {
+    /// Returns the classification at the end of the run, which is `initial` for an empty run.
+    pub fn final_sort(&self) -> &StateSort {
+        self.sorts.last().unwrap_or(&self.initial)
+    }
+}
+
+/// The failure of [`validate`](DeterministicAutomatonBlueprint::validate), distinguishing
+/// rejected input from an automaton error.
+///
+/// A rejection means the input was processed successfully but is not in the language; an
+/// inner error means the automaton itself failed while processing it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ValidationError<ErrorType> {
+    /// The input was processed and classified as [`BasicStateSort::Reject`].
+    Rejected,
+
+    /// The automaton returned an error while processing the input.
+    Inner(ErrorType)
+}
+
+impl<ErrorType: std::fmt::Display> std::fmt::Display for ValidationError<ErrorType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::Rejected => write!(f, "input rejected"),
+            ValidationError::Inner(error) => write!(f, "automaton error: {}", error),
+        }
+    }
+}
+
+impl<ErrorType: std::error::Error + 'static> std::error::Error for ValidationError<ErrorType> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidationError::Rejected => None,
+            ValidationError::Inner(error) => Some(error),
+        }
+    }
+}
+
+/// Wraps an automaton error, so `?` on a plain `Result<_, ErrorType>` works inside a function
+/// returning [`ValidationError`].
+impl<ErrorType> From<ErrorType> for ValidationError<ErrorType> {
+    fn from(error: ErrorType) -> Self {
+        ValidationError::Inner(error)
+    }
+}
+
+/// The failure of [`characterise_decoded`](DeterministicAutomatonBlueprint::characterise_decoded),
+/// distinguishing malformed raw input from an automaton error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DecodeOrRun<DecodeError, ErrorType> {
+    /// A raw input element could not be decoded into a symbol.
+    Decode(DecodeError),
+
+    /// The automaton returned an error while processing the decoded input.
+    Run(ErrorType)
+}
 
+impl<DecodeError: std::fmt::Display, ErrorType: std::fmt::Display> std::fmt::Display for DecodeOrRun<DecodeError, ErrorType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeOrRun::Decode(error) => write!(f, "decode error: {}", error),
+            DecodeOrRun::Run(error) => write!(f, "automaton error: {}", error),
+        }
+    }
+}
 
+impl<DecodeError, ErrorType> std::error::Error for DecodeOrRun<DecodeError, ErrorType>
+where
+    DecodeError: std::error::Error + 'static,
+    ErrorType: std::error::Error + 'static
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DecodeOrRun::Decode(error) => Some(error),
+            DecodeOrRun::Run(error) => Some(error),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Blueprints for recognizers that need bounded lookahead.
+//!
+//! Deterministic and mutation automata read exactly one symbol per transition, which cannot
+//! express recognizers that must peek at upcoming symbols before deciding how to proceed.
+//! The [`LookaheadBlueprint`] trait gives each transition a window of upcoming symbols and
+//! lets it report how many of them it consumed, so a recognizer with a fixed, bounded
+//! lookahead can be written directly.
+//!
+//! # Example
+//!
+//! ```
+//! use deterministic_automata::{BasicStateSort, LookaheadBlueprint};
+//!
+//! // Counts tokens where "->" is a single arrow token and every other character is its own token
+//! struct Tokens;
+//!
+//! impl LookaheadBlueprint for Tokens {
+//!     type State = usize;
+//!     type Alphabet = char;
+//!     type StateSort = usize;
+//!     type ErrorType = String;
+//!
+//!     fn lookahead(&self) -> usize { 2 }
+//!
+//!     fn initial_lookahead_state(&self) -> Self::State { 0 }
+//!
+//!     fn lookahead_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+//!         Ok(*state)
+//!     }
+//!
+//!     fn transition_lookahead(&self, state: &Self::State, window: &[Self::Alphabet]) -> Result<(Self::State, usize), Self::ErrorType> {
+//!         match window {
+//!             ['-', '>', ..] => Ok((state + 1, 2)),
+//!             _ => Ok((state + 1, 1)),
+//!         }
+//!     }
+//! }
+//!
+//! let input: Vec<char> = "a->b-c".chars().collect();
+//! assert_eq!(Tokens.characterise_lookahead(&input).unwrap(), 5);
+//! ```
+
+/// A blueprint for automata whose transitions may inspect a bounded window of upcoming symbols.
+///
+/// # Associated Types
+///
+/// * `State` - The type representing internal automaton states. Must be `Clone`.
+/// * `Alphabet` - The type of input symbols. Must support equality comparison.
+/// * `StateSort` - The classification type for states (e.g., Accept/Reject).
+/// * `ErrorType` - The type used for error handling when states are invalid.
+///
+/// # Required Methods
+///
+/// * [`lookahead`](Self::lookahead) - The maximum number of symbols in each window
+/// * [`initial_lookahead_state`](Self::initial_lookahead_state) - Returns the starting state
+/// * [`lookahead_state_sort_map`](Self::lookahead_state_sort_map) - Classifies a state, with validation
+/// * [`transition_lookahead`](Self::transition_lookahead) - Consumes symbols from a window, with validation
+///
+/// # Provided Methods
+///
+/// * [`characterise_lookahead`](Self::characterise_lookahead) - Processes an entire input sequence
+pub trait LookaheadBlueprint {
+    /// The type representing internal automaton states.
+    type State: Clone;
+
+    /// The type of input symbols that the automaton processes.
+    type Alphabet: PartialEq;
+
+    /// The classification type for states.
+    type StateSort;
+
+    /// The error type returned when state validation fails.
+    type ErrorType;
+
+    /// Returns the maximum number of upcoming symbols shown to each transition.
+    ///
+    /// Values below one are treated as one.
+    fn lookahead(&self) -> usize;
+
+    /// Returns the initial state of the automaton.
+    fn initial_lookahead_state(&self) -> Self::State;
+
+    /// Maps a state to its classification, with validation.
+    fn lookahead_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType>;
+
+    /// Defines the transition function over a window of upcoming symbols.
+    ///
+    /// `window` starts at the next unread symbol and holds up to
+    /// [`lookahead`](Self::lookahead) symbols; it is shorter near the end of the input but
+    /// never empty. Returns the next state and the number of symbols consumed, which should
+    /// be between one and `window.len()`; other values are clamped to that range so that
+    /// processing always makes progress.
+    fn transition_lookahead(&self, state: &Self::State, window: &[Self::Alphabet]) -> Result<(Self::State, usize), Self::ErrorType>;
+
+    /// Processes an entire input sequence and returns the final state classification.
+    ///
+    /// Repeatedly shows the transition the window starting at the next unread symbol, then
+    /// advances by the number of symbols it reports as consumed.
+    fn characterise_lookahead(&self, word: &[Self::Alphabet]) -> Result<Self::StateSort, Self::ErrorType> {
+        let size = self.lookahead().max(1);
+        let mut state = self.initial_lookahead_state();
+        let mut position = 0;
+        while position < word.len() {
+            let window = &word[position..word.len().min(position + size)];
+            let (next, consumed) = self.transition_lookahead(&state, window)?;
+            state = next;
+            position += consumed.clamp(1, window.len());
+        }
+        self.lookahead_state_sort_map(&state)
+    }
+}
@@ -0,0 +1,97 @@
+//! Example automaton that recognizes digit sequences whose digit sum is divisible by `k`.
+//!
+//! This module provides a finite-state counterpart to the
+//! [`counter_automaton_example`](crate::counter_automaton_example). The
+//! [`ModSumBlueprint`] reads decimal digits and tracks the running digit sum modulo a fixed
+//! modulus, so it only ever visits `modulus` distinct states. It is a classic example of a
+//! language recognized by a traditional finite state automaton.
+//!
+//! # Divisibility Tests
+//!
+//! Since `10 ≡ 1 (mod 3)` and `10 ≡ 1 (mod 9)`, a number is divisible by 3 or 9 exactly when
+//! its digit sum is. With a modulus of 3 or 9 the blueprint therefore recognizes the decimal
+//! representations of multiples of 3 or 9. For other moduli it recognizes digit sums only.
+//!
+//! # State Machine Design
+//!
+//! The state is a `u64` in `0..modulus` holding the digit sum read so far, reduced modulo
+//! the modulus. State `0` accepts and every other state rejects. States outside that range,
+//! such as one supplied through
+//! [`with_initial`](crate::DeterministicAutomatonBlueprint::with_initial), are read as their
+//! residue. Symbols are `u8` digit values in `0..=9`; any other value is reported as an error.
+//!
+//! # Example
+//!
+//! ```
+//! use deterministic_automata::{DeterministicAutomatonBlueprint, BasicStateSort};
+//! use deterministic_automata::mod_counter_example::ModSumBlueprint;
+//!
+//! let divisible_by_three = ModSumBlueprint::new(3);
+//!
+//! // 1 + 2 + 3 = 6
+//! assert_eq!(divisible_by_three.characterise(&[1, 2, 3]).unwrap(), BasicStateSort::Accept);
+//! // 1 + 2 + 4 = 7
+//! assert_eq!(divisible_by_three.characterise(&[1, 2, 4]).unwrap(), BasicStateSort::Reject);
+//! assert!(divisible_by_three.characterise(&[12]).is_err());
+//! ```
+
+use crate::{DeterministicAutomatonBlueprint, BasicStateSort};
+
+/// A blueprint for an automaton accepting digit sequences whose digit sum is divisible by a modulus.
+///
+/// The empty sequence has digit sum zero and is therefore accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModSumBlueprint {
+    modulus: u64
+}
+
+impl ModSumBlueprint {
+    /// Creates a new digit-sum blueprint.
+    ///
+    /// # Parameters
+    ///
+    /// * `modulus` - The value the digit sum must be divisible by
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn new(modulus: u64) -> Self {
+        assert!(modulus > 0, "ModSumBlueprint modulus must be positive");
+        Self { modulus }
+    }
+
+    /// Returns the modulus the digit sum is reduced by.
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+}
+
+impl DeterministicAutomatonBlueprint for ModSumBlueprint {
+    type State = u64;
+    type Alphabet = u8;
+    type StateSort = BasicStateSort;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        0
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(if *state % self.modulus == 0 { BasicStateSort::Accept } else { BasicStateSort::Reject })
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        if *character > 9 {
+            return Err(format!("Not a decimal digit: {}", character));
+        }
+        // Reducing both operands first keeps them below the modulus, even for states that
+        // did not come from this blueprint; if their sum then overflows it certainly exceeds
+        // the modulus, so subtracting the modulus once reduces it
+        let state = *state % self.modulus;
+        let digit = u64::from(*character) % self.modulus;
+        Ok(match state.checked_add(digit) {
+            Some(sum) => sum % self.modulus,
+            None => digit - (self.modulus - state),
+        })
+    }
+}
@@ -9,7 +9,11 @@
 //!
 //! - **State Transitions**: `mutation_transition_map` takes `&mut State` and returns `()`
 //! - **In-Place Updates**: State changes happen directly rather than through functional updates
-//! - **Interoperability**: All deterministic automata automatically work as mutation automata
+//! - **Interoperability**: All deterministic automata automatically work as mutation automata,
+//!   and [`Deterministicize`] adapts any mutation automaton for use as a deterministic one.
+//!   Deterministic blueprints with large states can override
+//!   [`transition_in_place`](crate::DeterministicAutomatonBlueprint::transition_in_place) so
+//!   that their mutation transitions update the state without rebuilding it
 //!
 //! # Example
 //!
@@ -41,7 +45,12 @@
 //! }
 //! ```
 
-use crate::DeterministicAutomatonBlueprint;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::ControlFlow;
+
+use crate::{BasicStateSort, DeterministicAutomatonBlueprint, ValidationError};
+use crate::operations::MutationWithInitialBlueprint;
 
 /// A blueprint for defining mutation automata with in-place state modification.
 ///
@@ -64,7 +73,23 @@ use crate::DeterministicAutomatonBlueprint;
 ///
 /// # Provided Methods
 ///
+/// * [`mutation_validate_state`](Self::mutation_validate_state) - Checks a state is valid without classifying it
 /// * [`mutation_characterise`](Self::mutation_characterise) - Processes an entire input sequence
+/// * [`mutation_characterise_refs`](Self::mutation_characterise_refs) - Processes an input sequence given as a slice of references
+/// * [`mutation_characterise_rev`](Self::mutation_characterise_rev) - Processes an input sequence back-to-front
+/// * [`mutation_characterise_controlled`](Self::mutation_characterise_controlled) - Processes an input sequence, allowing an observer to stop early
+/// * [`mutation_characterise_early_trap`](Self::mutation_characterise_early_trap) - Processes an input sequence, stopping once a caller-identified trap state is reached
+/// * [`mutation_characterise_zip`](Self::mutation_characterise_zip) - Pairs each input symbol with the classification it produced
+/// * [`mutation_trace_diff`](Self::mutation_trace_diff) - Finds the first symbol whose classification differs from an expected trace
+/// * [`mutation_characterise_records`](Self::mutation_characterise_records) - Processes each of a sequence of records independently
+/// * [`mutation_characterise_counted`](Self::mutation_characterise_counted) - Processes an input sequence, also counting the transitions performed
+/// * [`mutation_state_path`](Self::mutation_state_path) - Returns every state visited while processing an input sequence
+/// * [`mutation_accepting_snapshots`](Self::mutation_accepting_snapshots) - Returns the state at every accepting prefix
+/// * [`mutation_sort_histogram`](Self::mutation_sort_histogram) - Counts how many prefixes land in each classification
+/// * [`mutation_characterise_partial`](Self::mutation_characterise_partial) - Processes a prefix and returns the running automaton
+/// * [`mutation_validate`](Self::mutation_validate) - Processes an input sequence, treating rejection as an error
+/// * [`mutation_accepts_empty`](Self::mutation_accepts_empty) - Checks whether the empty word is accepted
+/// * [`mutation_with_initial`](Self::mutation_with_initial) - Wraps the blueprint to start from a given state
 /// * [`mutation_automaton`](Self::mutation_automaton) - Creates a runtime automaton instance
 pub trait MutationAutomatonBlueprint {
     type State: Clone;
@@ -91,6 +116,15 @@ pub trait MutationAutomatonBlueprint {
     /// would produce an invalid state.
     fn mutation_transition_map(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(),Self::ErrorType>;
 
+    /// Checks that a state is structurally valid, without classifying or running it.
+    ///
+    /// The default implementation delegates to
+    /// [`mutation_state_sort_map`](Self::mutation_state_sort_map) and discards the
+    /// classification. Blueprints can override it with a cheaper check.
+    fn mutation_validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.mutation_state_sort_map(state).map(|_| ())
+    }
+
     /// Processes an entire input sequence and returns the final state classification.
     ///
     /// Creates a runtime automaton, processes the input sequence, and returns
@@ -107,6 +141,263 @@ pub trait MutationAutomatonBlueprint {
         automaton.current_state_sort()
     }
 
+    /// Processes an input sequence of symbol references and returns the final state classification.
+    ///
+    /// Behaves like [`mutation_characterise`](Self::mutation_characterise), but accepts a slice
+    /// of references so symbols that are expensive to clone need not be collected into an
+    /// owned slice.
+    fn mutation_characterise_refs(&self, word: &[&Self::Alphabet]) -> Result<Self::StateSort, Self::ErrorType>
+    where
+        Self: Sized
+    {
+        let mut automaton = self.mutation_automaton();
+        for character in word {
+            automaton.update_state(character)?;
+        }
+        automaton.current_state_sort()
+    }
+
+    /// Processes an input sequence back-to-front and returns the final state classification.
+    ///
+    /// Behaves like [`characterise_rev`](crate::DeterministicAutomatonBlueprint::characterise_rev):
+    /// the same automaton runs over the symbols in reverse order, without allocating a
+    /// reversed copy.
+    fn mutation_characterise_rev(&self, word: &[Self::Alphabet]) -> Result<Self::StateSort, Self::ErrorType>
+    where
+        Self: Sized
+    {
+        let mut automaton = self.mutation_automaton();
+        for character in word.iter().rev() {
+            automaton.update_state(character)?;
+        }
+        automaton.current_state_sort()
+    }
+
+    /// Processes an input sequence while allowing an observer to stop early.
+    ///
+    /// After each symbol is consumed, `control` is consulted with the classification of
+    /// the new state. Returning [`ControlFlow::Break`] stops processing immediately and
+    /// returns that classification; otherwise the final classification is returned.
+    fn mutation_characterise_controlled<F>(&self, word: &[Self::Alphabet], mut control: F) -> Result<Self::StateSort, Self::ErrorType>
+    where
+        Self: Sized,
+        F: FnMut(&Self::StateSort) -> ControlFlow<()>
+    {
+        let mut automaton = self.mutation_automaton();
+        for character in word {
+            let sort = automaton.update_sort_state(character)?;
+            if control(&sort).is_break() {
+                return Ok(sort);
+            }
+        }
+        automaton.current_state_sort()
+    }
+
+    /// Processes an input sequence, stopping as soon as a trap state is reached.
+    ///
+    /// The mutation paradigm counterpart of
+    /// [`characterise_early_trap`](crate::DeterministicAutomatonBlueprint::characterise_early_trap).
+    fn mutation_characterise_early_trap<F>(&self, word: &[Self::Alphabet], is_trap: F) -> Result<Self::StateSort, Self::ErrorType>
+    where
+        Self: Sized,
+        F: Fn(&Self::State) -> bool
+    {
+        let mut automaton = self.mutation_automaton();
+        for character in word {
+            if is_trap(automaton.view_state()) {
+                break;
+            }
+            automaton.update_state(character)?;
+        }
+        automaton.current_state_sort()
+    }
+
+    /// Processes an input sequence, pairing each symbol with the classification it produced.
+    ///
+    /// Returns one entry per input symbol, containing a clone of the symbol and the
+    /// classification of the state reached after consuming it.
+    #[allow(clippy::type_complexity)]
+    fn mutation_characterise_zip(&self, word: &[Self::Alphabet]) -> Result<Vec<(Self::Alphabet, Self::StateSort)>, Self::ErrorType>
+    where
+        Self: Sized,
+        Self::Alphabet: Clone
+    {
+        let mut automaton = self.mutation_automaton();
+        let mut pairs = Vec::with_capacity(word.len());
+        for character in word {
+            pairs.push((character.clone(), automaton.update_sort_state(character)?));
+        }
+        Ok(pairs)
+    }
+
+    /// Compares the classification trace of an input sequence against an expected trace.
+    ///
+    /// The mutation paradigm counterpart of
+    /// [`trace_diff`](crate::DeterministicAutomatonBlueprint::trace_diff).
+    fn mutation_trace_diff(&self, word: &[Self::Alphabet], expected: &[Self::StateSort]) -> Result<Option<usize>, Self::ErrorType>
+    where
+        Self: Sized,
+        Self::StateSort: PartialEq
+    {
+        let mut automaton = self.mutation_automaton();
+        for (index, (character, expected_sort)) in word.iter().zip(expected).enumerate() {
+            if automaton.update_sort_state(character)? != *expected_sort {
+                return Ok(Some(index));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Processes each of a sequence of records independently, returning one result per record.
+    ///
+    /// The mutation paradigm counterpart of
+    /// [`characterise_records`](crate::DeterministicAutomatonBlueprint::characterise_records):
+    /// a single runtime automaton is [`reset`](MutationAutomaton::reset) between records.
+    fn mutation_characterise_records<'w, I>(&self, records: I) -> Vec<Result<Self::StateSort, Self::ErrorType>>
+    where
+        Self: Sized,
+        Self::Alphabet: 'w,
+        I: IntoIterator<Item = &'w [Self::Alphabet]>
+    {
+        let mut automaton = self.mutation_automaton();
+        records.into_iter()
+            .map(|record| {
+                automaton.reset();
+                for character in record {
+                    automaton.update_state(character)?;
+                }
+                automaton.current_state_sort()
+            })
+            .collect()
+    }
+
+    /// Processes an entire input sequence, also returning how many transitions were performed.
+    ///
+    /// The mutation paradigm counterpart of
+    /// [`characterise_counted`](crate::DeterministicAutomatonBlueprint::characterise_counted):
+    /// the count is the number of [`mutation_transition_map`](Self::mutation_transition_map) calls made.
+    fn mutation_characterise_counted(&self, word: &[Self::Alphabet]) -> Result<(Self::StateSort, usize), Self::ErrorType>
+    where
+        Self: Sized
+    {
+        let mut automaton = self.mutation_automaton();
+        let mut steps = 0;
+        for character in word {
+            automaton.update_state(character)?;
+            steps += 1;
+        }
+        Ok((automaton.current_state_sort()?, steps))
+    }
+
+    /// Processes an input sequence, returning every state visited.
+    ///
+    /// The mutation paradigm counterpart of
+    /// [`state_path`](crate::DeterministicAutomatonBlueprint::state_path): the state is
+    /// cloned before each in-place transition, so the path has `word.len() + 1` entries.
+    fn mutation_state_path(&self, word: &[Self::Alphabet]) -> Result<Vec<Self::State>, Self::ErrorType>
+    where
+        Self: Sized
+    {
+        let mut state = self.initial_mutation_state();
+        let mut path = Vec::with_capacity(word.len() + 1);
+        for character in word {
+            path.push(state.clone());
+            self.mutation_transition_map(&mut state, character)?;
+        }
+        path.push(state);
+        Ok(path)
+    }
+
+    /// Returns the length and state of every accepting prefix of an input sequence.
+    ///
+    /// The mutation paradigm counterpart of
+    /// [`accepting_snapshots`](crate::DeterministicAutomatonBlueprint::accepting_snapshots).
+    fn mutation_accepting_snapshots(&self, word: &[Self::Alphabet]) -> Result<Vec<(usize, Self::State)>, Self::ErrorType>
+    where
+        Self: Sized + MutationAutomatonBlueprint<StateSort = BasicStateSort>
+    {
+        let mut automaton = self.mutation_automaton();
+        let mut snapshots = Vec::new();
+        if automaton.current_state_sort()? == BasicStateSort::Accept {
+            snapshots.push((0, automaton.view_state().clone()));
+        }
+        for (index, character) in word.iter().enumerate() {
+            if automaton.update_sort_state(character)? == BasicStateSort::Accept {
+                snapshots.push((index + 1, automaton.view_state().clone()));
+            }
+        }
+        Ok(snapshots)
+    }
+
+    /// Counts how many prefixes of `word` land in each classification.
+    ///
+    /// Behaves like [`sort_histogram`](crate::DeterministicAutomatonBlueprint::sort_histogram),
+    /// including the empty prefix in the tally.
+    fn mutation_sort_histogram(&self, word: &[Self::Alphabet]) -> Result<HashMap<Self::StateSort, usize>, Self::ErrorType>
+    where
+        Self: Sized,
+        Self::StateSort: Eq + Hash
+    {
+        let mut automaton = self.mutation_automaton();
+        let mut histogram = HashMap::new();
+        *histogram.entry(automaton.current_state_sort()?).or_insert(0) += 1;
+        for character in word {
+            *histogram.entry(automaton.update_sort_state(character)?).or_insert(0) += 1;
+        }
+        Ok(histogram)
+    }
+
+    /// Processes an input prefix and returns the running automaton for later continuation.
+    ///
+    /// The returned automaton is in the state reached after `word`, so further symbols can
+    /// be fed with [`update_state`](MutationAutomaton::update_state) as they arrive without
+    /// re-processing the prefix. This suits input arriving in bursts, such as a REPL.
+    fn mutation_characterise_partial(&self, word: &[Self::Alphabet]) -> Result<MutationAutomaton<'_, Self>, Self::ErrorType>
+    where
+        Self: Sized
+    {
+        let mut automaton = self.mutation_automaton();
+        for character in word {
+            automaton.update_state(character)?;
+        }
+        Ok(automaton)
+    }
+
+    /// Processes an entire input sequence, returning `Ok(())` only if it is accepted.
+    ///
+    /// The mutation paradigm counterpart of
+    /// [`validate`](crate::DeterministicAutomatonBlueprint::validate).
+    fn mutation_validate(&self, word: &[Self::Alphabet]) -> Result<(), ValidationError<Self::ErrorType>>
+    where
+        Self: Sized + MutationAutomatonBlueprint<StateSort = BasicStateSort>
+    {
+        match self.mutation_characterise(word).map_err(ValidationError::Inner)? {
+            BasicStateSort::Accept => Ok(()),
+            BasicStateSort::Reject => Err(ValidationError::Rejected),
+        }
+    }
+
+    /// Returns whether the language contains the empty word.
+    ///
+    /// The mutation paradigm counterpart of
+    /// [`accepts_empty`](crate::DeterministicAutomatonBlueprint::accepts_empty).
+    fn mutation_accepts_empty(&self) -> Result<bool, Self::ErrorType>
+    where
+        Self: Sized + MutationAutomatonBlueprint<StateSort = BasicStateSort>
+    {
+        Ok(self.mutation_state_sort_map(&self.initial_mutation_state())? == BasicStateSort::Accept)
+    }
+
+    /// Returns a blueprint that behaves like this one but starts from `state`.
+    ///
+    /// See [`MutationWithInitialBlueprint`] for details.
+    fn mutation_with_initial(&self, state: Self::State) -> MutationWithInitialBlueprint<'_, Self>
+    where
+        Self: Sized
+    {
+        MutationWithInitialBlueprint::new(self, state)
+    }
+
     /// Creates a runtime automaton instance from this blueprint.
     fn mutation_automaton(&self) -> MutationAutomaton<'_, Self> 
     where 
@@ -126,6 +417,33 @@ pub struct MutationAutomaton<'a, Blueprint:MutationAutomatonBlueprint> {
     current_state: Blueprint::State
 }
 
+/// Cloning a running automaton forks it: the clone shares the blueprint reference and
+/// continues independently from a clone of the current state.
+impl<Blueprint: MutationAutomatonBlueprint> Clone for MutationAutomaton<'_, Blueprint> {
+    fn clone(&self) -> Self {
+        Self {
+            blueprint: self.blueprint,
+            current_state: self.current_state.clone()
+        }
+    }
+}
+
+/// Two running automata are equal when their current states are equal.
+///
+/// Only the state participates: the blueprint is ignored, so automata running different
+/// blueprint instances of the same type compare equal whenever their states do. This suits
+/// deduplicating explored configurations of a single blueprint, for example in a search
+/// frontier of forked runs.
+impl<Blueprint> PartialEq for MutationAutomaton<'_, Blueprint>
+where
+    Blueprint: MutationAutomatonBlueprint,
+    Blueprint::State: PartialEq
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.current_state == other.current_state
+    }
+}
+
 impl<'a, Blueprint:MutationAutomatonBlueprint> MutationAutomaton<'a, Blueprint> {
     /// Creates a new mutation automaton instance from a blueprint.
     pub fn new(blueprint: &'a Blueprint) -> Self {
@@ -151,11 +469,151 @@ impl<'a, Blueprint:MutationAutomatonBlueprint> MutationAutomaton<'a, Blueprint>
         self.current_state_sort()
     }
 
+    /// Returns the classification the automaton would have after a symbol, without consuming it.
+    ///
+    /// The transition is applied to a clone of the current state, so the automaton itself is
+    /// left untouched whether the dry run succeeds or fails. This is useful for lookahead
+    /// decisions, such as checking whether a symbol would keep the input acceptable before
+    /// committing to it.
+    pub fn try_transition(&self, character: &Blueprint::Alphabet) -> Result<Blueprint::StateSort, Blueprint::ErrorType> {
+        let mut state = self.current_state.clone();
+        self.blueprint.mutation_transition_map(&mut state, character)?;
+        self.blueprint.mutation_state_sort_map(&state)
+    }
+
+    /// Returns the automaton to the blueprint's initial state.
+    ///
+    /// This allows one runtime instance to be reused for several independent inputs.
+    pub fn reset(&mut self) {
+        self.current_state = self.blueprint.initial_mutation_state();
+    }
+
     /// Returns a reference to the current state.
-    pub fn view_state(&'a self) -> &'a Blueprint::State {
+    pub fn view_state(&self) -> &Blueprint::State {
         &self.current_state
     }
 
+    /// Applies a closure to the current state in-place, without invoking any transition.
+    ///
+    /// This is an escape hatch for recognizers that react to external events as well as
+    /// input symbols, such as clearing a buffer between transitions. The blueprint's
+    /// transition logic is bypassed entirely, so the closure is responsible for leaving the
+    /// state in a configuration the blueprint can continue from.
+    pub fn modify_state<F: FnOnce(&mut Blueprint::State)>(&mut self, f: F) {
+        f(&mut self.current_state)
+    }
+
+    /// Consumes the automaton and returns the current state.
+    pub fn take_state(self) -> Blueprint::State {
+        self.current_state
+    }
+}
+
+/// A runtime instance of a mutation automaton that memoizes the current classification.
+///
+/// [`MutationAutomaton::current_state_sort`] calls the blueprint's
+/// [`mutation_state_sort_map`](MutationAutomatonBlueprint::mutation_state_sort_map) on every
+/// query. When classification is costly and queried more often than the state changes, this
+/// runtime keeps the last successful classification and only recomputes it after the state
+/// has been touched by [`update_state`](Self::update_state), [`reset`](Self::reset) or
+/// [`modify_state`](Self::modify_state). Errors are never cached, so a failing classification
+/// is retried on the next query.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, MutationAutomatonBlueprint};
+/// use deterministic_automata::mutation_automaton::CachedMutationAutomaton;
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+///
+/// let counter = CounterAutomatonBlueprint::new('a', 'b');
+/// let mut automaton = CachedMutationAutomaton::new(&counter);
+///
+/// automaton.update_state(&'a').unwrap();
+/// assert_eq!(automaton.current_state_sort().unwrap(), BasicStateSort::Reject);
+/// assert_eq!(automaton.update_sort_state(&'b').unwrap(), BasicStateSort::Accept);
+/// ```
+pub struct CachedMutationAutomaton<'a, Blueprint>
+where
+    Blueprint: MutationAutomatonBlueprint,
+    Blueprint::StateSort: Clone
+{
+    blueprint: &'a Blueprint,
+    current_state: Blueprint::State,
+    cached_sort: Option<Blueprint::StateSort>
+}
+
+impl<Blueprint> Clone for CachedMutationAutomaton<'_, Blueprint>
+where
+    Blueprint: MutationAutomatonBlueprint,
+    Blueprint::StateSort: Clone
+{
+    fn clone(&self) -> Self {
+        Self {
+            blueprint: self.blueprint,
+            current_state: self.current_state.clone(),
+            cached_sort: self.cached_sort.clone()
+        }
+    }
+}
+
+impl<'a, Blueprint> CachedMutationAutomaton<'a, Blueprint>
+where
+    Blueprint: MutationAutomatonBlueprint,
+    Blueprint::StateSort: Clone
+{
+    /// Creates a new caching mutation automaton instance from a blueprint.
+    pub fn new(blueprint: &'a Blueprint) -> Self {
+        Self {
+            blueprint,
+            current_state: blueprint.initial_mutation_state(),
+            cached_sort: None
+        }
+    }
+
+    /// Returns the classification of the current state, computing it only if the state has
+    /// changed since the last successful query.
+    pub fn current_state_sort(&mut self) -> Result<Blueprint::StateSort, Blueprint::ErrorType> {
+        if let Some(sort) = &self.cached_sort {
+            return Ok(sort.clone());
+        }
+        let sort = self.blueprint.mutation_state_sort_map(&self.current_state)?;
+        self.cached_sort = Some(sort.clone());
+        Ok(sort)
+    }
+
+    /// Processes a single input symbol, updating the current state in-place and invalidating
+    /// the cached classification.
+    pub fn update_state(&mut self, character: &Blueprint::Alphabet) -> Result<(), Blueprint::ErrorType> {
+        self.cached_sort = None;
+        self.blueprint.mutation_transition_map(&mut self.current_state, character)
+    }
+
+    /// Processes a single input symbol and returns the new state classification.
+    pub fn update_sort_state(&mut self, character: &Blueprint::Alphabet) -> Result<Blueprint::StateSort, Blueprint::ErrorType> {
+        self.update_state(character)?;
+        self.current_state_sort()
+    }
+
+    /// Returns the automaton to the blueprint's initial state.
+    pub fn reset(&mut self) {
+        self.cached_sort = None;
+        self.current_state = self.blueprint.initial_mutation_state();
+    }
+
+    /// Returns a reference to the current state.
+    pub fn view_state(&self) -> &Blueprint::State {
+        &self.current_state
+    }
+
+    /// Applies a closure to the current state in-place, without invoking any transition.
+    ///
+    /// The cached classification is invalidated, as the closure may change it.
+    pub fn modify_state<F: FnOnce(&mut Blueprint::State)>(&mut self, f: F) {
+        self.cached_sort = None;
+        f(&mut self.current_state)
+    }
+
     /// Consumes the automaton and returns the current state.
     pub fn take_state(self) -> Blueprint::State {
         self.current_state
@@ -180,7 +638,109 @@ impl<Blueprint: DeterministicAutomatonBlueprint> MutationAutomatonBlueprint for
     }
 
     fn mutation_transition_map(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(),Self::ErrorType> {
-        *state = self.transition_map(state, character)?;
-        Ok(())
+        self.transition_in_place(state, character)
+    }
+
+    fn mutation_validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.validate_state(state)
+    }
+}
+
+/// An adapter presenting a mutation automaton blueprint as a deterministic one.
+///
+/// Every [`DeterministicAutomatonBlueprint`] is already a [`MutationAutomatonBlueprint`]
+/// through a blanket implementation. This wrapper closes the loop in the other direction,
+/// allowing mutation automata to be used wherever a deterministic blueprint is required,
+/// such as in the deterministic product constructions.
+///
+/// # Cost
+///
+/// Each call to [`transition_map`](DeterministicAutomatonBlueprint::transition_map) clones
+/// the current state and applies [`mutation_transition_map`](MutationAutomatonBlueprint::mutation_transition_map)
+/// to the clone. For large states this loses the in-place advantage of the mutation paradigm,
+/// so prefer running the mutation blueprint directly when a deterministic one is not required.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint, MutationAutomatonBlueprint};
+/// use deterministic_automata::mutation_automaton::Deterministicize;
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::product_automaton::BasicIntersectionAutomatonBlueprint;
+///
+/// struct Balanced;
+///
+/// impl MutationAutomatonBlueprint for Balanced {
+///     type State = i32;
+///     type Alphabet = char;
+///     type StateSort = BasicStateSort;
+///     type ErrorType = String;
+///
+///     fn initial_mutation_state(&self) -> Self::State { 0 }
+///
+///     fn mutation_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+///         Ok(if *state == 0 { BasicStateSort::Accept } else { BasicStateSort::Reject })
+///     }
+///
+///     fn mutation_transition_map(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+///         *state += if *character == 'a' { 1 } else { -1 };
+///         Ok(())
+///     }
+/// }
+///
+/// let balanced = Balanced;
+/// let deterministic = Deterministicize::new(&balanced);
+/// let counter = CounterAutomatonBlueprint::new('a', 'b');
+/// let intersection = BasicIntersectionAutomatonBlueprint::new(&deterministic, &counter);
+///
+/// assert_eq!(intersection.characterise(&['a', 'b']).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(intersection.characterise(&['b', 'a']).unwrap(), BasicStateSort::Reject);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Deterministicize<'a, M: MutationAutomatonBlueprint> {
+    blueprint: &'a M
+}
+
+impl<'a, M: MutationAutomatonBlueprint> Deterministicize<'a, M> {
+    /// Creates a new deterministic adapter around a mutation automaton blueprint.
+    pub fn new(blueprint: &'a M) -> Self {
+        Self { blueprint }
+    }
+
+    /// Returns the wrapped mutation automaton blueprint.
+    pub fn inner(&self) -> &'a M {
+        self.blueprint
+    }
+}
+
+impl<M: MutationAutomatonBlueprint> DeterministicAutomatonBlueprint for Deterministicize<'_, M> {
+    type State = M::State;
+
+    type Alphabet = M::Alphabet;
+
+    type StateSort = M::StateSort;
+
+    type ErrorType = M::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        self.blueprint.initial_mutation_state()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort,Self::ErrorType> {
+        self.blueprint.mutation_state_sort_map(state)
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        let mut next_state = state.clone();
+        self.blueprint.mutation_transition_map(&mut next_state, character)?;
+        Ok(next_state)
+    }
+
+    fn transition_in_place(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        self.blueprint.mutation_transition_map(state, character)
+    }
+
+    fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.blueprint.mutation_validate_state(state)
     }
 }
@@ -0,0 +1,2453 @@
+//! Lazy operations and adapters on automaton blueprints that do not require state enumeration.
+//!
+//! This module collects operations that transform the language recognized by an automaton
+//! lazily, by wrapping an existing blueprint rather than exploring its state space. Because
+//! nothing is enumerated, these operations work equally well for automata with infinite
+//! state spaces such as the [`CounterAutomatonBlueprint`](crate::counter_automaton_example::CounterAutomatonBlueprint).
+//!
+//! # Operations Provided
+//!
+//! ## [`complement`]
+//!
+//! Negates the classification of a [`BasicStateSort`] automaton, recognizing the complement
+//! of its language: `Σ* \ L(A)`. Combined with the union and intersection blueprints in
+//! [`product_automaton`](crate::product_automaton), this gives the full set of boolean
+//! operations on languages.
+//!
+//! ## [`tri_complement`]
+//!
+//! The three-valued counterpart of [`complement`] for [`TriStateSort`] automata: decided
+//! verdicts are swapped and undecided ones stay undecided, following Kleene logic.
+//!
+//! ## [`WithInitialBlueprint`] and [`MutationWithInitialBlueprint`]
+//!
+//! Override the initial state of a blueprint with a stored state while delegating
+//! transitions and classifications, so the same blueprint can start from different
+//! configurations. Usually created through
+//! [`with_initial`](crate::DeterministicAutomatonBlueprint::with_initial) or
+//! [`mutation_with_initial`](crate::MutationAutomatonBlueprint::mutation_with_initial).
+//!
+//! ## [`derivative`]
+//!
+//! Builds the left quotient of a language by a prefix, the Brzozowski derivative, by running
+//! the prefix and resuming from the state reached with [`WithInitialBlueprint`].
+//!
+//! ## [`MaxLengthBlueprint`]
+//!
+//! Restricts the language of a [`BasicStateSort`] automaton to words of at most `N` symbols
+//! by counting consumed symbols alongside the wrapped state. Usually created through
+//! [`max_length`](crate::DeterministicAutomatonBlueprint::max_length).
+//!
+//! ## [`MinLengthBlueprint`]
+//!
+//! The counterpart of [`MaxLengthBlueprint`]: only allows acceptance once at least `N`
+//! symbols have been consumed. Combining the two gives exact-length and length-range
+//! recognizers. Usually created through
+//! [`min_length`](crate::DeterministicAutomatonBlueprint::min_length).
+//!
+//! ## [`NonEmptyBlueprint`]
+//!
+//! Rejects the empty word while leaving the rest of the language unchanged, the clearer
+//! spelling of a minimum length of one. Usually created through
+//! [`non_empty`](crate::DeterministicAutomatonBlueprint::non_empty).
+//!
+//! ## [`OptionalBlueprint`]
+//!
+//! Adds the empty word to a language, the combinator form of the regular expression
+//! operator `?`. Usually created through
+//! [`optional`](crate::DeterministicAutomatonBlueprint::optional).
+//!
+//! ## [`CaseInsensitiveBlueprint`]
+//!
+//! Runs a `char` automaton on both the input and its lowercasing and accepts if either
+//! run does, so a recognizer written for lowercase input accepts any case. Usually created
+//! through [`case_insensitive`](crate::DeterministicAutomatonBlueprint::case_insensitive).
+//!
+//! ## [`repeat`]
+//!
+//! Recognizes exactly `k` consecutive words of a [`BasicStateSort`] automaton's language,
+//! `L(A)^k`, by tracking every way the input read so far can be split into words.
+//!
+//! ## [`ErrorsAsRejectBlueprint`]
+//!
+//! Turns transition errors of a [`BasicStateSort`] automaton into a permanent reject state,
+//! optionally only for the errors a filter selects, so a foreign symbol rejects the word
+//! instead of aborting the run.
+//!
+//! ## [`PeakTrackingBlueprint`]
+//!
+//! Carries the running maximum of a state metric alongside the wrapped state, such as the
+//! deepest nesting reached by a bracket checker. Usually created through
+//! [`track_peak`](crate::DeterministicAutomatonBlueprint::track_peak).
+//!
+//! ## [`compile_to_table`]
+//!
+//! Unlike the other operations here, this one does enumerate states: it explores every
+//! state reachable over a given finite alphabet and produces a [`CompiledDfa`], a dense
+//! transition table that recognizes words by array indexing alone. It only terminates for
+//! automata with finitely many reachable states.
+//!
+//! ## [`is_prefix_closed`] and [`PrefixClosureBlueprint`]
+//!
+//! Check whether a language contains every prefix of its words, and build the prefix
+//! closure of a language, accepting exactly the inputs that can still be extended to an
+//! accepted word. Both enumerate the states reachable over a finite alphabet.
+//!
+//! ## [`equivalence_classes`]
+//!
+//! Partitions a set of states into classes that no continuation can distinguish, the
+//! analysis underlying minimization. Like [`compile_to_table`], it enumerates the states
+//! reachable over a finite alphabet.
+//!
+//! ## [`are_isomorphic`]
+//!
+//! Checks whether two automata have the same reachable structure up to renaming states,
+//! which is stronger than recognizing the same language, for instance to confirm that a
+//! minimization produced the canonical automaton.
+//!
+//! ## [`trap_states`]
+//!
+//! Finds the reachable rejecting states from which no input leads back to acceptance, also
+//! by enumerating the states reachable over a finite alphabet.
+//!
+//! ## [`is_live`]
+//!
+//! Decides whether a state can still reach acceptance, that is whether the input read so
+//! far is a viable prefix. The search stops at the first accepting state, so only dead
+//! states need a finite reachable set.
+//!
+//! ## [`live_next_symbols`]
+//!
+//! Lists the next symbols after which a state can still reach acceptance, using [`is_live`]
+//! on each successor, for autocompletion and other input guidance.
+//!
+//! ## [`state_count`]
+//!
+//! Counts the states reachable from the initial state, with an optional cap so that
+//! infinite-state automata are detected rather than explored forever.
+//!
+//! ## [`predecessors`]
+//!
+//! Lists the `(state, symbol)` pairs over supplied finite domains that transition into a
+//! target state, for backward reachability and for tracing how a state was reached.
+//!
+//! ## [`intersection_nonempty_bounded`]
+//!
+//! Searches breadth-first for a word accepted by two automata, up to a length bound. It
+//! never compares states, so it applies to infinite-state automata, at the cost of
+//! completeness.
+//!
+//! ## [`TaggedAlphabet`], [`only_left`] and [`only_right`]
+//!
+//! Lift blueprints over two different alphabets to a common tagged alphabet, where each
+//! ignores the symbols tagged for the other. A product over the tagged alphabet then routes
+//! each symbol to the component it belongs to.
+//!
+//! ## [`agree`]
+//!
+//! Runs two blueprints in lockstep over the same input and reports whether their final
+//! classifications match, for differential testing of two implementations.
+//!
+//! ## [`tee`]
+//!
+//! Runs two blueprints independently over the same input and returns both verdicts, each
+//! with its own error type, without coupling them into a product.
+//!
+//! ## [`split_characterise`]
+//!
+//! Runs one blueprint on a prefix of fixed length and another on the rest, accepting when
+//! both do: concatenation at a known split point, such as a header followed by a body.
+//!
+//! # Example: De Morgan's Law
+//!
+//! ```
+//! use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+//! use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+//! use deterministic_automata::product_automaton::{BasicIntersectionAutomatonBlueprint, BasicUnionAutomatonBlueprint};
+//! use deterministic_automata::operations::complement;
+//!
+//! let ab = CounterAutomatonBlueprint::new('a', 'b');
+//! let ba = CounterAutomatonBlueprint::new('b', 'a');
+//!
+//! // ¬(A ∪ B) = ¬A ∩ ¬B
+//! let union = BasicUnionAutomatonBlueprint::new(&ab, &ba);
+//! let not_union = complement(&union);
+//! let (not_ab, not_ba) = (complement(&ab), complement(&ba));
+//! let intersection_of_complements = BasicIntersectionAutomatonBlueprint::new(&not_ab, &not_ba);
+//!
+//! for word in ["", "ab", "ba", "aab", "abba"] {
+//!     let input: Vec<char> = word.chars().collect();
+//!     assert_eq!(not_union.characterise(&input), intersection_of_complements.characterise(&input));
+//! }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use crate::{BasicStateSort, DescribableAutomaton, DeterministicAutomatonBlueprint, MutationAutomatonBlueprint, TriStateSort};
+
+/// A blueprint recognizing the complement of the language of a [`BasicStateSort`] automaton.
+///
+/// This blueprint shares the state space and transitions of the wrapped automaton and
+/// flips its classification: `Accept` becomes `Reject` and vice versa. No states are
+/// enumerated, so the wrapped automaton may have an unbounded state space.
+///
+/// # Totality
+///
+/// The complement is only the true language complement when the wrapped automaton is
+/// total, that is, when its transitions and classifications never return an error. If the
+/// wrapped automaton is partial, the complement inherits its errors unchanged rather than
+/// treating them as acceptance.
+///
+/// # Construction
+///
+/// Use [`complement`] or [`new`](Self::new) to create an instance from a blueprint reference.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComplementAutomatonBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    inner: &'a A
+}
+
+impl<'a, A> ComplementAutomatonBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    /// Creates a new complement blueprint from a component blueprint.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the automaton blueprint whose language is complemented
+    pub fn new(inner: &'a A) -> Self {
+        Self { inner }
+    }
+}
+
+impl<A> DeterministicAutomatonBlueprint for ComplementAutomatonBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    type State = A::State;
+
+    type Alphabet = A::Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = A::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        self.inner.initial_state()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(match self.inner.state_sort_map(state)? {
+            BasicStateSort::Accept => BasicStateSort::Reject,
+            BasicStateSort::Reject => BasicStateSort::Accept,
+        })
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        self.inner.transition_map(state, character)
+    }
+
+    fn transition_in_place(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        self.inner.transition_in_place(state, character)
+    }
+
+    fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.inner.validate_state(state)
+    }
+}
+
+/// Returns a blueprint recognizing the complement of the language of a total automaton.
+///
+/// See [`ComplementAutomatonBlueprint`] for details, including how errors from a partial
+/// automaton are propagated.
+pub fn complement<A>(automaton: &A) -> ComplementAutomatonBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    ComplementAutomatonBlueprint::new(automaton)
+}
+
+/// A blueprint negating the classification of a [`TriStateSort`] automaton.
+///
+/// This blueprint shares the state space and transitions of the wrapped automaton and
+/// negates its classification with [`TriStateSort::negate`]: `Accept` and `Reject` swap,
+/// while `Unknown` stays `Unknown`. Flipping an undecided verdict to a decided one would
+/// claim knowledge the wrapped automaton does not have, so unlike
+/// [`ComplementAutomatonBlueprint`] this is not a total flip. Negating twice gives back the
+/// original classification.
+///
+/// Errors from the wrapped automaton are propagated unchanged.
+///
+/// # Construction
+///
+/// Use [`tri_complement`] or [`new`](Self::new) to create an instance from a blueprint reference.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriComplementBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = TriStateSort>
+{
+    inner: &'a A
+}
+
+impl<'a, A> TriComplementBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = TriStateSort>
+{
+    /// Creates a new three-valued complement blueprint from a component blueprint.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the automaton blueprint whose classification is negated
+    pub fn new(inner: &'a A) -> Self {
+        Self { inner }
+    }
+}
+
+impl<A> DeterministicAutomatonBlueprint for TriComplementBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = TriStateSort>
+{
+    type State = A::State;
+
+    type Alphabet = A::Alphabet;
+
+    type StateSort = TriStateSort;
+
+    type ErrorType = A::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        self.inner.initial_state()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(self.inner.state_sort_map(state)?.negate())
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        self.inner.transition_map(state, character)
+    }
+
+    fn transition_in_place(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        self.inner.transition_in_place(state, character)
+    }
+
+    fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.inner.validate_state(state)
+    }
+}
+
+/// Returns a blueprint negating the classification of a [`TriStateSort`] automaton under
+/// Kleene logic.
+///
+/// See [`TriComplementBlueprint`] for details.
+pub fn tri_complement<A>(automaton: &A) -> TriComplementBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = TriStateSort>
+{
+    TriComplementBlueprint::new(automaton)
+}
+
+/// A blueprint that starts a deterministic automaton from a stored initial state.
+///
+/// All transitions and classifications are delegated to the wrapped blueprint; only
+/// [`initial_state`](DeterministicAutomatonBlueprint::initial_state) is overridden, returning
+/// a clone of the stored state. This is useful for resuming a logical run, or for testing
+/// transitions from a specific non-initial configuration.
+///
+/// # Construction
+///
+/// Use [`with_initial`](DeterministicAutomatonBlueprint::with_initial) or [`new`](Self::new).
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterState};
+///
+/// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+/// let resumed = blueprint.with_initial(CounterState::Start(2));
+///
+/// assert_eq!(resumed.characterise(&['b', 'b']).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(blueprint.characterise(&['b', 'b']).unwrap(), BasicStateSort::Reject);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithInitialBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint
+{
+    inner: &'a A,
+    initial: A::State
+}
+
+impl<'a, A> WithInitialBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint
+{
+    /// Creates a new blueprint starting the wrapped blueprint from `initial`.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the blueprint providing transitions and classifications
+    /// * `initial` - The state to start from instead of the blueprint's own initial state
+    pub fn new(inner: &'a A, initial: A::State) -> Self {
+        Self { inner, initial }
+    }
+}
+
+impl<A> DeterministicAutomatonBlueprint for WithInitialBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint
+{
+    type State = A::State;
+
+    type Alphabet = A::Alphabet;
+
+    type StateSort = A::StateSort;
+
+    type ErrorType = A::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        self.initial.clone()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        self.inner.state_sort_map(state)
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        self.inner.transition_map(state, character)
+    }
+
+    fn transition_in_place(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        self.inner.transition_in_place(state, character)
+    }
+
+    fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.inner.validate_state(state)
+    }
+}
+
+/// Returns the left quotient of the automaton's language by `prefix`.
+///
+/// The result accepts exactly the words `w` such that `prefix` followed by `w` is accepted:
+/// the Brzozowski derivative of the language. It is built by running `prefix` to a state and
+/// resuming from there with [`WithInitialBlueprint`], so no states are enumerated and
+/// infinite-state automata are supported. Errors raised while reading the prefix are returned.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::operations::derivative;
+///
+/// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+/// let after_aa = derivative(&blueprint, &['a', 'a']).unwrap();
+///
+/// // Two more 'b's complete a word of the language
+/// assert_eq!(after_aa.characterise(&['b', 'b']).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(after_aa.characterise(&['a', 'b', 'b']).unwrap(), BasicStateSort::Reject);
+/// ```
+pub fn derivative<'a, A>(automaton: &'a A, prefix: &[A::Alphabet]) -> Result<WithInitialBlueprint<'a, A>, A::ErrorType>
+where
+    A: DeterministicAutomatonBlueprint
+{
+    let mut state = automaton.initial_state();
+    for character in prefix {
+        state = automaton.transition_map(&state, character)?;
+    }
+    Ok(WithInitialBlueprint::new(automaton, state))
+}
+
+/// A blueprint that starts a mutation automaton from a stored initial state.
+///
+/// The mutation paradigm counterpart of [`WithInitialBlueprint`]: only
+/// [`initial_mutation_state`](MutationAutomatonBlueprint::initial_mutation_state) is
+/// overridden, and everything else is delegated to the wrapped blueprint.
+///
+/// # Construction
+///
+/// Use [`mutation_with_initial`](MutationAutomatonBlueprint::mutation_with_initial) or [`new`](Self::new).
+#[derive(Debug, Clone, PartialEq)]
+pub struct MutationWithInitialBlueprint<'a, A>
+where
+    A: MutationAutomatonBlueprint
+{
+    inner: &'a A,
+    initial: A::State
+}
+
+impl<'a, A> MutationWithInitialBlueprint<'a, A>
+where
+    A: MutationAutomatonBlueprint
+{
+    /// Creates a new blueprint starting the wrapped mutation blueprint from `initial`.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the blueprint providing transitions and classifications
+    /// * `initial` - The state to start from instead of the blueprint's own initial state
+    pub fn new(inner: &'a A, initial: A::State) -> Self {
+        Self { inner, initial }
+    }
+}
+
+impl<A> MutationAutomatonBlueprint for MutationWithInitialBlueprint<'_, A>
+where
+    A: MutationAutomatonBlueprint
+{
+    type State = A::State;
+
+    type Alphabet = A::Alphabet;
+
+    type StateSort = A::StateSort;
+
+    type ErrorType = A::ErrorType;
+
+    fn initial_mutation_state(&self) -> Self::State {
+        self.initial.clone()
+    }
+
+    fn mutation_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        self.inner.mutation_state_sort_map(state)
+    }
+
+    fn mutation_transition_map(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        self.inner.mutation_transition_map(state, character)
+    }
+    fn mutation_validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.inner.mutation_validate_state(state)
+    }
+}
+
+/// Runs two blueprints in lockstep on `word` and returns whether their final classifications match.
+///
+/// Both automata consume each symbol before the next is read, and the first error from
+/// either automaton is returned immediately. No states are enumerated, so this only compares
+/// the verdicts on the given word; it is intended for differential testing, such as checking
+/// an original and a refactored automaton against the same random inputs.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::operations::agree;
+///
+/// let ab = CounterAutomatonBlueprint::new('a', 'b');
+/// let ac = CounterAutomatonBlueprint::new('a', 'c');
+///
+/// assert!(agree(&ab, &ac, &['a']).unwrap());
+/// assert!(!agree(&ab, &ac, &['a', 'b']).unwrap());
+/// ```
+pub fn agree<A, B, Alphabet, StateSort, ErrorType>(first: &A, second: &B, word: &[Alphabet]) -> Result<bool, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq,
+    StateSort: PartialEq
+{
+    let (mut a, mut b) = (first.automaton(), second.automaton());
+    for character in word {
+        a.update_state(character)?;
+        b.update_state(character)?;
+    }
+    Ok(a.current_state_sort()? == b.current_state_sort()?)
+}
+
+/// Runs two blueprints independently on `word` and returns both final classifications.
+///
+/// Unlike a product or [`agree`], the automata share nothing but the input: each runs to
+/// completion on its own, so an error in one does not stop the other, and the two may have
+/// different state sorts and error types. Each result is attributed to its own automaton.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::BasicStateSort;
+/// use deterministic_automata::mod_counter_example::ModSumBlueprint;
+/// use deterministic_automata::primitives::NoRepeatsBlueprint;
+/// use deterministic_automata::operations::tee;
+///
+/// let divisible = ModSumBlueprint::new(3);
+/// let no_repeats: NoRepeatsBlueprint<u8> = NoRepeatsBlueprint::new();
+///
+/// let (sum, distinct) = tee(&divisible, &no_repeats, &[1, 2]);
+/// assert_eq!(sum.unwrap(), BasicStateSort::Accept);
+/// assert_eq!(distinct, Ok(BasicStateSort::Accept));
+///
+/// // The digit sum fails on a non-digit, but the other verdict is still reported
+/// let (sum, distinct) = tee(&divisible, &no_repeats, &[12, 12]);
+/// assert!(sum.is_err());
+/// assert_eq!(distinct, Ok(BasicStateSort::Reject));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn tee<A, B, Alphabet>(first: &A, second: &B, word: &[Alphabet]) -> (Result<A::StateSort, A::ErrorType>, Result<B::StateSort, B::ErrorType>)
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet>
+{
+    (first.characterise(word), second.characterise(word))
+}
+
+/// Accepts when `first` accepts `word[..split]` and `second` accepts `word[split..]`.
+///
+/// This is concatenation at a known split point, such as a fixed-width header followed by a
+/// body. Where the full concatenation of two languages must consider every split, here
+/// each automaton runs once over its own part. `second` is only run when `first` accepts,
+/// so its errors are not reported for words whose prefix is already rejected. A `split`
+/// beyond the end of `word` rejects without running either automaton, as the word is too
+/// short to contain the prefix.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::BasicStateSort;
+/// use deterministic_automata::mod_counter_example::ModSumBlueprint;
+/// use deterministic_automata::primitives::ContainsSubsequenceBlueprint;
+/// use deterministic_automata::operations::split_characterise;
+///
+/// // A two-byte magic number, then digits summing to a multiple of three
+/// let magic = ContainsSubsequenceBlueprint::<u8, String>::new(vec![0xCA, 0xFE]);
+/// let body = ModSumBlueprint::new(3);
+///
+/// assert_eq!(split_characterise(&magic, &body, &[0xCA, 0xFE, 1, 2], 2).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(split_characterise(&magic, &body, &[0xCA, 0xFE, 1], 2).unwrap(), BasicStateSort::Reject);
+/// assert_eq!(split_characterise(&magic, &body, &[0xCA], 2).unwrap(), BasicStateSort::Reject);
+/// ```
+pub fn split_characterise<A, B, Alphabet, ErrorType>(first: &A, second: &B, word: &[Alphabet], split: usize) -> Result<BasicStateSort, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    let Some((prefix, suffix)) = word.split_at_checked(split) else {
+        return Ok(BasicStateSort::Reject);
+    };
+    if first.characterise(prefix)? == BasicStateSort::Reject {
+        return Ok(BasicStateSort::Reject);
+    }
+    second.characterise(suffix)
+}
+
+/// A blueprint restricting the language of a [`BasicStateSort`] automaton to a maximum word length.
+///
+/// The state pairs the wrapped automaton's state with the number of symbols consumed. Once
+/// more than `max` symbols have been read the word is rejected regardless of the wrapped
+/// classification, and the wrapped automaton is no longer stepped, so it cannot produce
+/// errors on input that is already rejected. Otherwise the wrapped classification is used.
+///
+/// # Construction
+///
+/// Use [`max_length`](DeterministicAutomatonBlueprint::max_length) or [`new`](Self::new).
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+///
+/// let balanced = CounterAutomatonBlueprint::new('a', 'b');
+/// let short_balanced = balanced.max_length(4);
+///
+/// assert_eq!(short_balanced.characterise(&['a', 'a', 'b', 'b']).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(short_balanced.characterise(&['a', 'a', 'a', 'b', 'b', 'b']).unwrap(), BasicStateSort::Reject);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxLengthBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    inner: &'a A,
+    max: usize
+}
+
+impl<'a, A> MaxLengthBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    /// Creates a new blueprint accepting only words of the wrapped language with at most `max` symbols.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the automaton blueprint being restricted
+    /// * `max` - The maximum number of symbols in an accepted word
+    pub fn new(inner: &'a A, max: usize) -> Self {
+        Self { inner, max }
+    }
+}
+
+impl<A> DeterministicAutomatonBlueprint for MaxLengthBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    type State = (A::State, usize);
+
+    type Alphabet = A::Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = A::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        (self.inner.initial_state(), 0)
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        if state.1 > self.max {
+            Ok(BasicStateSort::Reject)
+        } else {
+            self.inner.state_sort_map(&state.0)
+        }
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        if state.1 >= self.max {
+            Ok((state.0.clone(), self.max + 1))
+        } else {
+            Ok((self.inner.transition_map(&state.0, character)?, state.1 + 1))
+        }
+    }
+
+    fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.inner.validate_state(&state.0)
+    }
+}
+
+impl<A> DescribableAutomaton for MaxLengthBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort> + DescribableAutomaton<Alphabet = <A as DeterministicAutomatonBlueprint>::Alphabet>
+{
+    /// Returns the wrapped example if it is short enough.
+    fn example_accepted(&self) -> Option<Vec<Self::Alphabet>> {
+        self.inner.example_accepted().filter(|word| word.len() <= self.max)
+    }
+}
+
+/// A blueprint restricting the language of a [`BasicStateSort`] automaton to a minimum word length.
+///
+/// The state pairs the wrapped automaton's state with the number of symbols consumed, which
+/// saturates at `min`. Until `min` symbols have been read every state is rejected regardless
+/// of the wrapped classification; afterwards the wrapped classification is used.
+///
+/// # Construction
+///
+/// Use [`min_length`](DeterministicAutomatonBlueprint::min_length) or [`new`](Self::new).
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+///
+/// let balanced = CounterAutomatonBlueprint::new('a', 'b');
+/// let non_trivial = balanced.min_length(1);
+///
+/// assert_eq!(non_trivial.characterise(&[]).unwrap(), BasicStateSort::Reject);
+/// assert_eq!(non_trivial.characterise(&['a', 'b']).unwrap(), BasicStateSort::Accept);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinLengthBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    inner: &'a A,
+    min: usize
+}
+
+impl<'a, A> MinLengthBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    /// Creates a new blueprint accepting only words of the wrapped language with at least `min` symbols.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the automaton blueprint being restricted
+    /// * `min` - The minimum number of symbols in an accepted word
+    pub fn new(inner: &'a A, min: usize) -> Self {
+        Self { inner, min }
+    }
+}
+
+impl<A> DeterministicAutomatonBlueprint for MinLengthBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    type State = (A::State, usize);
+
+    type Alphabet = A::Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = A::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        (self.inner.initial_state(), 0)
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        let sort = self.inner.state_sort_map(&state.0)?;
+        if state.1 < self.min {
+            Ok(BasicStateSort::Reject)
+        } else {
+            Ok(sort)
+        }
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok((self.inner.transition_map(&state.0, character)?, (state.1 + 1).min(self.min)))
+    }
+
+    fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.inner.validate_state(&state.0)
+    }
+}
+
+impl<A> DescribableAutomaton for MinLengthBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort> + DescribableAutomaton<Alphabet = <A as DeterministicAutomatonBlueprint>::Alphabet>
+{
+    /// Returns the wrapped example if it is long enough.
+    fn example_accepted(&self) -> Option<Vec<Self::Alphabet>> {
+        self.inner.example_accepted().filter(|word| word.len() >= self.min)
+    }
+}
+
+/// A blueprint removing the empty word from the language of a [`BasicStateSort`] automaton.
+///
+/// The state pairs a flag recording whether any symbol has been read with the wrapped
+/// automaton's state. Before the first symbol every state is rejected; afterwards the
+/// wrapped classification is used. This accepts the same language as a
+/// [`MinLengthBlueprint`] with a minimum of one, but states the intent directly, which reads
+/// better when guarding automata such as the a^n b^n recognizer that accept `""`.
+///
+/// # Construction
+///
+/// Use [`non_empty`](DeterministicAutomatonBlueprint::non_empty) or [`new`](Self::new).
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+///
+/// let balanced = CounterAutomatonBlueprint::new('a', 'b');
+/// let non_empty = balanced.non_empty();
+///
+/// assert_eq!(non_empty.characterise(&[]).unwrap(), BasicStateSort::Reject);
+/// assert_eq!(non_empty.characterise(&['a', 'b']).unwrap(), BasicStateSort::Accept);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct NonEmptyBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    inner: &'a A
+}
+
+impl<'a, A> NonEmptyBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    /// Creates a new blueprint accepting the non-empty words of the wrapped language.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the automaton blueprint being restricted
+    pub fn new(inner: &'a A) -> Self {
+        Self { inner }
+    }
+}
+
+impl<A> DeterministicAutomatonBlueprint for NonEmptyBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    type State = (bool, A::State);
+
+    type Alphabet = A::Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = A::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        (false, self.inner.initial_state())
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        let sort = self.inner.state_sort_map(&state.1)?;
+        if state.0 {
+            Ok(sort)
+        } else {
+            Ok(BasicStateSort::Reject)
+        }
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok((true, self.inner.transition_map(&state.1, character)?))
+    }
+
+    fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.inner.validate_state(&state.1)
+    }
+}
+
+impl<A> DescribableAutomaton for NonEmptyBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort> + DescribableAutomaton<Alphabet = <A as DeterministicAutomatonBlueprint>::Alphabet>
+{
+    /// Returns the wrapped example unless it is empty.
+    fn example_accepted(&self) -> Option<Vec<Self::Alphabet>> {
+        self.inner.example_accepted().filter(|word| !word.is_empty())
+    }
+}
+
+/// A blueprint adding the empty word to the language of a [`BasicStateSort`] automaton.
+///
+/// Recognizes `L(A) ∪ {ε}`, the regular expression operator `?`. The state pairs a flag
+/// recording whether any symbol has been read with the wrapped automaton's state. Before the
+/// first symbol the automaton is still able to skip `A` entirely and accepts; once a symbol
+/// is read it has committed to matching `A` and the wrapped classification is used.
+///
+/// # Construction
+///
+/// Use [`optional`](DeterministicAutomatonBlueprint::optional) or [`new`](Self::new).
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::mod_counter_example::ModSumBlueprint;
+///
+/// // Digit sums divisible by three, which includes the empty sum
+/// let divisible = ModSumBlueprint::new(3);
+/// let at_least_one = divisible.non_empty();
+/// let optional = at_least_one.optional();
+///
+/// assert_eq!(at_least_one.characterise(&[]).unwrap(), BasicStateSort::Reject);
+/// assert_eq!(optional.characterise(&[]).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(optional.characterise(&[1]).unwrap(), BasicStateSort::Reject);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionalBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    inner: &'a A
+}
+
+impl<'a, A> OptionalBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    /// Creates a new blueprint accepting the wrapped language and the empty word.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the automaton blueprint made optional
+    pub fn new(inner: &'a A) -> Self {
+        Self { inner }
+    }
+}
+
+impl<A> DeterministicAutomatonBlueprint for OptionalBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    type State = (bool, A::State);
+
+    type Alphabet = A::Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = A::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        (false, self.inner.initial_state())
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        let sort = self.inner.state_sort_map(&state.1)?;
+        if state.0 {
+            Ok(sort)
+        } else {
+            Ok(BasicStateSort::Accept)
+        }
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok((true, self.inner.transition_map(&state.1, character)?))
+    }
+
+    fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.inner.validate_state(&state.1)
+    }
+}
+
+impl<A> DescribableAutomaton for OptionalBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    /// Returns the empty word, which is always accepted.
+    fn example_accepted(&self) -> Option<Vec<Self::Alphabet>> {
+        Some(Vec::new())
+    }
+}
+
+/// A blueprint accepting a word if a `char` automaton accepts it either verbatim or lowercased.
+///
+/// The state pairs two runs of the wrapped automaton: one reading the input as given and one
+/// reading each symbol through [`char::to_lowercase`], which may feed it several symbols. The
+/// word is accepted if either run accepts, so a base automaton written for lowercase input
+/// also recognizes its words in any mix of cases: `L(A) ∪ {w | lowercase(w) ∈ L(A)}`.
+/// Errors from either run are propagated, so the wrapped automaton should reject rather than
+/// fail on uppercase symbols.
+///
+/// # Construction
+///
+/// Use [`case_insensitive`](DeterministicAutomatonBlueprint::case_insensitive) or [`new`](Self::new).
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+///
+/// let balanced = CounterAutomatonBlueprint::new('a', 'b');
+/// let any_case = balanced.case_insensitive();
+///
+/// assert_eq!(any_case.characterise(&['A', 'b']).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(balanced.characterise(&['A', 'b']).unwrap(), BasicStateSort::Reject);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaseInsensitiveBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = char, StateSort = BasicStateSort>
+{
+    inner: &'a A
+}
+
+impl<'a, A> CaseInsensitiveBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = char, StateSort = BasicStateSort>
+{
+    /// Creates a new blueprint accepting the words the wrapped automaton accepts verbatim or lowercased.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the `char` automaton blueprint being extended
+    pub fn new(inner: &'a A) -> Self {
+        Self { inner }
+    }
+}
+
+impl<A> DeterministicAutomatonBlueprint for CaseInsensitiveBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = char, StateSort = BasicStateSort>
+{
+    type State = (A::State, A::State);
+
+    type Alphabet = char;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = A::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        (self.inner.initial_state(), self.inner.initial_state())
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        let verbatim = self.inner.state_sort_map(&state.0)?;
+        let lowercased = self.inner.state_sort_map(&state.1)?;
+        Ok(BasicStateSort::from(verbatim == BasicStateSort::Accept || lowercased == BasicStateSort::Accept))
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        let verbatim = self.inner.transition_map(&state.0, character)?;
+        let mut lowercased = state.1.clone();
+        for lower in character.to_lowercase() {
+            lowercased = self.inner.transition_map(&lowercased, &lower)?;
+        }
+        Ok((verbatim, lowercased))
+    }
+
+    fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.inner.validate_state(&state.0)?;
+        self.inner.validate_state(&state.1)
+    }
+}
+
+/// A blueprint recognizing exactly `k` consecutive words of a [`BasicStateSort`] automaton's language.
+///
+/// Since a deterministic automaton cannot know where one word ends and the next begins, the
+/// state tracks every live split of the input so far: a set of pairs of the number of words
+/// already completed and the wrapped automaton's state within the current word. Whenever a
+/// tracked state accepts and fewer than `k` words are complete, a fresh copy starting from
+/// the wrapped initial state is added; this happens when the state is next classified or
+/// stepped, so errors from classifying wrapped states are propagated. The word is accepted when some copy within the `k`-th
+/// word accepts. For `k = 0` only the empty word is accepted.
+///
+/// # Bounded State Requirement
+///
+/// The set of tracked pairs is deduplicated using `Eq` on the wrapped states and holds at
+/// most `k` times the number of distinct reachable wrapped states. It stays small when the
+/// wrapped automaton has few states, but for automata with unbounded state spaces (such as
+/// counters) it can grow with the input.
+///
+/// # Construction
+///
+/// Use [`repeat`] or [`new`](Self::new).
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint, InfallibleAutomatonBlueprint};
+/// use deterministic_automata::operations::repeat;
+///
+/// struct Digit;
+///
+/// impl InfallibleAutomatonBlueprint for Digit {
+///     type State = u8;
+///     type Alphabet = char;
+///     type StateSort = BasicStateSort;
+///
+///     fn initial(&self) -> Self::State { 0 }
+///
+///     fn sort(&self, state: &Self::State) -> Self::StateSort {
+///         if *state == 1 { BasicStateSort::Accept } else { BasicStateSort::Reject }
+///     }
+///
+///     fn transition(&self, state: &Self::State, character: &Self::Alphabet) -> Self::State {
+///         if *state == 0 && character.is_ascii_digit() { 1 } else { 2 }
+///     }
+/// }
+///
+/// let three_digits = repeat(&Digit, 3);
+///
+/// assert_eq!(three_digits.characterise(&['4', '2', '7']).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(three_digits.characterise(&['4', '2']).unwrap(), BasicStateSort::Reject);
+/// assert_eq!(three_digits.characterise(&['4', 'x', '7']).unwrap(), BasicStateSort::Reject);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepeatBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    A::State: Eq
+{
+    inner: &'a A,
+    count: usize
+}
+
+impl<'a, A> RepeatBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    A::State: Eq
+{
+    /// Creates a new blueprint recognizing exactly `count` consecutive words of the wrapped language.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the automaton blueprint whose words are repeated
+    /// * `count` - The number of consecutive words required
+    pub fn new(inner: &'a A, count: usize) -> Self {
+        Self { inner, count }
+    }
+
+    fn close(&self, mut states: Vec<(usize, A::State)>) -> Result<Vec<(usize, A::State)>, A::ErrorType> {
+        let mut index = 0;
+        while index < states.len() {
+            let (completed, state) = &states[index];
+            if completed + 1 < self.count && self.inner.state_sort_map(state)? == BasicStateSort::Accept {
+                let fresh = (completed + 1, self.inner.initial_state());
+                if !states.contains(&fresh) {
+                    states.push(fresh);
+                }
+            }
+            index += 1;
+        }
+        Ok(states)
+    }
+}
+
+impl<A> DeterministicAutomatonBlueprint for RepeatBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    A::State: Eq
+{
+    type State = Vec<(usize, A::State)>;
+
+    type Alphabet = A::Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = A::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        vec![(0, self.inner.initial_state())]
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        if self.count == 0 {
+            return Ok(if state.is_empty() { BasicStateSort::Reject } else { BasicStateSort::Accept });
+        }
+        for (completed, inner_state) in self.close(state.clone())? {
+            if completed + 1 == self.count && self.inner.state_sort_map(&inner_state)? == BasicStateSort::Accept {
+                return Ok(BasicStateSort::Accept);
+            }
+        }
+        Ok(BasicStateSort::Reject)
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        if self.count == 0 {
+            return Ok(Vec::new());
+        }
+        let mut next = Vec::with_capacity(state.len());
+        for (completed, inner_state) in self.close(state.clone())? {
+            let pair = (completed, self.inner.transition_map(&inner_state, character)?);
+            if !next.contains(&pair) {
+                next.push(pair);
+            }
+        }
+        Ok(next)
+    }
+}
+
+/// Returns a blueprint recognizing exactly `count` consecutive words of the automaton's language.
+///
+/// See [`RepeatBlueprint`] for details, including the bounded state requirement.
+pub fn repeat<A>(automaton: &A, count: usize) -> RepeatBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    A::State: Eq
+{
+    RepeatBlueprint::new(automaton, count)
+}
+
+/// A blueprint treating errors of a [`BasicStateSort`] automaton as rejection.
+///
+/// The state is `Some` of the wrapped automaton's state while the run is healthy, and `None`
+/// once a transition error has been converted. `None` is a permanent reject state: it is
+/// never left and never steps the wrapped automaton again. Which errors are converted is
+/// decided by a filter, which may be any closure over the error; errors it declines are
+/// propagated unchanged. The filter type defaults to a function pointer, the type used by
+/// [`new`](Self::new), so `ErrorsAsRejectBlueprint<'a, A>` names that case. This lets a symbol
+/// foreign to the wrapped automaton's alphabet mean "reject" rather than "fail", for
+/// instance when combining it with automata over a larger alphabet.
+///
+/// Only transition errors are converted; errors from classifying a live state still
+/// propagate, since they do not correspond to a symbol that could be rejected.
+///
+/// # Construction
+///
+/// Use [`errors_as_reject`](DeterministicAutomatonBlueprint::errors_as_reject),
+/// [`new`](Self::new) or [`with_filter`](Self::with_filter).
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::operations::ErrorsAsRejectBlueprint;
+/// use deterministic_automata::mod_counter_example::ModSumBlueprint;
+///
+/// // Digit sums divisible by 3; ModSumBlueprint errors on bytes above 9
+/// let mod_three = ModSumBlueprint::new(3);
+///
+/// assert!(mod_three.characterise(&[1, 2, 12]).is_err());
+///
+/// let lenient = mod_three.errors_as_reject();
+/// assert_eq!(lenient.characterise(&[1, 2]).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(lenient.characterise(&[1, 2, 12]).unwrap(), BasicStateSort::Reject);
+///
+/// // Only convert some errors, propagating the rest
+/// let selective = ErrorsAsRejectBlueprint::with_filter(&mod_three, |error: &String| error.contains("12"));
+/// assert_eq!(selective.characterise(&[1, 2, 12]).unwrap(), BasicStateSort::Reject);
+/// assert!(selective.characterise(&[1, 2, 13]).is_err());
+/// ```
+#[derive(Clone)]
+pub struct ErrorsAsRejectBlueprint<'a, A, F = fn(&<A as DeterministicAutomatonBlueprint>::ErrorType) -> bool>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    F: Fn(&A::ErrorType) -> bool
+{
+    inner: &'a A,
+    filter: F
+}
+
+impl<'a, A> ErrorsAsRejectBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    /// Creates a new blueprint converting every transition error into rejection.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the automaton blueprint whose errors are converted
+    pub fn new(inner: &'a A) -> Self {
+        Self { inner, filter: |_| true }
+    }
+}
+
+impl<'a, A, F> ErrorsAsRejectBlueprint<'a, A, F>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    F: Fn(&A::ErrorType) -> bool
+{
+    /// Creates a new blueprint converting only the transition errors accepted by `filter`.
+    ///
+    /// Errors for which `filter` returns `false` are propagated unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the automaton blueprint whose errors are converted
+    /// * `filter` - Decides whether an error becomes rejection
+    pub fn with_filter(inner: &'a A, filter: F) -> Self {
+        Self { inner, filter }
+    }
+}
+
+/// Formats the wrapped blueprint; the filter may be a closure and is omitted.
+impl<A, F> std::fmt::Debug for ErrorsAsRejectBlueprint<'_, A, F>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort> + std::fmt::Debug,
+    F: Fn(&A::ErrorType) -> bool
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ErrorsAsRejectBlueprint")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A, F> DeterministicAutomatonBlueprint for ErrorsAsRejectBlueprint<'_, A, F>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    F: Fn(&A::ErrorType) -> bool
+{
+    type State = Option<A::State>;
+
+    type Alphabet = A::Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = A::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        Some(self.inner.initial_state())
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        match state {
+            Some(state) => self.inner.state_sort_map(state),
+            None => Ok(BasicStateSort::Reject)
+        }
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        let Some(state) = state else {
+            return Ok(None);
+        };
+        match self.inner.transition_map(state, character) {
+            Ok(next) => Ok(Some(next)),
+            Err(error) if (self.filter)(&error) => Ok(None),
+            Err(error) => Err(error)
+        }
+    }
+
+    fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        match state {
+            Some(state) => self.inner.validate_state(state),
+            None => Ok(())
+        }
+    }
+}
+
+/// A blueprint recording the largest value of a state metric reached during a run.
+///
+/// The state pairs the wrapped automaton's state with the peak of `extract` over every state
+/// visited so far, including the initial state. The language and classifications are those
+/// of the wrapped automaton; the peak is read from the final state, for instance through
+/// [`view_state`](crate::DeterministicAutomaton::view_state) after
+/// [`characterise_partial`](DeterministicAutomatonBlueprint::characterise_partial).
+///
+/// # Construction
+///
+/// Use [`track_peak`](DeterministicAutomatonBlueprint::track_peak) or [`new`](Self::new).
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterState};
+///
+/// // The maximum nesting depth of a bracket sequence
+/// let brackets = CounterAutomatonBlueprint::new('(', ')');
+/// let depth = brackets.track_peak(|state| match state {
+///     CounterState::Start(opened) => *opened,
+///     _ => 0
+/// });
+///
+/// let run = depth.characterise_partial(&['(', '(', '(', ')', ')', ')']).unwrap();
+/// assert_eq!(run.current_state_sort().unwrap(), BasicStateSort::Accept);
+/// assert_eq!(run.view_state().1, 3);
+/// ```
+#[derive(Clone)]
+pub struct PeakTrackingBlueprint<'a, A, M, F>
+where
+    A: DeterministicAutomatonBlueprint,
+    M: Ord + Clone,
+    F: Fn(&A::State) -> M
+{
+    inner: &'a A,
+    extract: F,
+    types: PhantomData<fn() -> M>
+}
+
+impl<'a, A, M, F> PeakTrackingBlueprint<'a, A, M, F>
+where
+    A: DeterministicAutomatonBlueprint,
+    M: Ord + Clone,
+    F: Fn(&A::State) -> M
+{
+    /// Creates a new blueprint tracking the peak of `extract` over the wrapped automaton's states.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the automaton blueprint being observed
+    /// * `extract` - Computes the metric of a state
+    pub fn new(inner: &'a A, extract: F) -> Self {
+        Self { inner, extract, types: PhantomData }
+    }
+}
+
+/// Formats the wrapped blueprint; the extractor is a closure and is omitted.
+impl<A, M, F> std::fmt::Debug for PeakTrackingBlueprint<'_, A, M, F>
+where
+    A: DeterministicAutomatonBlueprint + std::fmt::Debug,
+    M: Ord + Clone,
+    F: Fn(&A::State) -> M
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PeakTrackingBlueprint")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A, M, F> DeterministicAutomatonBlueprint for PeakTrackingBlueprint<'_, A, M, F>
+where
+    A: DeterministicAutomatonBlueprint,
+    M: Ord + Clone,
+    F: Fn(&A::State) -> M
+{
+    type State = (A::State, M);
+
+    type Alphabet = A::Alphabet;
+
+    type StateSort = A::StateSort;
+
+    type ErrorType = A::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        let initial = self.inner.initial_state();
+        let metric = (self.extract)(&initial);
+        (initial, metric)
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        self.inner.state_sort_map(&state.0)
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        let next = self.inner.transition_map(&state.0, character)?;
+        let peak = std::cmp::max(state.1.clone(), (self.extract)(&next));
+        Ok((next, peak))
+    }
+
+    fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.inner.validate_state(&state.0)
+    }
+}
+
+/// A finite automaton compiled to a dense transition table.
+///
+/// States and symbols are identified by indices: state `0` is the initial state, and symbol
+/// `i` is the `i`-th symbol of the alphabet passed to [`compile_to_table`]. Recognition only
+/// indexes into vectors, with no trait calls per symbol, which makes it suitable for hot
+/// paths that repeatedly recognize input with a fixed automaton.
+///
+/// `CompiledDfa` also implements [`DeterministicAutomatonBlueprint`] over symbol indices, so
+/// it composes with the rest of the crate; out-of-range symbol indices are reported as errors
+/// there rather than panicking.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompiledDfa {
+    transitions: Vec<Vec<usize>>,
+    accepting: Vec<bool>
+}
+
+impl CompiledDfa {
+    /// Returns the number of reachable states in the table.
+    pub fn state_count(&self) -> usize {
+        self.accepting.len()
+    }
+
+    /// Returns the state reached from `state` on the symbol with index `symbol`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `state` or `symbol` is out of range.
+    pub fn step(&self, state: usize, symbol: usize) -> usize {
+        self.transitions[state][symbol]
+    }
+
+    /// Returns whether `state` is accepting.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `state` is out of range.
+    pub fn is_accepting(&self, state: usize) -> bool {
+        self.accepting[state]
+    }
+
+    /// Returns, for each state, whether some accepting state is reachable from it.
+    fn live_states(&self) -> Vec<bool> {
+        let mut live = self.accepting.clone();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for state in 0..live.len() {
+                if !live[state] && self.transitions[state].iter().any(|next| live[*next]) {
+                    live[state] = true;
+                    changed = true;
+                }
+            }
+        }
+        live
+    }
+
+    /// Recognizes a word given as symbol indices, returning the final classification.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a symbol index is not smaller than the compiled alphabet's length.
+    pub fn classify_ids(&self, symbols: &[usize]) -> BasicStateSort {
+        let state = symbols.iter().fold(0, |state, symbol| self.transitions[state][*symbol]);
+        if self.accepting[state] { BasicStateSort::Accept } else { BasicStateSort::Reject }
+    }
+}
+
+impl DeterministicAutomatonBlueprint for CompiledDfa {
+    type State = usize;
+
+    type Alphabet = usize;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        0
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        match self.accepting.get(*state) {
+            Some(true) => Ok(BasicStateSort::Accept),
+            Some(false) => Ok(BasicStateSort::Reject),
+            None => Err(format!("State {} out of range", state)),
+        }
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        self.transitions.get(*state)
+            .ok_or_else(|| format!("State {} out of range", state))?
+            .get(*character)
+            .copied()
+            .ok_or_else(|| format!("Symbol {} out of range", character))
+    }
+}
+
+/// Compiles a [`BasicStateSort`] automaton to a dense lookup table over a finite alphabet.
+///
+/// Explores every state reachable from the initial state using the symbols of `alphabet`,
+/// assigning ids in breadth-first order, and records each transition and classification.
+/// The first error returned by the automaton during exploration is returned.
+///
+/// This enumerates states, so it only terminates when finitely many states are reachable
+/// over `alphabet`.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, InfallibleAutomatonBlueprint};
+/// use deterministic_automata::operations::compile_to_table;
+///
+/// struct EvenOnes;
+///
+/// impl InfallibleAutomatonBlueprint for EvenOnes {
+///     type State = bool;
+///     type Alphabet = u8;
+///     type StateSort = BasicStateSort;
+///
+///     fn initial(&self) -> Self::State { true }
+///
+///     fn sort(&self, state: &Self::State) -> Self::StateSort {
+///         if *state { BasicStateSort::Accept } else { BasicStateSort::Reject }
+///     }
+///
+///     fn transition(&self, state: &Self::State, bit: &Self::Alphabet) -> Self::State {
+///         *state ^ (*bit == 1)
+///     }
+/// }
+///
+/// let table = compile_to_table(&EvenOnes, &[0, 1]).unwrap();
+///
+/// assert_eq!(table.state_count(), 2);
+/// assert_eq!(table.classify_ids(&[1, 0, 1]), BasicStateSort::Accept);
+/// assert_eq!(table.classify_ids(&[1, 1, 1]), BasicStateSort::Reject);
+/// ```
+pub fn compile_to_table<A>(automaton: &A, alphabet: &[A::Alphabet]) -> Result<CompiledDfa, A::ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    A::State: Eq + Hash
+{
+    explore(automaton, alphabet).map(|(_, table)| table)
+}
+
+/// Enumerates the states reachable over `alphabet`, returning them by id with their table.
+fn explore<A>(automaton: &A, alphabet: &[A::Alphabet]) -> Result<(Vec<A::State>, CompiledDfa), A::ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    A::State: Eq + Hash
+{
+    let exploration = explore_from(automaton, &[automaton.initial_state()], alphabet, |_| false)?;
+    let accepting = exploration.sorts.iter().map(|sort| *sort == BasicStateSort::Accept).collect();
+    Ok((exploration.states, CompiledDfa { transitions: exploration.transitions, accepting }))
+}
+
+/// Returns whether the language of a [`BasicStateSort`] automaton over `alphabet` is prefix-closed.
+///
+/// A language is prefix-closed when every prefix of an accepted word is also accepted.
+/// Equivalently, no reachable rejecting state can reach an accepting state. This enumerates
+/// the states reachable over `alphabet`, so it only terminates when there are finitely many.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, InfallibleAutomatonBlueprint};
+/// use deterministic_automata::operations::is_prefix_closed;
+///
+/// // Accepts words with at most two symbols
+/// struct Short;
+///
+/// impl InfallibleAutomatonBlueprint for Short {
+///     type State = u8;
+///     type Alphabet = char;
+///     type StateSort = BasicStateSort;
+///
+///     fn initial(&self) -> Self::State { 0 }
+///
+///     fn sort(&self, state: &Self::State) -> Self::StateSort {
+///         if *state <= 2 { BasicStateSort::Accept } else { BasicStateSort::Reject }
+///     }
+///
+///     fn transition(&self, state: &Self::State, _: &Self::Alphabet) -> Self::State {
+///         (*state + 1).min(3)
+///     }
+/// }
+///
+/// assert_eq!(is_prefix_closed(&Short, &['a', 'b']), Ok(true));
+/// ```
+pub fn is_prefix_closed<A>(automaton: &A, alphabet: &[A::Alphabet]) -> Result<bool, A::ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    A::State: Eq + Hash
+{
+    let table = compile_to_table(automaton, alphabet)?;
+    Ok(table.live_states().into_iter().zip(table.accepting).all(|(live, accepting)| !live || accepting))
+}
+
+/// A blueprint accepting every prefix of a word accepted by a [`BasicStateSort`] automaton.
+///
+/// A state is accepted when some accepting state of the wrapped automaton is reachable from
+/// it over the alphabet given at construction. These live states are computed once, up front,
+/// by enumerating every state reachable over that alphabet, so construction only terminates
+/// when there are finitely many. Transitions are delegated to the wrapped automaton; states
+/// reached through symbols outside the alphabet are rejected.
+///
+/// This suits incremental validators that should accept partial input as long as it can
+/// still be completed.
+///
+/// # Construction
+///
+/// Use [`prefix_closure`] or [`new`](Self::new); both return the wrapped automaton's error if
+/// one occurs during enumeration.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint, InfallibleAutomatonBlueprint};
+/// use deterministic_automata::operations::prefix_closure;
+///
+/// // Accepts exactly the word "ab"
+/// struct ExactlyAB;
+///
+/// impl InfallibleAutomatonBlueprint for ExactlyAB {
+///     type State = u8;
+///     type Alphabet = char;
+///     type StateSort = BasicStateSort;
+///
+///     fn initial(&self) -> Self::State { 0 }
+///
+///     fn sort(&self, state: &Self::State) -> Self::StateSort {
+///         if *state == 2 { BasicStateSort::Accept } else { BasicStateSort::Reject }
+///     }
+///
+///     fn transition(&self, state: &Self::State, character: &Self::Alphabet) -> Self::State {
+///         match (*state, *character) {
+///             (0, 'a') => 1,
+///             (1, 'b') => 2,
+///             _ => 3,
+///         }
+///     }
+/// }
+///
+/// let Ok(partial) = prefix_closure(&ExactlyAB, &['a', 'b']);
+///
+/// assert_eq!(partial.characterise(&[]).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(partial.characterise(&['a']).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(partial.characterise(&['b']).unwrap(), BasicStateSort::Reject);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrefixClosureBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    A::State: Eq + Hash
+{
+    inner: &'a A,
+    live: HashSet<A::State>
+}
+
+impl<'a, A> PrefixClosureBlueprint<'a, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    A::State: Eq + Hash
+{
+    /// Creates the prefix closure of a blueprint's language over a finite alphabet.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the automaton blueprint whose language is closed
+    /// * `alphabet` - The symbols to explore; input outside it is rejected
+    pub fn new(inner: &'a A, alphabet: &[A::Alphabet]) -> Result<Self, A::ErrorType> {
+        let (states, table) = explore(inner, alphabet)?;
+        let live = states.into_iter()
+            .zip(table.live_states())
+            .filter_map(|(state, live)| live.then_some(state))
+            .collect();
+        Ok(Self { inner, live })
+    }
+}
+
+impl<A> DeterministicAutomatonBlueprint for PrefixClosureBlueprint<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    A::State: Eq + Hash
+{
+    type State = A::State;
+
+    type Alphabet = A::Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = A::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        self.inner.initial_state()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(if self.live.contains(state) { BasicStateSort::Accept } else { BasicStateSort::Reject })
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        self.inner.transition_map(state, character)
+    }
+
+    fn transition_in_place(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        self.inner.transition_in_place(state, character)
+    }
+
+    fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.inner.validate_state(state)
+    }
+}
+
+/// Returns a blueprint accepting every prefix of a word in the automaton's language over `alphabet`.
+///
+/// See [`PrefixClosureBlueprint`] for details.
+pub fn prefix_closure<'a, A>(automaton: &'a A, alphabet: &[A::Alphabet]) -> Result<PrefixClosureBlueprint<'a, A>, A::ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    A::State: Eq + Hash
+{
+    PrefixClosureBlueprint::new(automaton, alphabet)
+}
+
+/// Groups `states` into classes of language-equivalent states over `alphabet`.
+///
+/// Two states are equivalent when every word over `alphabet` leads them to states with equal
+/// classifications, so the automaton cannot tell them apart by any continuation. This is the
+/// Myhill–Nerode equivalence that minimization collapses, exposed as a standalone analysis.
+///
+/// The states reachable from `states` are enumerated and the partition is computed by
+/// refinement: states are first grouped by classification, then groups are split until every
+/// member of a group moves to the same group on each symbol. It therefore only terminates
+/// when finitely many states are reachable.
+///
+/// Each class lists its members in the order they first appear in `states`, duplicates removed,
+/// and the classes are ordered by their first member.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, InfallibleAutomatonBlueprint};
+/// use deterministic_automata::operations::equivalence_classes;
+///
+/// // Counts symbols modulo 4 but only checks evenness, so 0 ≡ 2 and 1 ≡ 3
+/// struct ModFour;
+///
+/// impl InfallibleAutomatonBlueprint for ModFour {
+///     type State = u8;
+///     type Alphabet = char;
+///     type StateSort = BasicStateSort;
+///
+///     fn initial(&self) -> u8 { 0 }
+///
+///     fn sort(&self, state: &u8) -> BasicStateSort {
+///         if state % 2 == 0 { BasicStateSort::Accept } else { BasicStateSort::Reject }
+///     }
+///
+///     fn transition(&self, state: &u8, _: &char) -> u8 { (state + 1) % 4 }
+/// }
+///
+/// let Ok(classes) = equivalence_classes(&ModFour, &[0, 1, 2, 3], &['a']);
+/// assert_eq!(classes, vec![vec![0, 2], vec![1, 3]]);
+/// ```
+pub fn equivalence_classes<A>(automaton: &A, states: &[A::State], alphabet: &[A::Alphabet]) -> Result<Vec<Vec<A::State>>, A::ErrorType>
+where
+    A: DeterministicAutomatonBlueprint,
+    A::State: Eq + Hash,
+    A::StateSort: PartialEq
+{
+    let Exploration { states: explored, sorts, transitions } = explore_from(automaton, states, alphabet, |_| false)?;
+    let requested = states.iter().collect::<HashSet<_>>().len();
+
+    // Initial partition: one class per distinct classification
+    let mut representatives: Vec<&A::StateSort> = Vec::new();
+    let mut class: Vec<usize> = sorts.iter()
+        .map(|sort| match representatives.iter().position(|representative| *representative == sort) {
+            Some(index) => index,
+            None => {
+                representatives.push(sort);
+                representatives.len() - 1
+            }
+        })
+        .collect();
+    let mut class_count = representatives.len();
+
+    loop {
+        let mut signatures = HashMap::new();
+        let refined: Vec<usize> = (0..explored.len())
+            .map(|id| {
+                let signature: Vec<usize> = std::iter::once(class[id])
+                    .chain(transitions[id].iter().map(|next| class[*next]))
+                    .collect();
+                let next_class = signatures.len();
+                *signatures.entry(signature).or_insert(next_class)
+            })
+            .collect();
+        class = refined;
+        if signatures.len() == class_count {
+            break;
+        }
+        class_count = signatures.len();
+    }
+
+    let mut positions: HashMap<usize, usize> = HashMap::new();
+    let mut classes: Vec<Vec<A::State>> = Vec::new();
+    for (id, state) in explored.into_iter().take(requested).enumerate() {
+        match positions.get(&class[id]) {
+            Some(position) => classes[*position].push(state),
+            None => {
+                positions.insert(class[id], classes.len());
+                classes.push(vec![state]);
+            }
+        }
+    }
+
+    Ok(classes)
+}
+
+/// Checks whether two automata are isomorphic over a finite alphabet.
+///
+/// Numbers the states reachable from each initial state in breadth-first order, reading
+/// `alphabet` in order. This numbering depends only on the structure of the automaton, so
+/// the automata are isomorphic exactly when they end up with the same transition table and
+/// the same classification for each number. The second exploration stops as soon as it
+/// outgrows the first or disagrees with it on a classification.
+///
+/// This is stronger than language equivalence: two automata recognizing the same language
+/// are isomorphic only if they also have the same reachable structure, such as two
+/// minimal automata for that language. Unreachable states are ignored. Both automata must
+/// have finitely many states reachable over `alphabet`, or the search does not terminate.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::operations::{are_isomorphic, compile_to_table};
+/// use deterministic_automata::primitives::{EvenLengthBlueprint, LengthModBlueprint};
+///
+/// let even: EvenLengthBlueprint<char> = EvenLengthBlueprint::new();
+/// let mod_two: LengthModBlueprint<char> = LengthModBlueprint::new(2);
+/// let mod_four: LengthModBlueprint<char> = LengthModBlueprint::new(4);
+///
+/// assert!(are_isomorphic(&even, &mod_two, &['a', 'b']).unwrap());
+///
+/// // Length divisible by four is a different language with more states
+/// assert!(!are_isomorphic(&even, &mod_four, &['a', 'b']).unwrap());
+///
+/// // Compiling to a table relabels the states but keeps the structure
+/// let table = compile_to_table(&mod_four, &['a', 'b']).unwrap();
+/// let reference = compile_to_table(&mod_four, &['b', 'a']).unwrap();
+/// assert!(are_isomorphic(&table, &reference, &[0, 1]).unwrap());
+/// ```
+pub fn are_isomorphic<A, B, Alphabet, ErrorType>(first: &A, second: &B, alphabet: &[Alphabet]) -> Result<bool, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = A::StateSort, ErrorType = ErrorType>,
+    A::State: Eq + Hash,
+    B::State: Eq + Hash,
+    A::StateSort: PartialEq
+{
+    // Breadth-first ids depend only on the structure, so isomorphic automata get equal tables
+    let first = explore_from(first, &[first.initial_state()], alphabet, |_| false)?;
+    let second = explore_from(second, &[second.initial_state()], alphabet, |exploration| {
+        let id = exploration.sorts.len() - 1;
+        exploration.states.len() > first.states.len() || first.sorts.get(id) != exploration.sorts.get(id)
+    })?;
+
+    Ok(first.states.len() == second.states.len() && first.sorts == second.sorts && first.transitions == second.transitions)
+}
+
+/// Searches for a word of at most `max_depth` symbols accepted by both automata.
+///
+/// Explores the product of the two automata breadth-first, so the witness returned is a
+/// shortest accepted word, with ties broken by the order of `alphabet`. States are not
+/// compared, so this works for automata with infinite state spaces such as the
+/// [`CounterAutomatonBlueprint`](crate::counter_automaton_example::CounterAutomatonBlueprint),
+/// where emptiness of the intersection cannot be decided in general. In exchange the search
+/// is incomplete: `None` only means no common word exists up to the bound, and the number
+/// of words explored grows exponentially with `max_depth`.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::DeterministicAutomatonBlueprint;
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::operations::intersection_nonempty_bounded;
+///
+/// let balanced = CounterAutomatonBlueprint::new('a', 'b');
+/// let one_extra_a = CounterAutomatonBlueprint::with_tolerance('a', 'b', 1..=1);
+///
+/// // a^n b^n words always have balance zero
+/// assert_eq!(intersection_nonempty_bounded(&balanced, &one_extra_a, &['a', 'b'], 6).unwrap(), None);
+///
+/// let non_empty = balanced.min_length(1);
+/// assert_eq!(intersection_nonempty_bounded(&balanced, &non_empty, &['a', 'b'], 6).unwrap(), Some(vec!['a', 'b']));
+/// ```
+pub fn intersection_nonempty_bounded<A, B, Alphabet, ErrorType>(first: &A, second: &B, alphabet: &[Alphabet], max_depth: usize) -> Result<Option<Vec<Alphabet>>, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort, Alphabet = Alphabet, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<StateSort = BasicStateSort, Alphabet = Alphabet, ErrorType = ErrorType>,
+    Alphabet: Clone
+{
+    let mut frontier = vec![(Vec::new(), first.initial_state(), second.initial_state())];
+    for depth in 0..=max_depth {
+        for (word, first_state, second_state) in &frontier {
+            if first.state_sort_map(first_state)? == BasicStateSort::Accept
+                && second.state_sort_map(second_state)? == BasicStateSort::Accept {
+                return Ok(Some(word.clone()));
+            }
+        }
+        if depth == max_depth {
+            break;
+        }
+        let mut next_frontier = Vec::with_capacity(frontier.len() * alphabet.len());
+        for (word, first_state, second_state) in &frontier {
+            for character in alphabet {
+                let mut next_word = word.clone();
+                next_word.push(character.clone());
+                next_frontier.push((
+                    next_word,
+                    first.transition_map(first_state, character)?,
+                    second.transition_map(second_state, character)?
+                ));
+            }
+        }
+        frontier = next_frontier;
+    }
+    Ok(None)
+}
+
+/// A symbol from one of two alphabets, tagged with the alphabet it belongs to.
+///
+/// Automata over disjoint alphabets can be run together on a single input by lifting each
+/// to the tagged alphabet with [`only_left`] or [`only_right`], so each component reacts
+/// only to its own symbols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaggedAlphabet<A, B> {
+    /// A symbol from the left alphabet.
+    Left(A),
+    /// A symbol from the right alphabet.
+    Right(B)
+}
+
+/// A blueprint lifting a blueprint over alphabet `A` to the tagged alphabet `TaggedAlphabet<A, B>`.
+///
+/// Left-tagged symbols are passed to the wrapped blueprint; right-tagged symbols leave the
+/// state unchanged. States and classifications are those of the wrapped blueprint.
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) or [`only_left`].
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::mod_counter_example::ModSumBlueprint;
+/// use deterministic_automata::operations::{only_left, only_right, TaggedAlphabet};
+/// use deterministic_automata::product_automaton::BasicIntersectionAutomatonBlueprint;
+///
+/// let brackets = CounterAutomatonBlueprint::new('(', ')');
+/// let divisible_by_three = ModSumBlueprint::new(3);
+/// let (left, right) = (only_left(&brackets), only_right(&divisible_by_three));
+/// let both = BasicIntersectionAutomatonBlueprint::new(&left, &right);
+///
+/// use TaggedAlphabet::{Left, Right};
+/// assert_eq!(both.characterise(&[Left('('), Right(1), Right(2), Left(')')]).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(both.characterise(&[Left('('), Right(1), Left(')')]).unwrap(), BasicStateSort::Reject);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnlyLeftBlueprint<'a, A, B>
+where
+    A: DeterministicAutomatonBlueprint
+{
+    inner: &'a A,
+    other: PhantomData<fn(&B)>
+}
+
+impl<'a, A, B> OnlyLeftBlueprint<'a, A, B>
+where
+    A: DeterministicAutomatonBlueprint
+{
+    /// Creates a new blueprint reacting only to left-tagged symbols.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the blueprint run on left-tagged symbols
+    pub fn new(inner: &'a A) -> Self {
+        Self { inner, other: PhantomData }
+    }
+}
+
+impl<A, B> DeterministicAutomatonBlueprint for OnlyLeftBlueprint<'_, A, B>
+where
+    A: DeterministicAutomatonBlueprint,
+    B: PartialEq
+{
+    type State = A::State;
+
+    type Alphabet = TaggedAlphabet<A::Alphabet, B>;
+
+    type StateSort = A::StateSort;
+
+    type ErrorType = A::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        self.inner.initial_state()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        self.inner.state_sort_map(state)
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        match character {
+            TaggedAlphabet::Left(character) => self.inner.transition_map(state, character),
+            TaggedAlphabet::Right(_) => Ok(state.clone()),
+        }
+    }
+
+    fn transition_in_place(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        match character {
+            TaggedAlphabet::Left(character) => self.inner.transition_in_place(state, character),
+            TaggedAlphabet::Right(_) => Ok(()),
+        }
+    }
+
+    fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.inner.validate_state(state)
+    }
+}
+
+/// A blueprint lifting a blueprint over alphabet `B` to the tagged alphabet `TaggedAlphabet<A, B>`.
+///
+/// The mirror image of [`OnlyLeftBlueprint`]: right-tagged symbols are passed to the wrapped
+/// blueprint and left-tagged symbols leave the state unchanged.
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) or [`only_right`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnlyRightBlueprint<'a, A, B>
+where
+    B: DeterministicAutomatonBlueprint
+{
+    inner: &'a B,
+    other: PhantomData<fn(&A)>
+}
+
+impl<'a, A, B> OnlyRightBlueprint<'a, A, B>
+where
+    B: DeterministicAutomatonBlueprint
+{
+    /// Creates a new blueprint reacting only to right-tagged symbols.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - Reference to the blueprint run on right-tagged symbols
+    pub fn new(inner: &'a B) -> Self {
+        Self { inner, other: PhantomData }
+    }
+}
+
+impl<A, B> DeterministicAutomatonBlueprint for OnlyRightBlueprint<'_, A, B>
+where
+    A: PartialEq,
+    B: DeterministicAutomatonBlueprint
+{
+    type State = B::State;
+
+    type Alphabet = TaggedAlphabet<A, B::Alphabet>;
+
+    type StateSort = B::StateSort;
+
+    type ErrorType = B::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        self.inner.initial_state()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        self.inner.state_sort_map(state)
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        match character {
+            TaggedAlphabet::Left(_) => Ok(state.clone()),
+            TaggedAlphabet::Right(character) => self.inner.transition_map(state, character),
+        }
+    }
+
+    fn transition_in_place(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        match character {
+            TaggedAlphabet::Left(_) => Ok(()),
+            TaggedAlphabet::Right(character) => self.inner.transition_in_place(state, character),
+        }
+    }
+
+    fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+        self.inner.validate_state(state)
+    }
+}
+
+/// Lifts a blueprint to the tagged alphabet, reacting only to left-tagged symbols.
+///
+/// See [`OnlyLeftBlueprint`] for details.
+pub fn only_left<A, B>(automaton: &A) -> OnlyLeftBlueprint<'_, A, B>
+where
+    A: DeterministicAutomatonBlueprint
+{
+    OnlyLeftBlueprint::new(automaton)
+}
+
+/// Lifts a blueprint to the tagged alphabet, reacting only to right-tagged symbols.
+///
+/// See [`OnlyRightBlueprint`] for details.
+pub fn only_right<A, B>(automaton: &B) -> OnlyRightBlueprint<'_, A, B>
+where
+    B: DeterministicAutomatonBlueprint
+{
+    OnlyRightBlueprint::new(automaton)
+}
+
+/// Returns whether an accepting state is reachable from `state` over `alphabet`.
+///
+/// A state is live when some continuation, possibly empty, leads to acceptance: the input
+/// read so far is a viable prefix that can still be completed. This is what an
+/// as-you-type validator needs to decide between "not valid yet" and "can never be valid".
+/// The search is breadth-first and stops as soon as an accepting state is found, so it
+/// terminates for live states even in infinite state spaces; proving a state dead requires
+/// exhausting its reachable states, which must therefore be finite.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterState};
+/// use deterministic_automata::operations::is_live;
+///
+/// let balanced = CounterAutomatonBlueprint::new('a', 'b');
+///
+/// // Three 'b's would balance "aaa"
+/// assert!(is_live(&balanced, &CounterState::Start(3), &['a', 'b']).unwrap());
+/// assert!(!is_live(&balanced, &CounterState::Reject, &['a', 'b']).unwrap());
+/// ```
+pub fn is_live<A>(automaton: &A, state: &A::State, alphabet: &[A::Alphabet]) -> Result<bool, A::ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    A::State: Eq + Hash
+{
+    let exploration = explore_from(automaton, std::slice::from_ref(state), alphabet, |exploration| {
+        exploration.sorts.last() == Some(&BasicStateSort::Accept)
+    })?;
+    Ok(exploration.sorts.last() == Some(&BasicStateSort::Accept))
+}
+
+/// Returns the symbols of `alphabet` after which `state` can still reach acceptance.
+///
+/// Each symbol is tried from `state` and kept when the resulting state is live in the sense
+/// of [`is_live`], so the result lists the next inputs that do not doom the word. This is
+/// the core of input guidance such as autocompletion or disabling buttons that would make
+/// a form invalid. Symbols are returned in alphabet order. Like [`is_live`], the check
+/// terminates for live successors in any state space but needs a finite reachable set to
+/// rule a successor out.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterState};
+/// use deterministic_automata::operations::live_next_symbols;
+///
+/// let balanced = CounterAutomatonBlueprint::new('a', 'b');
+///
+/// // Before any input an opening 'a' keeps the word viable, a 'b' never can
+/// let next = live_next_symbols(&balanced, &CounterState::Start(0), &['a', 'b']).unwrap();
+/// assert_eq!(next, vec!['a']);
+/// ```
+pub fn live_next_symbols<A>(automaton: &A, state: &A::State, alphabet: &[A::Alphabet]) -> Result<Vec<A::Alphabet>, A::ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    A::State: Eq + Hash,
+    A::Alphabet: Clone
+{
+    let mut live = Vec::new();
+    for character in alphabet {
+        let next = automaton.transition_map(state, character)?;
+        if is_live(automaton, &next, alphabet)? {
+            live.push(character.clone());
+        }
+    }
+    Ok(live)
+}
+
+/// Returns the trap states reachable from `states` over `alphabet`.
+///
+/// A trap state rejects, and so does every state reachable from it: once entered, no input
+/// can lead back to acceptance. The result is the largest set of reachable rejecting states
+/// whose transitions all stay inside the set, listed in the order they were discovered from
+/// `states`. This explains inputs after which an automaton can never recover. It enumerates
+/// the states reachable over `alphabet`, so it only terminates when there are finitely many.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, InfallibleAutomatonBlueprint};
+/// use deterministic_automata::operations::trap_states;
+///
+/// // Accepts words of 'a's only; any 'b' leads to a dead state
+/// struct OnlyAs;
+///
+/// impl InfallibleAutomatonBlueprint for OnlyAs {
+///     type State = bool;
+///     type Alphabet = char;
+///     type StateSort = BasicStateSort;
+///
+///     fn initial(&self) -> bool { true }
+///
+///     fn sort(&self, alive: &bool) -> BasicStateSort {
+///         if *alive { BasicStateSort::Accept } else { BasicStateSort::Reject }
+///     }
+///
+///     fn transition(&self, alive: &bool, c: &char) -> bool { *alive && *c == 'a' }
+/// }
+///
+/// let Ok(traps) = trap_states(&OnlyAs, &[true], &['a', 'b']);
+/// assert_eq!(traps, vec![false]);
+/// ```
+pub fn trap_states<A>(automaton: &A, states: &[A::State], alphabet: &[A::Alphabet]) -> Result<Vec<A::State>, A::ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    A::State: Eq + Hash
+{
+    let exploration = explore_from(automaton, states, alphabet, |_| false)?;
+
+    // A rejecting state is a trap exactly when no accepting state is reachable from it
+    let accepting = exploration.sorts.iter().map(|sort| *sort == BasicStateSort::Accept).collect();
+    let table = CompiledDfa { transitions: exploration.transitions, accepting };
+    let live = table.live_states();
+    Ok(exploration.states.into_iter().zip(live).filter_map(|(state, live)| (!live).then_some(state)).collect())
+}
+
+/// Counts the states reachable from the initial state over `alphabet`.
+///
+/// This is a cheap estimate of the size of an automaton before running operations that
+/// enumerate its states, such as [`compile_to_table`] or [`equivalence_classes`]. Infinite
+/// state spaces, like that of
+/// [`CounterAutomatonBlueprint`](crate::counter_automaton_example::CounterAutomatonBlueprint),
+/// would never finish, so the exploration can be capped: with `Some(cap)`, it stops and
+/// returns `Ok(None)` as soon as more than `cap` states have been found. With `None` it runs
+/// to completion, which only terminates when the reachable state space is finite. States are
+/// classified as they are found, so classification errors are reported as well.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::mod_counter_example::ModSumBlueprint;
+/// use deterministic_automata::operations::state_count;
+///
+/// assert_eq!(state_count(&ModSumBlueprint::new(5), &[0, 1], None).unwrap(), Some(5));
+///
+/// // The counter has infinitely many states; the cap stops the search
+/// let balanced = CounterAutomatonBlueprint::new('a', 'b');
+/// assert_eq!(state_count(&balanced, &['a', 'b'], Some(1000)).unwrap(), None);
+/// ```
+pub fn state_count<A>(automaton: &A, alphabet: &[A::Alphabet], cap: Option<usize>) -> Result<Option<usize>, A::ErrorType>
+where
+    A: DeterministicAutomatonBlueprint,
+    A::State: Eq + Hash
+{
+    let exceeds_cap = |count: usize| cap.is_some_and(|cap| count > cap);
+    let exploration = explore_from(automaton, &[automaton.initial_state()], alphabet, |exploration| {
+        exceeds_cap(exploration.states.len())
+    })?;
+    let count = exploration.states.len();
+    Ok((!exceeds_cap(count)).then_some(count))
+}
+
+/// Returns every `(state, symbol)` pair over the given domains whose transition leads to `target`.
+///
+/// The transition relation is brute-forced over `states × alphabet`, so the result is only
+/// as complete as the supplied domains; pairs are listed in that order. This answers "how
+/// could this state have been reached?", which is the building block of backward
+/// reachability. Any transition error is returned immediately.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::mod_counter_example::ModSumBlueprint;
+/// use deterministic_automata::operations::predecessors;
+///
+/// let divisible = ModSumBlueprint::new(3);
+///
+/// let into_zero = predecessors(&divisible, &[0, 1, 2], &[1, 2, 3], &0).unwrap();
+/// assert_eq!(into_zero, vec![(0, 3), (1, 2), (2, 1)]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn predecessors<A>(automaton: &A, states: &[A::State], alphabet: &[A::Alphabet], target: &A::State) -> Result<Vec<(A::State, A::Alphabet)>, A::ErrorType>
+where
+    A: DeterministicAutomatonBlueprint,
+    A::State: Eq,
+    A::Alphabet: Clone
+{
+    let mut found = Vec::new();
+    for state in states {
+        for character in alphabet {
+            if automaton.transition_map(state, character)? == *target {
+                found.push((state.clone(), character.clone()));
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// The states reachable from a set of starting states, numbered in the order they were found.
+struct Exploration<State, StateSort> {
+    /// The reachable states by id.
+    states: Vec<State>,
+
+    /// The classification of each state, by id.
+    sorts: Vec<StateSort>,
+
+    /// The id of each state's successor on each alphabet symbol, by id.
+    transitions: Vec<Vec<usize>>
+}
+
+/// Enumerates the states reachable from `states` over `alphabet` breadth-first, with their
+/// classifications and transition table by id. The distinct states of `states` come first,
+/// in order.
+///
+/// `stop` is consulted each time a state has been classified, before its successors are
+/// explored; once it returns `true` the partial exploration is returned as is, so callers
+/// can cap the search or end it as soon as they have their answer.
+fn explore_from<A, F>(automaton: &A, states: &[A::State], alphabet: &[A::Alphabet], mut stop: F) -> Result<Exploration<A::State, A::StateSort>, A::ErrorType>
+where
+    A: DeterministicAutomatonBlueprint,
+    A::State: Eq + Hash,
+    F: FnMut(&Exploration<A::State, A::StateSort>) -> bool
+{
+    let mut ids = HashMap::new();
+    let mut exploration = Exploration { states: Vec::new(), sorts: Vec::new(), transitions: Vec::new() };
+    for state in states {
+        if !ids.contains_key(state) {
+            ids.insert(state.clone(), exploration.states.len());
+            exploration.states.push(state.clone());
+        }
+    }
+
+    let mut current = 0;
+    while current < exploration.states.len() {
+        let state = exploration.states[current].clone();
+        exploration.sorts.push(automaton.state_sort_map(&state)?);
+        if stop(&exploration) {
+            break;
+        }
+        let mut row = Vec::with_capacity(alphabet.len());
+        for character in alphabet {
+            let next = automaton.transition_map(&state, character)?;
+            let id = match ids.get(&next) {
+                Some(id) => *id,
+                None => {
+                    let id = exploration.states.len();
+                    ids.insert(next.clone(), id);
+                    exploration.states.push(next);
+                    id
+                }
+            };
+            row.push(id);
+        }
+        exploration.transitions.push(row);
+        current += 1;
+    }
+
+    Ok(exploration)
+}
@@ -0,0 +1,554 @@
+//! Ready-made blueprints for common structural constraints on words.
+//!
+//! Some regular constraints come up again and again and are tedious to re-implement for each
+//! project. This module bundles them as small generic blueprints that are meant to be
+//! combined with other recognizers, typically through intersection in
+//! [`product_automaton`](crate::product_automaton).
+//!
+//! # Blueprints Provided
+//!
+//! ## [`NoRepeatsBlueprint`]
+//!
+//! Accepts exactly the words in which no two adjacent symbols are equal.
+//!
+//! ## [`EvenLengthBlueprint`]
+//!
+//! Accepts exactly the words of even length, tracking only the parity of the number of
+//! symbols read.
+//!
+//! ## [`LengthModBlueprint`]
+//!
+//! Accepts the words whose length is a multiple of a fixed modulus, the general form of
+//! [`EvenLengthBlueprint`].
+//!
+//! ## [`ContainsSubsequenceBlueprint`]
+//!
+//! Accepts the words containing a fixed pattern as a not necessarily contiguous
+//! subsequence, such as a sequence of events that must occur in order.
+//!
+//! ## [`AtMostKBlueprint`], [`ExactlyKBlueprint`] and [`AtLeastKBlueprint`]
+//!
+//! Bound the number of occurrences of a single symbol from above, exactly, or from below.
+//! The count saturates just past the threshold, so each has `k + 2` states at most and can
+//! be used with the operations that enumerate states.
+//!
+//! # Example
+//!
+//! ```
+//! use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+//! use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+//! use deterministic_automata::primitives::NoRepeatsBlueprint;
+//! use deterministic_automata::product_automaton::BasicIntersectionAutomatonBlueprint;
+//!
+//! // Balanced a^n b^n words without adjacent repeats: only "" and "ab"
+//! let no_repeats = NoRepeatsBlueprint::<char, String>::new();
+//! let counter = CounterAutomatonBlueprint::new('a', 'b');
+//! let both = BasicIntersectionAutomatonBlueprint::new(&no_repeats, &counter);
+//!
+//! assert_eq!(both.characterise(&['a', 'b']).unwrap(), BasicStateSort::Accept);
+//! assert_eq!(both.characterise(&['a', 'a', 'b', 'b']).unwrap(), BasicStateSort::Reject);
+//! ```
+
+use std::convert::Infallible;
+use std::marker::PhantomData;
+
+use crate::{BasicStateSort, DeterministicAutomatonBlueprint};
+
+/// A blueprint accepting words in which no two adjacent symbols are equal.
+///
+/// The state remembers the last symbol read. Once a symbol equal to the previous one is read,
+/// the automaton enters [`NoRepeatsState::Repeated`] and rejects from then on.
+///
+/// # Type Parameters
+///
+/// * `Alphabet` - Input symbol type, cloned into the state
+/// * `ErrorType` - Error type of the blueprint, [`Infallible`] by default
+///
+/// Transitions never fail. The error type is nonetheless a parameter so that the blueprint
+/// can be intersected with recognizers of any error type.
+///
+/// # Construction
+///
+/// Use [`new`](Self::new).
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoRepeatsBlueprint<Alphabet, ErrorType = Infallible> {
+    types: PhantomData<fn(&Alphabet) -> ErrorType>
+}
+
+impl<Alphabet, ErrorType> NoRepeatsBlueprint<Alphabet, ErrorType> {
+    /// Creates a new no-repeats blueprint.
+    pub fn new() -> Self {
+        Self { types: PhantomData }
+    }
+}
+
+impl<Alphabet, ErrorType> Default for NoRepeatsBlueprint<Alphabet, ErrorType> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// State of a [`NoRepeatsBlueprint`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NoRepeatsState<Alphabet> {
+    /// No repeat has been seen; holds the last symbol read, or `None` before any input.
+    Last(Option<Alphabet>),
+    /// Two adjacent symbols were equal.
+    Repeated
+}
+
+impl<Alphabet, ErrorType> DeterministicAutomatonBlueprint for NoRepeatsBlueprint<Alphabet, ErrorType>
+where
+    Alphabet: Clone + PartialEq
+{
+    type State = NoRepeatsState<Alphabet>;
+
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        NoRepeatsState::Last(None)
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(match state {
+            NoRepeatsState::Last(_) => BasicStateSort::Accept,
+            NoRepeatsState::Repeated => BasicStateSort::Reject,
+        })
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok(match state {
+            NoRepeatsState::Last(Some(last)) if last == character => NoRepeatsState::Repeated,
+            NoRepeatsState::Last(_) => NoRepeatsState::Last(Some(character.clone())),
+            NoRepeatsState::Repeated => NoRepeatsState::Repeated,
+        })
+    }
+}
+
+/// A blueprint accepting exactly the words of even length.
+///
+/// The state is `true` after an odd number of symbols and `false` after an even number, so
+/// the blueprint has two states and can be intersected with infinite-state recognizers such
+/// as [`CounterAutomatonBlueprint`](crate::counter_automaton_example::CounterAutomatonBlueprint)
+/// without affecting their state space beyond a flag.
+///
+/// # Type Parameters
+///
+/// * `Alphabet` - Input symbol type
+/// * `ErrorType` - Error type of the blueprint, [`Infallible`] by default
+///
+/// # Construction
+///
+/// Use [`new`](Self::new).
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::primitives::EvenLengthBlueprint;
+/// use deterministic_automata::product_automaton::BasicIntersectionAutomatonBlueprint;
+///
+/// // At most one unmatched 'a', and an even number of symbols overall
+/// let nearly_balanced = CounterAutomatonBlueprint::with_tolerance('a', 'b', 0..=1);
+/// let even = EvenLengthBlueprint::<char, String>::new();
+/// let both = BasicIntersectionAutomatonBlueprint::new(&nearly_balanced, &even);
+///
+/// assert_eq!(nearly_balanced.characterise(&['a', 'a', 'b']).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(both.characterise(&['a', 'a', 'b']).unwrap(), BasicStateSort::Reject);
+/// assert_eq!(both.characterise(&['b', 'a']).unwrap(), BasicStateSort::Accept);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvenLengthBlueprint<Alphabet, ErrorType = Infallible> {
+    types: PhantomData<fn(&Alphabet) -> ErrorType>
+}
+
+impl<Alphabet, ErrorType> EvenLengthBlueprint<Alphabet, ErrorType> {
+    /// Creates a new even-length blueprint.
+    pub fn new() -> Self {
+        Self { types: PhantomData }
+    }
+}
+
+impl<Alphabet, ErrorType> Default for EvenLengthBlueprint<Alphabet, ErrorType> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Alphabet, ErrorType> DeterministicAutomatonBlueprint for EvenLengthBlueprint<Alphabet, ErrorType>
+where
+    Alphabet: PartialEq
+{
+    type State = bool;
+
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        false
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(BasicStateSort::from(!*state))
+    }
+
+    fn transition_map(&self, state: &Self::State, _character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok(!*state)
+    }
+}
+
+/// A blueprint accepting exactly the words whose length is a multiple of a modulus.
+///
+/// The state is the number of symbols read modulo `modulus`, so the blueprint has `modulus`
+/// states. This generalizes [`EvenLengthBlueprint`] to rules such as "one checksum symbol
+/// after every three data symbols", and is meant to be intersected with other recognizers.
+///
+/// # Type Parameters
+///
+/// * `Alphabet` - Input symbol type
+/// * `ErrorType` - Error type of the blueprint, [`Infallible`] by default
+///
+/// # Construction
+///
+/// Use [`new`](Self::new).
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::primitives::{LengthModBlueprint, NoRepeatsBlueprint};
+/// use deterministic_automata::product_automaton::BasicIntersectionAutomatonBlueprint;
+///
+/// // Words without adjacent repeats whose length is a multiple of three
+/// let no_repeats = NoRepeatsBlueprint::<char>::new();
+/// let triples: LengthModBlueprint<char> = LengthModBlueprint::new(3);
+/// let both = BasicIntersectionAutomatonBlueprint::new(&no_repeats, &triples);
+///
+/// let Ok(sort) = both.characterise(&['a', 'b', 'a']);
+/// assert_eq!(sort, BasicStateSort::Accept);
+/// let Ok(sort) = both.characterise(&['a', 'b']);
+/// assert_eq!(sort, BasicStateSort::Reject);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LengthModBlueprint<Alphabet, ErrorType = Infallible> {
+    modulus: usize,
+    types: PhantomData<fn(&Alphabet) -> ErrorType>
+}
+
+impl<Alphabet, ErrorType> LengthModBlueprint<Alphabet, ErrorType> {
+    /// Creates a new blueprint accepting words whose length is a multiple of `modulus`.
+    ///
+    /// # Parameters
+    ///
+    /// * `modulus` - The number the length must be divisible by
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn new(modulus: usize) -> Self {
+        assert!(modulus > 0, "LengthModBlueprint modulus must be positive");
+        Self { modulus, types: PhantomData }
+    }
+}
+
+impl<Alphabet, ErrorType> DeterministicAutomatonBlueprint for LengthModBlueprint<Alphabet, ErrorType>
+where
+    Alphabet: PartialEq
+{
+    type State = usize;
+
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        0
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(BasicStateSort::from(*state == 0))
+    }
+
+    fn transition_map(&self, state: &Self::State, _character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok((state + 1) % self.modulus)
+    }
+}
+
+/// A blueprint accepting words in which a symbol occurs at most `k` times.
+///
+/// The state is the number of occurrences seen, saturating at `k + 1`: once the symbol has
+/// occurred more than `k` times the word is rejected, whatever follows.
+///
+/// # Type Parameters
+///
+/// * `Alphabet` - Input symbol type
+/// * `ErrorType` - Error type of the blueprint, [`Infallible`] by default
+///
+/// # Construction
+///
+/// Use [`new`](Self::new).
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::primitives::AtMostKBlueprint;
+///
+/// let at_most_two_xs: AtMostKBlueprint<char> = AtMostKBlueprint::new('x', 2);
+///
+/// let Ok(sort) = at_most_two_xs.characterise(&['x', 'y', 'x']);
+/// assert_eq!(sort, BasicStateSort::Accept);
+/// let Ok(sort) = at_most_two_xs.characterise(&['x', 'x', 'x']);
+/// assert_eq!(sort, BasicStateSort::Reject);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtMostKBlueprint<Alphabet, ErrorType = Infallible> {
+    symbol: Alphabet,
+    k: usize,
+    types: PhantomData<fn() -> ErrorType>
+}
+
+impl<Alphabet, ErrorType> AtMostKBlueprint<Alphabet, ErrorType> {
+    /// Creates a new blueprint accepting words with at most `k` occurrences of `symbol`.
+    ///
+    /// # Parameters
+    ///
+    /// * `symbol` - The symbol whose occurrences are counted
+    /// * `k` - The largest accepted number of occurrences
+    pub fn new(symbol: Alphabet, k: usize) -> Self {
+        Self { symbol, k, types: PhantomData }
+    }
+}
+
+impl<Alphabet, ErrorType> DeterministicAutomatonBlueprint for AtMostKBlueprint<Alphabet, ErrorType>
+where
+    Alphabet: PartialEq
+{
+    type State = usize;
+
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        0
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(BasicStateSort::from(*state <= self.k))
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok(count_occurrence(*state, &self.symbol, character, self.k.saturating_add(1)))
+    }
+}
+
+/// A blueprint accepting words in which a symbol occurs exactly `k` times.
+///
+/// The state is the number of occurrences seen, saturating at `k + 1`.
+///
+/// # Type Parameters
+///
+/// * `Alphabet` - Input symbol type
+/// * `ErrorType` - Error type of the blueprint, [`Infallible`] by default
+///
+/// # Construction
+///
+/// Use [`new`](Self::new).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExactlyKBlueprint<Alphabet, ErrorType = Infallible> {
+    symbol: Alphabet,
+    k: usize,
+    types: PhantomData<fn() -> ErrorType>
+}
+
+impl<Alphabet, ErrorType> ExactlyKBlueprint<Alphabet, ErrorType> {
+    /// Creates a new blueprint accepting words with exactly `k` occurrences of `symbol`.
+    ///
+    /// # Parameters
+    ///
+    /// * `symbol` - The symbol whose occurrences are counted
+    /// * `k` - The accepted number of occurrences
+    pub fn new(symbol: Alphabet, k: usize) -> Self {
+        Self { symbol, k, types: PhantomData }
+    }
+}
+
+impl<Alphabet, ErrorType> DeterministicAutomatonBlueprint for ExactlyKBlueprint<Alphabet, ErrorType>
+where
+    Alphabet: PartialEq
+{
+    type State = usize;
+
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        0
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(BasicStateSort::from(*state == self.k))
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok(count_occurrence(*state, &self.symbol, character, self.k.saturating_add(1)))
+    }
+}
+
+/// A blueprint accepting words in which a symbol occurs at least `k` times.
+///
+/// The state is the number of occurrences seen, saturating at `k`: once the symbol has
+/// occurred `k` times the word is accepted, whatever follows.
+///
+/// # Type Parameters
+///
+/// * `Alphabet` - Input symbol type
+/// * `ErrorType` - Error type of the blueprint, [`Infallible`] by default
+///
+/// # Construction
+///
+/// Use [`new`](Self::new).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtLeastKBlueprint<Alphabet, ErrorType = Infallible> {
+    symbol: Alphabet,
+    k: usize,
+    types: PhantomData<fn() -> ErrorType>
+}
+
+impl<Alphabet, ErrorType> AtLeastKBlueprint<Alphabet, ErrorType> {
+    /// Creates a new blueprint accepting words with at least `k` occurrences of `symbol`.
+    ///
+    /// # Parameters
+    ///
+    /// * `symbol` - The symbol whose occurrences are counted
+    /// * `k` - The smallest accepted number of occurrences
+    pub fn new(symbol: Alphabet, k: usize) -> Self {
+        Self { symbol, k, types: PhantomData }
+    }
+}
+
+impl<Alphabet, ErrorType> DeterministicAutomatonBlueprint for AtLeastKBlueprint<Alphabet, ErrorType>
+where
+    Alphabet: PartialEq
+{
+    type State = usize;
+
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        0
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(BasicStateSort::from(*state >= self.k))
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok(count_occurrence(*state, &self.symbol, character, self.k))
+    }
+}
+
+/// A blueprint accepting words that contain a fixed pattern as a subsequence.
+///
+/// The pattern symbols must appear in order but need not be adjacent, so the pattern `ace`
+/// is contained in `abcde` but not in `aec`. The state is the length of the longest pattern
+/// prefix matched so far; matching greedily is optimal for subsequences, so the blueprint
+/// has `pattern.len() + 1` states and accepts once the whole pattern has been matched. The
+/// empty pattern is contained in every word.
+///
+/// # Type Parameters
+///
+/// * `Alphabet` - Input symbol type
+/// * `ErrorType` - Error type of the blueprint, [`Infallible`] by default
+///
+/// # Construction
+///
+/// Use [`new`](Self::new).
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::primitives::ContainsSubsequenceBlueprint;
+///
+/// // Did the log record a login, then a write, then a logout, in that order?
+/// let audit: ContainsSubsequenceBlueprint<&str> = ContainsSubsequenceBlueprint::new(vec!["login", "write", "logout"]);
+///
+/// let Ok(sort) = audit.characterise(&["login", "read", "write", "read", "logout"]);
+/// assert_eq!(sort, BasicStateSort::Accept);
+/// let Ok(sort) = audit.characterise(&["login", "logout", "write"]);
+/// assert_eq!(sort, BasicStateSort::Reject);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainsSubsequenceBlueprint<Alphabet, ErrorType = Infallible> {
+    pattern: Vec<Alphabet>,
+    types: PhantomData<fn() -> ErrorType>
+}
+
+impl<Alphabet, ErrorType> ContainsSubsequenceBlueprint<Alphabet, ErrorType> {
+    /// Creates a new blueprint accepting words that contain `pattern` as a subsequence.
+    ///
+    /// # Parameters
+    ///
+    /// * `pattern` - The symbols that must appear in order
+    pub fn new(pattern: Vec<Alphabet>) -> Self {
+        Self { pattern, types: PhantomData }
+    }
+}
+
+impl<Alphabet, ErrorType> DeterministicAutomatonBlueprint for ContainsSubsequenceBlueprint<Alphabet, ErrorType>
+where
+    Alphabet: PartialEq + Clone
+{
+    type State = usize;
+
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        0
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(BasicStateSort::from(*state >= self.pattern.len()))
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok(match self.pattern.get(*state) {
+            Some(next) if next == character => state + 1,
+            _ => *state,
+        })
+    }
+}
+
+/// Advances an occurrence count, saturating at `cap`.
+fn count_occurrence<Alphabet: PartialEq>(count: usize, symbol: &Alphabet, character: &Alphabet, cap: usize) -> usize {
+    if character == symbol {
+        count.saturating_add(1).min(cap)
+    } else {
+        count
+    }
+}
@@ -19,6 +19,10 @@
 //! This is useful when you need access to the individual classifications from both
 //! component automata.
 //!
+//! [`ProductAutomatonBlueprint::reduce_sort`] collapses the tuple classification to a
+//! [`BasicStateSort`] with a custom two-component reducer, producing a
+//! [`ReducedProductAutomatonBlueprint`].
+//!
 //! ## [`BasicUnionAutomatonBlueprint`]
 //!
 //! A specialized product construction for automata using [`BasicStateSort`]. Implements
@@ -31,6 +35,84 @@
 //! the logical AND operation: accepts only if **both** component automata accept.
 //! This recognizes the intersection of the languages accepted by the component automata.
 //!
+//! ## Owned Variants
+//!
+//! [`OwnedProductAutomatonBlueprint`], [`OwnedBasicUnionAutomatonBlueprint`] and
+//! [`OwnedBasicIntersectionAutomatonBlueprint`] take their components by value rather than
+//! by reference. Without lifetime parameters they can be stored in structs and returned from
+//! factory functions along with their components. Transitions and classifications are those
+//! of the borrowed constructions, which they delegate to.
+//!
+//! ## [`LatticeJoinProductBlueprint`] and [`LatticeMeetProductBlueprint`]
+//!
+//! Generalize union and intersection to classifications forming a bounded [`Lattice`],
+//! such as confidence levels, by combining the component classifications with
+//! [`join`](Lattice::join) or [`meet`](Lattice::meet).
+//!
+//! ## [`FallbackBlueprint`]
+//!
+//! Uses the classification of a primary automaton until it errors, then switches to a
+//! fallback automaton run alongside it, for degrading gracefully from a strict recognizer to
+//! a lenient one.
+//!
+//! ## [`ShuffleBlueprint`]
+//!
+//! Recognizes the interleavings of a word from each of two languages by tracking every way
+//! the symbols read so far could have been distributed between the components.
+//!
+//! ## Mutation Paradigm Counterparts
+//!
+//! [`MutationProductAutomatonBlueprint`], [`MutationBasicUnionAutomatonBlueprint`] and
+//! [`MutationBasicIntersectionAutomatonBlueprint`] provide the same constructions for
+//! [`MutationAutomatonBlueprint`] components, mutating both component states in place.
+//! Since every deterministic blueprint is also a mutation blueprint, these accept
+//! deterministic components as well.
+//!
+//! ```
+//! use deterministic_automata::{BasicStateSort, MutationAutomatonBlueprint};
+//! use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterState};
+//! use deterministic_automata::product_automaton::{MutationProductAutomatonBlueprint, MutationBasicUnionAutomatonBlueprint};
+//!
+//! let ab = CounterAutomatonBlueprint::new('a', 'b');
+//! let ac = CounterAutomatonBlueprint::new('a', 'c');
+//!
+//! let union = MutationBasicUnionAutomatonBlueprint::new(&ab, &ac);
+//! assert_eq!(union.mutation_characterise(&['a', 'c']).unwrap(), BasicStateSort::Accept);
+//!
+//! let product = MutationProductAutomatonBlueprint::new(&ab, &ac);
+//! let mut automaton = product.mutation_automaton();
+//! for character in ['a', 'a', 'b'] {
+//!     automaton.update_state(&character).unwrap();
+//! }
+//!
+//! // Read both component counters after the run
+//! let (first, second) = MutationProductAutomatonBlueprint::component_states(&automaton);
+//! assert!(matches!(first, CounterState::End(1)));
+//! assert!(matches!(second, CounterState::Reject));
+//! ```
+//!
+//! ## [`LangExpr`]
+//!
+//! A fluent builder for boolean language expressions over [`BasicStateSort`] automata,
+//! avoiding deeply nested constructor calls:
+//!
+//! ```
+//! use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+//! use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+//! use deterministic_automata::product_automaton::LangExpr;
+//!
+//! let ab = CounterAutomatonBlueprint::new('a', 'b');
+//! let ac = CounterAutomatonBlueprint::new('a', 'c');
+//! let bc = CounterAutomatonBlueprint::new('b', 'c');
+//!
+//! // ¬((ab ∧ ac) ∨ bc)
+//! let expression = LangExpr::atom(&ab).and(&ac).or(&bc).not().build();
+//!
+//! assert_eq!(expression.characterise(&[]).unwrap(), BasicStateSort::Reject);
+//! assert_eq!(expression.characterise(&['a', 'b']).unwrap(), BasicStateSort::Accept);
+//! assert_eq!(expression.characterise(&['b', 'c']).unwrap(), BasicStateSort::Reject);
+//! ```
+//!
 //! # Boolean Operations on Languages
 //!
 //! The union and intersection blueprints provide a way to perform boolean operations
@@ -42,8 +124,10 @@
 //! These operations are closed for the class of languages recognizable by deterministic
 //! automata in this framework, meaning the result is always another recognizable language.
 
-use crate::{BasicStateSort, DeterministicAutomatonBlueprint};
-use crate::MutationAutomatonBlueprint;
+use crate::{BasicStateSort, DeterministicAutomaton, DeterministicAutomatonBlueprint};
+use crate::{DescribableAutomaton, MutationAutomaton, MutationAutomatonBlueprint};
+use crate::operations::ComplementAutomatonBlueprint;
+use std::collections::BTreeSet;
 
 /// A blueprint for the general product construction of two deterministic automata.
 ///
@@ -112,6 +196,38 @@ where
             second
         }
     }
+
+    /// Collapses the tuple of component classifications to a [`BasicStateSort`] with a custom reducer.
+    ///
+    /// The product still runs both components in parallel; only the final classification is
+    /// replaced by `reducer(first_sort, second_sort)`. This allows arbitrary two-component
+    /// combination logic, such as exclusive or, without writing a bespoke blueprint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+    /// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+    /// use deterministic_automata::product_automaton::ProductAutomatonBlueprint;
+    ///
+    /// let ab = CounterAutomatonBlueprint::new('a', 'b');
+    /// let ac = CounterAutomatonBlueprint::new('a', 'c');
+    /// let xor = ProductAutomatonBlueprint::new(&ab, &ac).reduce_sort(|x, y| {
+    ///     if x != y { BasicStateSort::Accept } else { BasicStateSort::Reject }
+    /// });
+    ///
+    /// assert_eq!(xor.characterise(&['a', 'b']).unwrap(), BasicStateSort::Accept);
+    /// assert_eq!(xor.characterise(&[]).unwrap(), BasicStateSort::Reject);
+    /// ```
+    pub fn reduce_sort<F>(self, reducer: F) -> ReducedProductAutomatonBlueprint<'a, 'b, A, B, Alphabet, ErrorType, F>
+    where
+        F: Fn(A::StateSort, B::StateSort) -> BasicStateSort
+    {
+        ReducedProductAutomatonBlueprint {
+            product: self,
+            reducer
+        }
+    }
 }
 
 impl<A, B, Alphabet, ErrorType> DeterministicAutomatonBlueprint for ProductAutomatonBlueprint<'_, '_, A, B, Alphabet, ErrorType>
@@ -145,6 +261,112 @@ where
     }
 }
 
+impl<A, B, Alphabet, ErrorType> DeterministicAutomaton<'_, ProductAutomatonBlueprint<'_, '_, A, B, Alphabet, ErrorType>>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    /// Returns mutable references to both component states of a running product automaton.
+    ///
+    /// Each component state can be inspected, snapshotted or replaced independently of
+    /// the other, allowing one component to be rewound to an earlier checkpoint while the
+    /// other continues from where it is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+    /// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+    /// use deterministic_automata::product_automaton::ProductAutomatonBlueprint;
+    ///
+    /// let first = CounterAutomatonBlueprint::new('a', 'b');
+    /// let second = CounterAutomatonBlueprint::new('a', 'b');
+    /// let product = ProductAutomatonBlueprint::new(&first, &second);
+    /// let mut automaton = product.automaton();
+    ///
+    /// automaton.update_state(&'a').unwrap();
+    /// let checkpoint = automaton.split().0.clone();
+    /// automaton.update_state(&'a').unwrap();
+    ///
+    /// // Rewind only the first component
+    /// *automaton.split().0 = checkpoint;
+    /// automaton.update_state(&'b').unwrap();
+    ///
+    /// assert_eq!(automaton.current_state_sort().unwrap(), (BasicStateSort::Accept, BasicStateSort::Reject));
+    /// ```
+    pub fn split(&mut self) -> (&mut A::State, &mut B::State) {
+        let (first, second) = &mut self.current_state;
+        (first, second)
+    }
+}
+
+
+/// A product blueprint whose tuple classification is collapsed by a reducer function.
+///
+/// Runs both component automata in parallel like [`ProductAutomatonBlueprint`], then
+/// classifies each product state by applying the reducer to the pair of component
+/// classifications.
+///
+/// # Construction
+///
+/// Use [`ProductAutomatonBlueprint::reduce_sort`].
+#[derive(Clone)]
+pub struct ReducedProductAutomatonBlueprint<'a, 'b, A, B, Alphabet, ErrorType, F>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    Alphabet: PartialEq,
+    F: Fn(A::StateSort, B::StateSort) -> BasicStateSort
+{
+    product: ProductAutomatonBlueprint<'a, 'b, A, B, Alphabet, ErrorType>,
+    reducer: F
+}
+
+/// Formats the two component blueprints; the reducer is a closure and is omitted.
+impl<A, B, Alphabet, ErrorType, F> std::fmt::Debug for ReducedProductAutomatonBlueprint<'_, '_, A, B, Alphabet, ErrorType, F>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType> + std::fmt::Debug,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType> + std::fmt::Debug,
+    Alphabet: PartialEq,
+    F: Fn(A::StateSort, B::StateSort) -> BasicStateSort
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReducedProductAutomatonBlueprint")
+            .field("first", &self.product.first)
+            .field("second", &self.product.second)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A, B, Alphabet, ErrorType, F> DeterministicAutomatonBlueprint for ReducedProductAutomatonBlueprint<'_, '_, A, B, Alphabet, ErrorType, F>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    Alphabet: PartialEq,
+    F: Fn(A::StateSort, B::StateSort) -> BasicStateSort
+{
+    type State = (A::State, B::State);
+
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        self.product.initial_state()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort,Self::ErrorType> {
+        let (a_sort, b_sort) = self.product.state_sort_map(state)?;
+        Ok((self.reducer)(a_sort, b_sort))
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        self.product.transition_map(state, character)
+    }
+}
 
 /// A blueprint for the union (logical OR) of two automata with [`BasicStateSort`].
 ///
@@ -244,6 +466,18 @@ where
     }
 }
 
+impl<A, B, Alphabet, ErrorType> DescribableAutomaton for BasicUnionAutomatonBlueprint<'_, '_, A, B, Alphabet, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType> + DescribableAutomaton<Alphabet = Alphabet>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType> + DescribableAutomaton<Alphabet = Alphabet>,
+    Alphabet: PartialEq
+{
+    /// Returns the first component's example, falling back to the second's.
+    fn example_accepted(&self) -> Option<Vec<Alphabet>> {
+        self.first.example_accepted().or_else(|| self.second.example_accepted())
+    }
+}
+
 /// A blueprint for the intersection (logical AND) of two automata with [`BasicStateSort`].
 ///
 /// This blueprint creates an automaton that accepts a string only if **both** of the
@@ -343,68 +577,52 @@ where
     }
 }
 
-/// A mutation automaton blueprint for the general product construction of two mutation automata.
-///
-/// This blueprint implements the Cartesian product of two mutation automata, creating a new
-/// mutation automaton that runs both component automata in parallel with in-place state mutation.
-/// The resulting automaton's state space is the product of the component state spaces, and its
-/// state sort preserves both component classifications as a tuple.
-///
-/// # Type Parameters
-///
-/// * `A`, `B` - The component mutation automaton blueprint types
-/// * `Alphabet` - The input symbol type (must be the same for both automata)
-/// * `ErrorType` - The error type (must be the same for both automata)
-///
-/// # State and Behavior
-///
-/// * **State**: `(A::State, B::State)` - Pairs of component states
-/// * **StateSort**: `(A::StateSort, B::StateSort)` - Pairs of component classifications
-/// * **Transitions**: Both component automata mutate their states simultaneously in place
+/// A product blueprint owning its two component blueprints; see [`ProductAutomatonBlueprint`].
 ///
 /// # Construction
 ///
-/// Use [`new`](Self::new) to create an instance from two component blueprint references.
+/// Use [`new`](Self::new) to create an instance from two component blueprints.
 #[derive(Debug, Clone, PartialEq)]
-pub struct MutationProductAutomatonBlueprint<'a, 'b, A, B, Alphabet, ErrorType>
+pub struct OwnedProductAutomatonBlueprint<A, B, Alphabet, ErrorType>
 where
-    A: MutationAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
-    B: MutationAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
     Alphabet: PartialEq
 {
-    first: &'a A,
-    second: &'b B
+    first: A,
+    second: B
 }
 
-impl<'a, 'b, A, B, Alphabet, ErrorType> MutationProductAutomatonBlueprint<'a, 'b, A, B, Alphabet, ErrorType>
+impl<A, B, Alphabet, ErrorType> OwnedProductAutomatonBlueprint<A, B, Alphabet, ErrorType>
 where
-    A: MutationAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
-    B: MutationAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
     Alphabet: PartialEq
 {
-    /// Creates a new mutation product automaton blueprint from two component blueprints.
+    /// Creates a new product blueprint owning two component blueprints.
     ///
     /// # Parameters
     ///
-    /// * `first` - Reference to the first component mutation automaton blueprint
-    /// * `second` - Reference to the second component mutation automaton blueprint
-    ///
-    /// # Returns
-    ///
-    /// A new mutation product blueprint that preserves both component state classifications
-    /// as a tuple, with in-place state mutation for both components.
-    pub fn new(first: &'a A, second: &'b B) -> Self {
-        Self {
-            first,
-            second
-        }
+    /// * `first` - The first component automaton blueprint
+    /// * `second` - The second component automaton blueprint
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Consumes the blueprint and returns the two components.
+    pub fn into_parts(self) -> (A, B) {
+        (self.first, self.second)
+    }
+
+    fn borrowed(&self) -> ProductAutomatonBlueprint<'_, '_, A, B, Alphabet, ErrorType> {
+        ProductAutomatonBlueprint::new(&self.first, &self.second)
     }
 }
 
-impl<A, B, Alphabet, ErrorType> MutationAutomatonBlueprint for MutationProductAutomatonBlueprint<'_, '_, A, B, Alphabet, ErrorType>
+impl<A, B, Alphabet, ErrorType> DeterministicAutomatonBlueprint for OwnedProductAutomatonBlueprint<A, B, Alphabet, ErrorType>
 where
-    A: MutationAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
-    B: MutationAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
     Alphabet: PartialEq
 {
     type State = (A::State, B::State);
@@ -415,87 +633,65 @@ where
 
     type ErrorType = ErrorType;
 
-    fn initial_mutation_state(&self) -> Self::State {
-        (self.first.initial_mutation_state(), self.second.initial_mutation_state())
+    fn initial_state(&self) -> Self::State {
+        self.borrowed().initial_state()
     }
 
-    fn mutation_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
-        let (a, b) = (self.first, self.second);
-        let (a_sort, b_sort) = (a.mutation_state_sort_map(&state.0)?, b.mutation_state_sort_map(&state.1)?);
-        Ok((a_sort, b_sort))
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        self.borrowed().state_sort_map(state)
     }
 
-    fn mutation_transition_map(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
-        let (a, b) = (self.first, self.second);
-        a.mutation_transition_map(&mut state.0, character)?;
-        b.mutation_transition_map(&mut state.1, character)?;
-        Ok(())
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        self.borrowed().transition_map(state, character)
     }
 }
 
-/// A mutation automaton blueprint for the union (logical OR) of two mutation automata with [`BasicStateSort`].
-///
-/// This blueprint creates a mutation automaton that accepts a string if **either** of the
-/// component mutation automata accepts it, implementing the union of their recognized languages:
-/// `L(A) ∪ L(B)` with in-place state mutation.
-///
-/// # Boolean Logic
-///
-/// The state classification follows logical OR semantics:
-/// - `Accept OR Accept → Accept`
-/// - `Accept OR Reject → Accept`  
-/// - `Reject OR Accept → Accept`
-/// - `Reject OR Reject → Reject`
-///
-/// # Type Parameters
-///
-/// * `A`, `B` - Component mutation automaton blueprints (must use [`BasicStateSort`])
-/// * `Alphabet` - Input symbol type (shared by both automata)
-/// * `ErrorType` - Error type (shared by both automata)
+/// A union blueprint owning its two component blueprints; see [`BasicUnionAutomatonBlueprint`].
 ///
 /// # Construction
 ///
-/// Use [`new`](Self::new) to create an instance from two component blueprint references.
+/// Use [`new`](Self::new) to create an instance from two component blueprints.
 #[derive(Debug, Clone, PartialEq)]
-pub struct MutationBasicUnionAutomatonBlueprint<'a, 'b, A, B, Alphabet, ErrorType>
+pub struct OwnedBasicUnionAutomatonBlueprint<A, B, Alphabet, ErrorType>
 where
-    A: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
-    B: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
     Alphabet: PartialEq
 {
-    first: &'a A,
-    second: &'b B
+    first: A,
+    second: B
 }
 
-impl<'a, 'b, A, B, Alphabet, ErrorType> MutationBasicUnionAutomatonBlueprint<'a, 'b, A, B, Alphabet, ErrorType>
+impl<A, B, Alphabet, ErrorType> OwnedBasicUnionAutomatonBlueprint<A, B, Alphabet, ErrorType>
 where
-    A: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
-    B: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
     Alphabet: PartialEq
 {
-    /// Creates a new mutation union automaton blueprint from two component blueprints.
+    /// Creates a new union blueprint owning two component blueprints.
     ///
     /// # Parameters
     ///
-    /// * `first` - Reference to the first component mutation automaton blueprint
-    /// * `second` - Reference to the second component mutation automaton blueprint
-    ///
-    /// # Returns
-    ///
-    /// A new mutation union blueprint that accepts strings accepted by either component,
-    /// with in-place state mutation for both components.
-    pub fn new(first: &'a A, second: &'b B) -> Self {
-        Self {
-            first,
-            second
-        }
+    /// * `first` - The first component automaton blueprint
+    /// * `second` - The second component automaton blueprint
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Consumes the blueprint and returns the two components.
+    pub fn into_parts(self) -> (A, B) {
+        (self.first, self.second)
+    }
+
+    fn borrowed(&self) -> BasicUnionAutomatonBlueprint<'_, '_, A, B, Alphabet, ErrorType> {
+        BasicUnionAutomatonBlueprint::new(&self.first, &self.second)
     }
 }
 
-impl<A, B, Alphabet, ErrorType> MutationAutomatonBlueprint for MutationBasicUnionAutomatonBlueprint<'_, '_, A, B, Alphabet, ErrorType>
+impl<A, B, Alphabet, ErrorType> DeterministicAutomatonBlueprint for OwnedBasicUnionAutomatonBlueprint<A, B, Alphabet, ErrorType>
 where
-    A: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
-    B: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
     Alphabet: PartialEq
 {
     type State = (A::State, B::State);
@@ -506,72 +702,865 @@ where
 
     type ErrorType = ErrorType;
 
-    fn initial_mutation_state(&self) -> Self::State {
-        (self.first.initial_mutation_state(), self.second.initial_mutation_state())
+    fn initial_state(&self) -> Self::State {
+        self.borrowed().initial_state()
     }
 
-    fn mutation_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
-        Ok(match (self.first.mutation_state_sort_map(&state.0)?, self.second.mutation_state_sort_map(&state.1)?) {
-            (BasicStateSort::Accept, BasicStateSort::Accept) => BasicStateSort::Accept,
-            (BasicStateSort::Accept, BasicStateSort::Reject) => BasicStateSort::Accept,
-            (BasicStateSort::Reject, BasicStateSort::Accept) => BasicStateSort::Accept,
-            (BasicStateSort::Reject, BasicStateSort::Reject) => BasicStateSort::Reject,
-        })
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        self.borrowed().state_sort_map(state)
     }
 
-    fn mutation_transition_map(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
-        let (a, b) = (self.first, self.second);
-        a.mutation_transition_map(&mut state.0, character)?;
-        b.mutation_transition_map(&mut state.1, character)?;
-        Ok(())
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        self.borrowed().transition_map(state, character)
     }
 }
 
-/// A mutation automaton blueprint for the intersection (logical AND) of two mutation automata with [`BasicStateSort`].
-///
-/// This blueprint creates a mutation automaton that accepts a string only if **both** of the
-/// component mutation automata accept it, implementing the intersection of their recognized
-/// languages: `L(A) ∩ L(B)` with in-place state mutation.
+/// An intersection blueprint owning its two component blueprints; see [`BasicIntersectionAutomatonBlueprint`].
 ///
-/// # Boolean Logic
+/// # Construction
 ///
-/// The state classification follows logical AND semantics:
-/// - `Accept AND Accept → Accept`
-/// - `Accept AND Reject → Reject`
-/// - `Reject AND Accept → Reject`
-/// - `Reject AND Reject → Reject`
+/// Use [`new`](Self::new) to create an instance from two component blueprints.
 ///
-/// # Type Parameters
+/// # Example
 ///
-/// * `A`, `B` - Component mutation automaton blueprints (must use [`BasicStateSort`])
-/// * `Alphabet` - Input symbol type (shared by both automata)
-/// * `ErrorType` - Error type (shared by both automata)
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::primitives::NoRepeatsBlueprint;
+/// use deterministic_automata::product_automaton::OwnedBasicIntersectionAutomatonBlueprint;
 ///
-/// # Construction
+/// // The components are created inside the factory and moved into the result
+/// fn alternating_balanced() -> OwnedBasicIntersectionAutomatonBlueprint<CounterAutomatonBlueprint<char>, NoRepeatsBlueprint<char, String>, char, String> {
+///     OwnedBasicIntersectionAutomatonBlueprint::new(CounterAutomatonBlueprint::new('a', 'b'), NoRepeatsBlueprint::new())
+/// }
 ///
-/// Use [`new`](Self::new) to create an instance from two component blueprint references.
+/// let recognizer = alternating_balanced();
+/// assert_eq!(recognizer.characterise(&['a', 'b']).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(recognizer.characterise(&['a', 'a', 'b', 'b']).unwrap(), BasicStateSort::Reject);
+/// ```
 #[derive(Debug, Clone, PartialEq)]
-pub struct MutationBasicIntersectionAutomatonBlueprint<'a, 'b, A, B, Alphabet, ErrorType>
+pub struct OwnedBasicIntersectionAutomatonBlueprint<A, B, Alphabet, ErrorType>
 where
-    A: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
-    B: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
     Alphabet: PartialEq
 {
-    first: &'a A,
-    second: &'b B
+    first: A,
+    second: B
 }
 
-impl<'a, 'b, A, B, Alphabet, ErrorType> MutationBasicIntersectionAutomatonBlueprint<'a, 'b, A, B, Alphabet, ErrorType>
+impl<A, B, Alphabet, ErrorType> OwnedBasicIntersectionAutomatonBlueprint<A, B, Alphabet, ErrorType>
 where
-    A: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
-    B: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
     Alphabet: PartialEq
 {
-    /// Creates a new mutation intersection automaton blueprint from two component blueprints.
+    /// Creates a new intersection blueprint owning two component blueprints.
     ///
     /// # Parameters
     ///
-    /// * `first` - Reference to the first component mutation automaton blueprint
+    /// * `first` - The first component automaton blueprint
+    /// * `second` - The second component automaton blueprint
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Consumes the blueprint and returns the two components.
+    pub fn into_parts(self) -> (A, B) {
+        (self.first, self.second)
+    }
+
+    fn borrowed(&self) -> BasicIntersectionAutomatonBlueprint<'_, '_, A, B, Alphabet, ErrorType> {
+        BasicIntersectionAutomatonBlueprint::new(&self.first, &self.second)
+    }
+}
+
+impl<A, B, Alphabet, ErrorType> DeterministicAutomatonBlueprint for OwnedBasicIntersectionAutomatonBlueprint<A, B, Alphabet, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    type State = (A::State, B::State);
+
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        self.borrowed().initial_state()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        self.borrowed().state_sort_map(state)
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        self.borrowed().transition_map(state, character)
+    }
+}
+
+/// A bounded lattice of state classifications.
+///
+/// [`meet`](Self::meet) and [`join`](Self::join) generalize logical AND and OR to
+/// multi-valued classifications such as confidence levels, with [`bottom`](Self::bottom) and
+/// [`top`](Self::top) as their identities. [`BasicStateSort`] is the two-element lattice with
+/// `Reject` below `Accept`, where meet is intersection and join is union.
+///
+/// Implementations should satisfy the lattice laws: both operations are commutative,
+/// associative and idempotent, and absorb each other.
+pub trait Lattice {
+    /// Returns the greatest lower bound of two classifications.
+    fn meet(self, other: Self) -> Self;
+
+    /// Returns the least upper bound of two classifications.
+    fn join(self, other: Self) -> Self;
+
+    /// Returns the least element, the identity of [`join`](Self::join).
+    fn bottom() -> Self;
+
+    /// Returns the greatest element, the identity of [`meet`](Self::meet).
+    fn top() -> Self;
+}
+
+impl Lattice for BasicStateSort {
+    fn meet(self, other: Self) -> Self {
+        if self == BasicStateSort::Accept { other } else { BasicStateSort::Reject }
+    }
+
+    fn join(self, other: Self) -> Self {
+        if self == BasicStateSort::Accept { BasicStateSort::Accept } else { other }
+    }
+
+    fn bottom() -> Self {
+        BasicStateSort::Reject
+    }
+
+    fn top() -> Self {
+        BasicStateSort::Accept
+    }
+}
+
+/// A product blueprint classifying each state by the [`join`](Lattice::join) of the component classifications.
+///
+/// This generalizes [`BasicUnionAutomatonBlueprint`] to any classification forming a
+/// [`Lattice`]: for [`BasicStateSort`] the two agree.
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) to create an instance from two component blueprint references.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::product_automaton::LatticeJoinProductBlueprint;
+///
+/// let ab = CounterAutomatonBlueprint::new('a', 'b');
+/// let ac = CounterAutomatonBlueprint::new('a', 'c');
+/// let join = LatticeJoinProductBlueprint::new(&ab, &ac);
+///
+/// assert_eq!(join.characterise(&['a', 'c']).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(join.characterise(&['a']).unwrap(), BasicStateSort::Reject);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LatticeJoinProductBlueprint<'a, 'b, A, B, Alphabet, StateSort, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq,
+    StateSort: Lattice
+{
+    first: &'a A,
+    second: &'b B
+}
+
+impl<'a, 'b, A, B, Alphabet, StateSort, ErrorType> LatticeJoinProductBlueprint<'a, 'b, A, B, Alphabet, StateSort, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq,
+    StateSort: Lattice
+{
+    /// Creates a new join product blueprint from two component blueprints.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - Reference to the first component automaton blueprint
+    /// * `second` - Reference to the second component automaton blueprint
+    pub fn new(first: &'a A, second: &'b B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<A, B, Alphabet, StateSort, ErrorType> DeterministicAutomatonBlueprint for LatticeJoinProductBlueprint<'_, '_, A, B, Alphabet, StateSort, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq,
+    StateSort: Lattice
+{
+    type State = (A::State, B::State);
+
+    type Alphabet = Alphabet;
+
+    type StateSort = StateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        (self.first.initial_state(), self.second.initial_state())
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort,Self::ErrorType> {
+        Ok(self.first.state_sort_map(&state.0)?.join(self.second.state_sort_map(&state.1)?))
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok((self.first.transition_map(&state.0, character)?, self.second.transition_map(&state.1, character)?))
+    }
+}
+
+/// A product blueprint classifying each state by the [`meet`](Lattice::meet) of the component classifications.
+///
+/// This generalizes [`BasicIntersectionAutomatonBlueprint`] to any classification forming a
+/// [`Lattice`]: for [`BasicStateSort`] the two agree.
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) to create an instance from two component blueprint references.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LatticeMeetProductBlueprint<'a, 'b, A, B, Alphabet, StateSort, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq,
+    StateSort: Lattice
+{
+    first: &'a A,
+    second: &'b B
+}
+
+impl<'a, 'b, A, B, Alphabet, StateSort, ErrorType> LatticeMeetProductBlueprint<'a, 'b, A, B, Alphabet, StateSort, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq,
+    StateSort: Lattice
+{
+    /// Creates a new meet product blueprint from two component blueprints.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - Reference to the first component automaton blueprint
+    /// * `second` - Reference to the second component automaton blueprint
+    pub fn new(first: &'a A, second: &'b B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<A, B, Alphabet, StateSort, ErrorType> DeterministicAutomatonBlueprint for LatticeMeetProductBlueprint<'_, '_, A, B, Alphabet, StateSort, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq,
+    StateSort: Lattice
+{
+    type State = (A::State, B::State);
+
+    type Alphabet = Alphabet;
+
+    type StateSort = StateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        (self.first.initial_state(), self.second.initial_state())
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort,Self::ErrorType> {
+        Ok(self.first.state_sort_map(&state.0)?.meet(self.second.state_sort_map(&state.1)?))
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok((self.first.transition_map(&state.0, character)?, self.second.transition_map(&state.1, character)?))
+    }
+}
+
+/// A blueprint using the verdict of a primary automaton, falling back to a second automaton
+/// once the primary errors.
+///
+/// Unlike [`BasicUnionAutomatonBlueprint`], the verdicts are not combined: the fallback is
+/// only consulted after the primary has failed, which suits graceful degradation from a
+/// strict recognizer to a lenient one.
+///
+/// # State Representation
+///
+/// The primary may fail in the middle of a run, at which point the fallback must be able to
+/// take over with the state it would have reached on the same input. Both components are
+/// therefore run in parallel while the primary is healthy, giving
+/// [`FallbackState::Primary`]. Once a primary transition errors, its state is dropped and only
+/// the fallback continues, in [`FallbackState::Fallback`]. A fallback that errors while the
+/// primary is still healthy is likewise dropped, so the primary's later errors are final.
+///
+/// Classification follows the same rule: a primary state whose classification errors is
+/// classified by the fallback instead, if it is still running.
+///
+/// # Errors
+///
+/// The run fails only when both components have failed. When they fail on the same symbol,
+/// the fallback's error is reported, as it was the last resort; when the fallback failed
+/// earlier, the primary's error is reported.
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) to create an instance from two component blueprint references.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::mod_counter_example::ModSumBlueprint;
+/// use deterministic_automata::product_automaton::FallbackBlueprint;
+/// use deterministic_automata::fn_automaton::FnBlueprint;
+///
+/// // Strict: only digits up to 9 are valid. Lenient: any byte counts towards the length.
+/// let strict = ModSumBlueprint::new(2);
+/// let lenient = FnBlueprint::new(
+///     || 0usize,
+///     |length: &usize| Ok::<_, String>(BasicStateSort::from(length % 2 == 0)),
+///     |length: &usize, _: &u8| Ok(length + 1),
+/// );
+/// let resilient = FallbackBlueprint::new(&strict, &lenient);
+///
+/// // The strict verdict is used while every digit is valid: 1 + 2 is odd
+/// assert_eq!(resilient.characterise(&[1, 2]).unwrap(), BasicStateSort::Reject);
+/// // After the invalid 42 the lenient verdict takes over: three symbols is odd
+/// assert_eq!(resilient.characterise(&[1, 42, 1]).unwrap(), BasicStateSort::Reject);
+/// assert_eq!(resilient.characterise(&[1, 42]).unwrap(), BasicStateSort::Accept);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FallbackBlueprint<'a, 'b, A, B, Alphabet, StateSort, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    primary: &'a A,
+    fallback: &'b B
+}
+
+/// State of a [`FallbackBlueprint`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FallbackState<P, F> {
+    /// The primary is healthy; holds its state and the fallback's, unless the fallback failed.
+    Primary(P, Option<F>),
+    /// The primary failed; only the fallback is running.
+    Fallback(F)
+}
+
+impl<'a, 'b, A, B, Alphabet, StateSort, ErrorType> FallbackBlueprint<'a, 'b, A, B, Alphabet, StateSort, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    /// Creates a new fallback blueprint from two component blueprints.
+    ///
+    /// # Parameters
+    ///
+    /// * `primary` - Reference to the automaton blueprint whose verdict is preferred
+    /// * `fallback` - Reference to the automaton blueprint consulted once the primary errors
+    pub fn new(primary: &'a A, fallback: &'b B) -> Self {
+        Self { primary, fallback }
+    }
+}
+
+impl<A, B, Alphabet, StateSort, ErrorType> DeterministicAutomatonBlueprint for FallbackBlueprint<'_, '_, A, B, Alphabet, StateSort, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    type State = FallbackState<A::State, B::State>;
+
+    type Alphabet = Alphabet;
+
+    type StateSort = StateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        FallbackState::Primary(self.primary.initial_state(), Some(self.fallback.initial_state()))
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        match state {
+            FallbackState::Primary(primary, fallback) => match (self.primary.state_sort_map(primary), fallback) {
+                (Ok(sort), _) => Ok(sort),
+                (Err(_), Some(fallback)) => self.fallback.state_sort_map(fallback),
+                (Err(error), None) => Err(error),
+            },
+            FallbackState::Fallback(fallback) => self.fallback.state_sort_map(fallback),
+        }
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        match state {
+            FallbackState::Primary(primary, fallback) => {
+                let fallback = fallback.as_ref().map(|fallback| self.fallback.transition_map(fallback, character));
+                match (self.primary.transition_map(primary, character), fallback) {
+                    (Ok(primary), fallback) => Ok(FallbackState::Primary(primary, fallback.and_then(Result::ok))),
+                    (Err(_), Some(Ok(fallback))) => Ok(FallbackState::Fallback(fallback)),
+                    (Err(_), Some(Err(error))) | (Err(error), None) => Err(error),
+                }
+            }
+            FallbackState::Fallback(fallback) => Ok(FallbackState::Fallback(self.fallback.transition_map(fallback, character)?)),
+        }
+    }
+}
+
+/// A blueprint for the shuffle (interleaving) of the languages of two [`BasicStateSort`] automata.
+///
+/// A word is in the shuffle when its symbols can be split into two subsequences, the first
+/// accepted by `first` and the second by `second`. For example, the shuffle of `{ab}` and
+/// `{c}` is `{abc, acb, cab}`.
+///
+/// # State Representation
+///
+/// Each symbol may be given to either component, so the run is nondeterministic. The state
+/// is the set of component state pairs reachable by some way of distributing the symbols
+/// read so far, stored as a [`BTreeSet`] so that the same set of pairs is always the same
+/// state, whatever order the pairs were reached in. Component states must therefore be
+/// [`Ord`]. A state accepts when some pair has both components accepting.
+///
+/// # Complexity
+///
+/// The set of pairs can double with every symbol until duplicates start to coincide, so
+/// in the worst case it grows exponentially in the input length, and for finite components
+/// it is bounded by the product of their state counts. Transitions cost time linear in the
+/// set size times a logarithmic insertion, so long inputs over large components are expensive.
+///
+/// # Errors
+///
+/// Every pair is advanced both ways, and a way whose transition fails is dropped, since that
+/// distribution of the symbols cannot be completed. The errors of dropped ways are silently
+/// discarded, so components over different alphabets may report errors on each other's
+/// symbols. A transition error is returned only when every way of advancing fails, and it is
+/// the first component's error on the smallest pair of the set. Classification errors are
+/// always returned.
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) to create an instance from two component blueprint references.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::product_automaton::ShuffleBlueprint;
+///
+/// // Interleavings of a balanced parenthesis word with a balanced bracket word
+/// let parens = CounterAutomatonBlueprint::new('(', ')');
+/// let brackets = CounterAutomatonBlueprint::new('[', ']');
+/// let shuffle = ShuffleBlueprint::new(&parens, &brackets);
+///
+/// assert_eq!(shuffle.characterise(&['(', '[', ')', ']']).unwrap(), BasicStateSort::Accept);
+/// assert_eq!(shuffle.characterise(&['(', '[', ')']).unwrap(), BasicStateSort::Reject);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShuffleBlueprint<'a, 'b, A, B, Alphabet, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    first: &'a A,
+    second: &'b B
+}
+
+impl<'a, 'b, A, B, Alphabet, ErrorType> ShuffleBlueprint<'a, 'b, A, B, Alphabet, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    /// Creates a new shuffle blueprint from two component blueprints.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - Reference to the automaton blueprint for the first interleaved word
+    /// * `second` - Reference to the automaton blueprint for the second interleaved word
+    pub fn new(first: &'a A, second: &'b B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<A, B, Alphabet, ErrorType> DeterministicAutomatonBlueprint for ShuffleBlueprint<'_, '_, A, B, Alphabet, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    A::State: Ord,
+    B::State: Ord,
+    Alphabet: PartialEq
+{
+    type State = BTreeSet<(A::State, B::State)>;
+
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        BTreeSet::from([(self.first.initial_state(), self.second.initial_state())])
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        for (first, second) in state {
+            if self.first.state_sort_map(first)? == BasicStateSort::Accept
+                && self.second.state_sort_map(second)? == BasicStateSort::Accept {
+                return Ok(BasicStateSort::Accept);
+            }
+        }
+        Ok(BasicStateSort::Reject)
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        let mut next = BTreeSet::new();
+        let mut first_error = None;
+        for (first, second) in state {
+            for pair in [
+                self.first.transition_map(first, character).map(|first| (first, second.clone())),
+                self.second.transition_map(second, character).map(|second| (first.clone(), second)),
+            ] {
+                match pair {
+                    Ok(pair) => {
+                        next.insert(pair);
+                    }
+                    Err(error) => {
+                        first_error.get_or_insert(error);
+                    }
+                }
+            }
+        }
+        match first_error {
+            Some(error) if next.is_empty() => Err(error),
+            _ => Ok(next)
+        }
+    }
+}
+
+impl<A, B, Alphabet, ErrorType> DescribableAutomaton for ShuffleBlueprint<'_, '_, A, B, Alphabet, ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType> + DescribableAutomaton<Alphabet = Alphabet>,
+    B: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType> + DescribableAutomaton<Alphabet = Alphabet>,
+    <A as DeterministicAutomatonBlueprint>::State: Ord,
+    <B as DeterministicAutomatonBlueprint>::State: Ord,
+    Alphabet: PartialEq
+{
+    /// Returns the first component's example followed by the second's, one of the interleavings.
+    fn example_accepted(&self) -> Option<Vec<Alphabet>> {
+        let mut example = self.first.example_accepted()?;
+        example.extend(self.second.example_accepted()?);
+        Some(example)
+    }
+}
+
+/// A mutation automaton blueprint for the general product construction of two mutation automata.
+///
+/// This blueprint implements the Cartesian product of two mutation automata, creating a new
+/// mutation automaton that runs both component automata in parallel with in-place state mutation.
+/// The resulting automaton's state space is the product of the component state spaces, and its
+/// state sort preserves both component classifications as a tuple.
+///
+/// # Type Parameters
+///
+/// * `A`, `B` - The component mutation automaton blueprint types
+/// * `Alphabet` - The input symbol type (must be the same for both automata)
+/// * `ErrorType` - The error type (must be the same for both automata)
+///
+/// # State and Behavior
+///
+/// * **State**: `(A::State, B::State)` - Pairs of component states
+/// * **StateSort**: `(A::StateSort, B::StateSort)` - Pairs of component classifications
+/// * **Transitions**: Both component automata mutate their states simultaneously in place
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) to create an instance from two component blueprint references.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MutationProductAutomatonBlueprint<'a, 'b, A, B, Alphabet, ErrorType>
+where
+    A: MutationAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    B: MutationAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    first: &'a A,
+    second: &'b B
+}
+
+impl<'a, 'b, A, B, Alphabet, ErrorType> MutationProductAutomatonBlueprint<'a, 'b, A, B, Alphabet, ErrorType>
+where
+    A: MutationAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    B: MutationAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    /// Creates a new mutation product automaton blueprint from two component blueprints.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - Reference to the first component mutation automaton blueprint
+    /// * `second` - Reference to the second component mutation automaton blueprint
+    ///
+    /// # Returns
+    ///
+    /// A new mutation product blueprint that preserves both component state classifications
+    /// as a tuple, with in-place state mutation for both components.
+    pub fn new(first: &'a A, second: &'b B) -> Self {
+        Self {
+            first,
+            second
+        }
+    }
+
+    /// Returns references to both component states of a running product automaton.
+    ///
+    /// This provides the mutation product's counterpart to inspecting the paired state
+    /// of a deterministic product, splitting the current state into its halves.
+    pub fn component_states<'s>(automaton: &'s MutationAutomaton<'_, Self>) -> (&'s A::State, &'s B::State) {
+        let (first, second) = automaton.view_state();
+        (first, second)
+    }
+
+    /// Consumes a running product automaton and returns both component states.
+    ///
+    /// Useful when a run has finished and the final state of each component is needed
+    /// independently, for example to read the counters of two counter automata.
+    pub fn take_component_states(automaton: MutationAutomaton<'_, Self>) -> (A::State, B::State) {
+        automaton.take_state()
+    }
+}
+
+impl<A, B, Alphabet, StateSort, ErrorType> MutationProductAutomatonBlueprint<'_, '_, A, B, Alphabet, ErrorType>
+where
+    A: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    B: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = StateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq,
+    StateSort: PartialEq
+{
+    /// Finds the shortest prefix of `word` on which the two components disagree.
+    ///
+    /// Steps the product automaton through `word`, comparing the component classifications
+    /// before the first symbol and after each symbol. Returns `Some(n)` if the prefix of
+    /// length `n` is the first on which the classifications differ, or `None` if the
+    /// components agree on every prefix. Useful for debugging two validators that are
+    /// expected to be equivalent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+    /// use deterministic_automata::product_automaton::MutationProductAutomatonBlueprint;
+    ///
+    /// let ab = CounterAutomatonBlueprint::new('a', 'b');
+    /// let ac = CounterAutomatonBlueprint::new('a', 'c');
+    /// let product = MutationProductAutomatonBlueprint::new(&ab, &ac);
+    ///
+    /// assert_eq!(product.divergence_index(&['a', 'b', 'a']).unwrap(), Some(2));
+    /// assert_eq!(product.divergence_index(&['a', 'a']).unwrap(), None);
+    /// ```
+    pub fn divergence_index(&self, word: &[Alphabet]) -> Result<Option<usize>, ErrorType> {
+        let mut automaton = self.mutation_automaton();
+        let (first, second) = automaton.current_state_sort()?;
+        if first != second {
+            return Ok(Some(0));
+        }
+        for (index, character) in word.iter().enumerate() {
+            let (first, second) = automaton.update_sort_state(character)?;
+            if first != second {
+                return Ok(Some(index + 1));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<A, B, Alphabet, ErrorType> MutationAutomatonBlueprint for MutationProductAutomatonBlueprint<'_, '_, A, B, Alphabet, ErrorType>
+where
+    A: MutationAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    B: MutationAutomatonBlueprint<Alphabet = Alphabet, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    type State = (A::State, B::State);
+
+    type Alphabet = Alphabet;
+
+    type StateSort = (A::StateSort, B::StateSort);
+
+    type ErrorType = ErrorType;
+
+    fn initial_mutation_state(&self) -> Self::State {
+        (self.first.initial_mutation_state(), self.second.initial_mutation_state())
+    }
+
+    fn mutation_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        let (a, b) = (self.first, self.second);
+        let (a_sort, b_sort) = (a.mutation_state_sort_map(&state.0)?, b.mutation_state_sort_map(&state.1)?);
+        Ok((a_sort, b_sort))
+    }
+
+    fn mutation_transition_map(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        let (a, b) = (self.first, self.second);
+        a.mutation_transition_map(&mut state.0, character)?;
+        b.mutation_transition_map(&mut state.1, character)?;
+        Ok(())
+    }
+}
+
+/// A mutation automaton blueprint for the union (logical OR) of two mutation automata with [`BasicStateSort`].
+///
+/// This blueprint creates a mutation automaton that accepts a string if **either** of the
+/// component mutation automata accepts it, implementing the union of their recognized languages:
+/// `L(A) ∪ L(B)` with in-place state mutation.
+///
+/// # Boolean Logic
+///
+/// The state classification follows logical OR semantics:
+/// - `Accept OR Accept → Accept`
+/// - `Accept OR Reject → Accept`  
+/// - `Reject OR Accept → Accept`
+/// - `Reject OR Reject → Reject`
+///
+/// # Type Parameters
+///
+/// * `A`, `B` - Component mutation automaton blueprints (must use [`BasicStateSort`])
+/// * `Alphabet` - Input symbol type (shared by both automata)
+/// * `ErrorType` - Error type (shared by both automata)
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) to create an instance from two component blueprint references.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MutationBasicUnionAutomatonBlueprint<'a, 'b, A, B, Alphabet, ErrorType>
+where
+    A: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    B: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    first: &'a A,
+    second: &'b B
+}
+
+impl<'a, 'b, A, B, Alphabet, ErrorType> MutationBasicUnionAutomatonBlueprint<'a, 'b, A, B, Alphabet, ErrorType>
+where
+    A: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    B: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    /// Creates a new mutation union automaton blueprint from two component blueprints.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - Reference to the first component mutation automaton blueprint
+    /// * `second` - Reference to the second component mutation automaton blueprint
+    ///
+    /// # Returns
+    ///
+    /// A new mutation union blueprint that accepts strings accepted by either component,
+    /// with in-place state mutation for both components.
+    pub fn new(first: &'a A, second: &'b B) -> Self {
+        Self {
+            first,
+            second
+        }
+    }
+}
+
+impl<A, B, Alphabet, ErrorType> MutationAutomatonBlueprint for MutationBasicUnionAutomatonBlueprint<'_, '_, A, B, Alphabet, ErrorType>
+where
+    A: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    B: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    type State = (A::State, B::State);
+
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_mutation_state(&self) -> Self::State {
+        (self.first.initial_mutation_state(), self.second.initial_mutation_state())
+    }
+
+    fn mutation_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(match (self.first.mutation_state_sort_map(&state.0)?, self.second.mutation_state_sort_map(&state.1)?) {
+            (BasicStateSort::Accept, BasicStateSort::Accept) => BasicStateSort::Accept,
+            (BasicStateSort::Accept, BasicStateSort::Reject) => BasicStateSort::Accept,
+            (BasicStateSort::Reject, BasicStateSort::Accept) => BasicStateSort::Accept,
+            (BasicStateSort::Reject, BasicStateSort::Reject) => BasicStateSort::Reject,
+        })
+    }
+
+    fn mutation_transition_map(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        let (a, b) = (self.first, self.second);
+        a.mutation_transition_map(&mut state.0, character)?;
+        b.mutation_transition_map(&mut state.1, character)?;
+        Ok(())
+    }
+}
+
+/// A mutation automaton blueprint for the intersection (logical AND) of two mutation automata with [`BasicStateSort`].
+///
+/// This blueprint creates a mutation automaton that accepts a string only if **both** of the
+/// component mutation automata accept it, implementing the intersection of their recognized
+/// languages: `L(A) ∩ L(B)` with in-place state mutation.
+///
+/// # Boolean Logic
+///
+/// The state classification follows logical AND semantics:
+/// - `Accept AND Accept → Accept`
+/// - `Accept AND Reject → Reject`
+/// - `Reject AND Accept → Reject`
+/// - `Reject AND Reject → Reject`
+///
+/// # Type Parameters
+///
+/// * `A`, `B` - Component mutation automaton blueprints (must use [`BasicStateSort`])
+/// * `Alphabet` - Input symbol type (shared by both automata)
+/// * `ErrorType` - Error type (shared by both automata)
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) to create an instance from two component blueprint references.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MutationBasicIntersectionAutomatonBlueprint<'a, 'b, A, B, Alphabet, ErrorType>
+where
+    A: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    B: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    first: &'a A,
+    second: &'b B
+}
+
+impl<'a, 'b, A, B, Alphabet, ErrorType> MutationBasicIntersectionAutomatonBlueprint<'a, 'b, A, B, Alphabet, ErrorType>
+where
+    A: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    B: MutationAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    /// Creates a new mutation intersection automaton blueprint from two component blueprints.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - Reference to the first component mutation automaton blueprint
     /// * `second` - Reference to the second component mutation automaton blueprint
     ///
     /// # Returns
@@ -619,4 +1608,226 @@ where
         b.mutation_transition_map(&mut state.1, character)?;
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// A fluent builder for boolean combinations of automata with [`BasicStateSort`].
+///
+/// Expressions are built left to right starting from [`atom`](LangExpr::atom), with each
+/// operation wrapping the expression built so far:
+///
+/// * [`and`](Self::and) / [`and_expr`](Self::and_expr) - Intersection with another automaton or expression
+/// * [`or`](Self::or) / [`or_expr`](Self::or_expr) - Union with another automaton or expression
+/// * [`not`](Self::not) - Complement of the expression so far
+///
+/// [`build`](Self::build) returns the finished blueprint. Component automata are held by
+/// reference; each expression node owns its sub-expressions, so the built blueprint can be
+/// returned and stored freely for as long as the referenced components live. Internally each
+/// node evaluates through [`BasicIntersectionAutomatonBlueprint`], [`BasicUnionAutomatonBlueprint`]
+/// and [`ComplementAutomatonBlueprint`] over its sub-expressions.
+///
+/// # Type Parameters
+///
+/// * `E` - The blueprint type of the expression built so far
+#[derive(Debug, Clone, PartialEq)]
+pub struct LangExpr<E> {
+    expression: E
+}
+
+impl<'a, A> LangExpr<LangAtom<'a, A>>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    /// Starts an expression from a single component automaton.
+    pub fn atom(blueprint: &'a A) -> Self {
+        Self { expression: LangAtom { inner: blueprint } }
+    }
+}
+
+impl<E> LangExpr<E>
+where
+    E: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    /// Intersects the expression so far with another automaton.
+    pub fn and<B>(self, other: &B) -> LangExpr<LangAnd<E, LangAtom<'_, B>>>
+    where
+        B: DeterministicAutomatonBlueprint<Alphabet = E::Alphabet, StateSort = BasicStateSort, ErrorType = E::ErrorType>
+    {
+        self.and_expr(LangExpr::atom(other))
+    }
+
+    /// Unites the expression so far with another automaton.
+    pub fn or<B>(self, other: &B) -> LangExpr<LangOr<E, LangAtom<'_, B>>>
+    where
+        B: DeterministicAutomatonBlueprint<Alphabet = E::Alphabet, StateSort = BasicStateSort, ErrorType = E::ErrorType>
+    {
+        self.or_expr(LangExpr::atom(other))
+    }
+
+    /// Intersects the expression so far with another expression.
+    pub fn and_expr<F>(self, other: LangExpr<F>) -> LangExpr<LangAnd<E, F>>
+    where
+        F: DeterministicAutomatonBlueprint<Alphabet = E::Alphabet, StateSort = BasicStateSort, ErrorType = E::ErrorType>
+    {
+        LangExpr { expression: LangAnd { first: self.expression, second: other.expression } }
+    }
+
+    /// Unites the expression so far with another expression.
+    pub fn or_expr<F>(self, other: LangExpr<F>) -> LangExpr<LangOr<E, F>>
+    where
+        F: DeterministicAutomatonBlueprint<Alphabet = E::Alphabet, StateSort = BasicStateSort, ErrorType = E::ErrorType>
+    {
+        LangExpr { expression: LangOr { first: self.expression, second: other.expression } }
+    }
+
+    /// Complements the expression so far.
+    ///
+    /// As with [`complement`](crate::operations::complement), errors from partial
+    /// components are propagated rather than treated as acceptance.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> LangExpr<LangNot<E>> {
+        LangExpr { expression: LangNot { inner: self.expression } }
+    }
+
+    /// Finishes the expression, returning its blueprint.
+    pub fn build(self) -> E {
+        self.expression
+    }
+}
+
+/// A single component automaton in a [`LangExpr`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LangAtom<'a, A> {
+    inner: &'a A
+}
+
+/// The intersection of two sub-expressions in a [`LangExpr`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LangAnd<L, R> {
+    first: L,
+    second: R
+}
+
+/// The union of two sub-expressions in a [`LangExpr`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LangOr<L, R> {
+    first: L,
+    second: R
+}
+
+/// The complement of a sub-expression in a [`LangExpr`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LangNot<E> {
+    inner: E
+}
+
+impl<A> DeterministicAutomatonBlueprint for LangAtom<'_, A>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    type State = A::State;
+
+    type Alphabet = A::Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = A::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        self.inner.initial_state()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort,Self::ErrorType> {
+        self.inner.state_sort_map(state)
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        self.inner.transition_map(state, character)
+    }
+
+    fn transition_in_place(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        self.inner.transition_in_place(state, character)
+    }
+}
+
+impl<L, R, Alphabet, ErrorType> DeterministicAutomatonBlueprint for LangAnd<L, R>
+where
+    L: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    R: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    type State = (L::State, R::State);
+
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        BasicIntersectionAutomatonBlueprint::new(&self.first, &self.second).initial_state()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort,Self::ErrorType> {
+        BasicIntersectionAutomatonBlueprint::new(&self.first, &self.second).state_sort_map(state)
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        BasicIntersectionAutomatonBlueprint::new(&self.first, &self.second).transition_map(state, character)
+    }
+}
+
+impl<L, R, Alphabet, ErrorType> DeterministicAutomatonBlueprint for LangOr<L, R>
+where
+    L: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    R: DeterministicAutomatonBlueprint<Alphabet = Alphabet, StateSort = BasicStateSort, ErrorType = ErrorType>,
+    Alphabet: PartialEq
+{
+    type State = (L::State, R::State);
+
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        BasicUnionAutomatonBlueprint::new(&self.first, &self.second).initial_state()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort,Self::ErrorType> {
+        BasicUnionAutomatonBlueprint::new(&self.first, &self.second).state_sort_map(state)
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        BasicUnionAutomatonBlueprint::new(&self.first, &self.second).transition_map(state, character)
+    }
+}
+
+impl<E> DeterministicAutomatonBlueprint for LangNot<E>
+where
+    E: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>
+{
+    type State = E::State;
+
+    type Alphabet = E::Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    type ErrorType = E::ErrorType;
+
+    fn initial_state(&self) -> Self::State {
+        ComplementAutomatonBlueprint::new(&self.inner).initial_state()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort,Self::ErrorType> {
+        ComplementAutomatonBlueprint::new(&self.inner).state_sort_map(state)
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        ComplementAutomatonBlueprint::new(&self.inner).transition_map(state, character)
+    }
+
+    fn transition_in_place(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        ComplementAutomatonBlueprint::new(&self.inner).transition_in_place(state, character)
+    }
+}
@@ -0,0 +1,226 @@
+//! Helpers for checking blueprints against the invariants the framework assumes.
+//!
+//! Every blueprint is expected to be deterministic: the same state and symbol must always
+//! produce the same result. This module provides assertions for that invariant and a small
+//! seeded word generator, so a blueprint can be exercised on many inputs from an ordinary
+//! test or from inside a property-testing framework. [`check_against_oracle`] compares a
+//! blueprint with a reference predicate on a batch of words, and the
+//! [`recognizes!`](crate::recognizes) macro asserts expected classifications for a batch of
+//! words. It is available with the `testing` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint};
+//! use deterministic_automata::fn_automaton::FnBlueprint;
+//! use deterministic_automata::testing::{assert_deterministic, WordGenerator};
+//!
+//! // Accepts words over {'0', '1'} whose binary value is divisible by three
+//! let blueprint = FnBlueprint::new(
+//!     || 0u8,
+//!     |remainder| Ok(if *remainder == 0 { BasicStateSort::Accept } else { BasicStateSort::Reject }),
+//!     |remainder, digit: &char| match digit {
+//!         '0' => Ok((remainder * 2) % 3),
+//!         '1' => Ok((remainder * 2 + 1) % 3),
+//!         _ => Err(format!("Not a binary digit: {}", digit)),
+//!     },
+//! );
+//! let mut generator = WordGenerator::new(42);
+//!
+//! for _ in 0..100 {
+//!     let word = generator.next_word(&['0', '1'], 8);
+//!     let state = blueprint.characterise_partial(&word).unwrap().take_state();
+//!     for digit in ['0', '1', '2'] {
+//!         assert_deterministic(&blueprint, &state, &digit);
+//!     }
+//! }
+//! ```
+
+use std::fmt::Debug;
+
+use crate::{BasicStateSort, DeterministicAutomatonBlueprint, MutationAutomatonBlueprint};
+
+/// Asserts that [`transition_map`](DeterministicAutomatonBlueprint::transition_map) is deterministic
+/// at the given state and symbol.
+///
+/// The transition is computed twice; both calls must succeed with equal states, or both must fail.
+///
+/// # Panics
+///
+/// Panics if the two calls disagree.
+pub fn assert_deterministic<A>(a: &A, state: &A::State, symbol: &A::Alphabet)
+where
+    A: DeterministicAutomatonBlueprint,
+    A::State: PartialEq + Debug
+{
+    match (a.transition_map(state, symbol), a.transition_map(state, symbol)) {
+        (Ok(first), Ok(second)) => assert_eq!(first, second, "transition_map produced different states for the same input"),
+        (Err(_), Err(_)) => {},
+        (Ok(_), Err(_)) | (Err(_), Ok(_)) => panic!("transition_map succeeded and failed for the same input"),
+    }
+}
+
+/// Asserts that [`mutation_transition_map`](MutationAutomatonBlueprint::mutation_transition_map)
+/// is deterministic at the given state and symbol.
+///
+/// The mutation paradigm counterpart of [`assert_deterministic`]: the transition is applied to
+/// two copies of `state`, which must end up equal with both calls succeeding, or both calls must fail.
+///
+/// # Panics
+///
+/// Panics if the two calls disagree.
+pub fn assert_mutation_deterministic<A>(a: &A, state: &A::State, symbol: &A::Alphabet)
+where
+    A: MutationAutomatonBlueprint,
+    A::State: PartialEq + Debug
+{
+    let mut first = state.clone();
+    let mut second = state.clone();
+    match (a.mutation_transition_map(&mut first, symbol), a.mutation_transition_map(&mut second, symbol)) {
+        (Ok(()), Ok(())) => assert_eq!(first, second, "mutation_transition_map produced different states for the same input"),
+        (Err(_), Err(_)) => {},
+        (Ok(()), Err(_)) | (Err(_), Ok(())) => panic!("mutation_transition_map succeeded and failed for the same input"),
+    }
+}
+
+/// A seeded generator of random words over a supplied alphabet.
+///
+/// The same seed always produces the same sequence of words, so a failing word can be
+/// reproduced from the seed alone. When used inside a property-testing framework, let the
+/// framework choose the seed.
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) with a seed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordGenerator {
+    state: u64
+}
+
+impl WordGenerator {
+    /// Creates a new generator.
+    ///
+    /// # Parameters
+    ///
+    /// * `seed` - Determines the sequence of words produced
+    pub fn new(seed: u64) -> Self {
+        // xorshift has a fixed point at zero, so the seed is mixed with a non-zero constant
+        Self { state: seed ^ 0x9E37_79B9_7F4A_7C15 }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// Generates a word of length at most `max_length` with symbols drawn uniformly from `alphabet`.
+    ///
+    /// Returns the empty word if `alphabet` is empty.
+    pub fn next_word<Alphabet: Clone>(&mut self, alphabet: &[Alphabet], max_length: usize) -> Vec<Alphabet> {
+        if alphabet.is_empty() {
+            return Vec::new();
+        }
+        let length = self.below(max_length.saturating_add(1));
+        (0..length).map(|_| alphabet[self.below(alphabet.len())].clone()).collect()
+    }
+}
+
+/// Returns the words on which an automaton disagrees with a reference predicate.
+///
+/// Each word is classified with [`characterise`](DeterministicAutomatonBlueprint::characterise)
+/// and counted as a mismatch when acceptance differs from `oracle`. Mismatches are returned
+/// in the order they appear in `words`, so an empty result means the automaton agrees with
+/// the oracle on the whole batch. The first error encountered is returned instead.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+/// use deterministic_automata::testing::{check_against_oracle, WordGenerator};
+///
+/// let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+/// // Wrong on purpose: ignores the order of the symbols
+/// let same_count = |word: &[char]| {
+///     word.iter().filter(|c| **c == 'a').count() == word.iter().filter(|c| **c == 'b').count()
+/// };
+///
+/// let words = vec![vec!['a', 'b'], vec!['b', 'a'], vec!['a']];
+/// assert_eq!(check_against_oracle(&blueprint, same_count, &words).unwrap(), vec![vec!['b', 'a']]);
+/// ```
+pub fn check_against_oracle<A, F>(a: &A, oracle: F, words: &[Vec<A::Alphabet>]) -> Result<Vec<Vec<A::Alphabet>>, A::ErrorType>
+where
+    A: DeterministicAutomatonBlueprint<StateSort = BasicStateSort>,
+    A::Alphabet: Clone,
+    F: Fn(&[A::Alphabet]) -> bool
+{
+    let mut mismatches = Vec::new();
+    for word in words {
+        let accepted = a.characterise(word)? == BasicStateSort::Accept;
+        if accepted != oracle(word) {
+            mismatches.push(word.clone());
+        }
+    }
+    Ok(mismatches)
+}
+
+/// Asserts that a blueprint classifies each of a batch of words as expected.
+///
+/// Takes a blueprint and a braced list of `word => sort` pairs. Without a conversion, each
+/// word is a string literal turned into a `Vec<char>`; with a conversion given before the
+/// list, each word is passed to it to build the input. The expected sorts may be written as
+/// bare `Accept` and `Reject` for [`BasicStateSort`], or as any expression of the blueprint's
+/// sort type. Each word is classified with
+/// [`characterise`](DeterministicAutomatonBlueprint::characterise), panicking with the
+/// offending word on a mismatch, and with the word and the error on an error, so the
+/// blueprint's error type must implement [`Debug`].
+///
+/// # Example
+///
+/// ```
+/// use deterministic_automata::recognizes;
+/// use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterSort};
+/// use deterministic_automata::mod_counter_example::ModSumBlueprint;
+///
+/// recognizes!(CounterAutomatonBlueprint::new('a', 'b'), {
+///     "" => Accept,
+///     "ab" => Accept,
+///     "aab" => Reject,
+/// });
+///
+/// recognizes!(CounterAutomatonBlueprint::new('(', ')').detailed(), {
+///     "((" => CounterSort::PendingOpen(2),
+///     "()" => CounterSort::Balanced,
+/// });
+///
+/// // Generic alphabets take a conversion from the written word to the input
+/// recognizes!(ModSumBlueprint::new(3), |digits: &[u8]| digits.to_vec(), {
+///     &[1, 2] => Accept,
+///     &[1, 1] => Reject,
+/// });
+/// ```
+#[macro_export]
+macro_rules! recognizes {
+    ($blueprint:expr, { $($word:expr => $sort:expr),* $(,)? }) => {
+        $crate::recognizes!($blueprint, |word: &str| word.chars().collect::<::std::vec::Vec<char>>(), { $($word => $sort),* })
+    };
+    ($blueprint:expr, $conversion:expr, { $($word:expr => $sort:expr),* $(,)? }) => {{
+        #[allow(unused_imports)]
+        use $crate::BasicStateSort::{Accept, Reject};
+        let blueprint = &$blueprint;
+        let conversion = $conversion;
+        $(
+            let word = $word;
+            let description = format!("{:?}", word);
+            let input = conversion(word);
+            let sort = $crate::DeterministicAutomatonBlueprint::characterise(blueprint, &input)
+                .unwrap_or_else(|error| panic!("characterising {} failed: {:?}", description, error));
+            assert!(sort == $sort, "{} was not classified as {}", description, stringify!($sort));
+        )*
+    }};
+}
@@ -0,0 +1,191 @@
+//! Blueprints for recognizers whose transitions depend on the time between symbols.
+//!
+//! Protocol state machines often impose timeouts: a symbol arriving too long after the
+//! previous one is treated differently from the same symbol arriving promptly. The
+//! [`TimedAutomatonBlueprint`] trait passes each transition the time elapsed since the
+//! previous symbol, as measured by the caller. The automaton never reads a clock itself, so
+//! runs stay deterministic and can be replayed in tests with chosen durations.
+//!
+//! # Example
+//!
+//! ```
+//! use std::time::Duration;
+//! use deterministic_automata::{BasicStateSort, TimedAutomatonBlueprint};
+//!
+//! // A request must be answered within one second
+//! #[derive(Clone, Copy, PartialEq, Debug)]
+//! enum Exchange { Idle, Waiting, Answered, TimedOut }
+//!
+//! struct RequestResponse;
+//!
+//! impl TimedAutomatonBlueprint for RequestResponse {
+//!     type State = Exchange;
+//!     type Alphabet = char;
+//!     type StateSort = BasicStateSort;
+//!     type ErrorType = String;
+//!
+//!     fn initial_timed_state(&self) -> Self::State { Exchange::Idle }
+//!
+//!     fn timed_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+//!         Ok(BasicStateSort::from(*state == Exchange::Answered))
+//!     }
+//!
+//!     fn transition_timed(&self, state: &Self::State, character: &Self::Alphabet, elapsed: Duration) -> Result<Self::State, Self::ErrorType> {
+//!         Ok(match (state, character) {
+//!             (Exchange::Idle, 'q') => Exchange::Waiting,
+//!             (Exchange::Waiting, 'r') if elapsed <= Duration::from_secs(1) => Exchange::Answered,
+//!             (Exchange::Waiting, 'r') => Exchange::TimedOut,
+//!             _ => return Err(format!("Unexpected {} in {:?}", character, state)),
+//!         })
+//!     }
+//! }
+//!
+//! let prompt = [('q', Duration::ZERO), ('r', Duration::from_millis(200))];
+//! let late = [('q', Duration::ZERO), ('r', Duration::from_secs(3))];
+//! assert_eq!(RequestResponse.characterise_timed(&prompt).unwrap(), BasicStateSort::Accept);
+//! assert_eq!(RequestResponse.characterise_timed(&late).unwrap(), BasicStateSort::Reject);
+//! ```
+
+use std::time::Duration;
+
+/// A blueprint for automata whose transitions depend on the time elapsed between symbols.
+///
+/// # Associated Types
+///
+/// * `State` - The type representing internal automaton states. Must be `Clone`.
+/// * `Alphabet` - The type of input symbols. Must support equality comparison.
+/// * `StateSort` - The classification type for states (e.g., Accept/Reject).
+/// * `ErrorType` - The type used for error handling when states are invalid.
+///
+/// # Required Methods
+///
+/// * [`initial_timed_state`](Self::initial_timed_state) - Returns the starting state
+/// * [`timed_state_sort_map`](Self::timed_state_sort_map) - Classifies a state, with validation
+/// * [`transition_timed`](Self::transition_timed) - Defines timed state transitions, with validation
+///
+/// # Provided Methods
+///
+/// * [`characterise_timed`](Self::characterise_timed) - Processes an entire sequence of timed symbols
+/// * [`timed_automaton`](Self::timed_automaton) - Creates a runtime automaton instance
+pub trait TimedAutomatonBlueprint {
+    /// The type representing internal automaton states.
+    type State: Clone;
+
+    /// The type of input symbols that the automaton processes.
+    type Alphabet: PartialEq;
+
+    /// The classification type for states.
+    type StateSort;
+
+    /// The error type returned when state validation fails.
+    type ErrorType;
+
+    /// Returns the initial state of the automaton.
+    fn initial_timed_state(&self) -> Self::State;
+
+    /// Maps a state to its classification, with validation.
+    fn timed_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType>;
+
+    /// Defines the transition function, given the time elapsed since the previous symbol.
+    ///
+    /// For the first symbol, `elapsed` is measured from whatever starting point the caller
+    /// chooses, typically the moment the automaton was created.
+    fn transition_timed(&self, state: &Self::State, character: &Self::Alphabet, elapsed: Duration) -> Result<Self::State, Self::ErrorType>;
+
+    /// Processes an entire sequence of symbols, each paired with the time elapsed since the
+    /// previous one, and returns the final state classification.
+    fn characterise_timed(&self, events: &[(Self::Alphabet, Duration)]) -> Result<Self::StateSort, Self::ErrorType>
+    where
+        Self: Sized
+    {
+        let mut automaton = self.timed_automaton();
+        for (character, elapsed) in events {
+            automaton.update_state(character, *elapsed)?;
+        }
+        automaton.current_state_sort()
+    }
+
+    /// Creates a runtime automaton instance from this blueprint.
+    fn timed_automaton(&self) -> TimedAutomaton<'_, Self>
+    where
+        Self: Sized
+    {
+        TimedAutomaton::new(self)
+    }
+}
+
+/// A runtime instance of a timed automaton.
+///
+/// The caller drives it with symbols and the time elapsed since the previous one, for
+/// example as measured by [`Instant::elapsed`](std::time::Instant::elapsed) on an embedded
+/// clock.
+pub struct TimedAutomaton<'a, Blueprint: TimedAutomatonBlueprint> {
+    blueprint: &'a Blueprint,
+    current_state: Blueprint::State
+}
+
+/// Cloning a running automaton forks it: the clone shares the blueprint reference and
+/// continues independently from a clone of the current state.
+impl<Blueprint: TimedAutomatonBlueprint> Clone for TimedAutomaton<'_, Blueprint> {
+    fn clone(&self) -> Self {
+        Self {
+            blueprint: self.blueprint,
+            current_state: self.current_state.clone()
+        }
+    }
+}
+
+/// Shows only the current state, so the blueprint need not implement [`Debug`](std::fmt::Debug).
+impl<Blueprint> std::fmt::Debug for TimedAutomaton<'_, Blueprint>
+where
+    Blueprint: TimedAutomatonBlueprint,
+    Blueprint::State: std::fmt::Debug
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TimedAutomaton")
+            .field("current_state", &self.current_state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Blueprint: TimedAutomatonBlueprint> TimedAutomaton<'a, Blueprint> {
+    /// Creates a new timed automaton instance from a blueprint.
+    pub fn new(blueprint: &'a Blueprint) -> Self {
+        Self {
+            blueprint,
+            current_state: blueprint.initial_timed_state()
+        }
+    }
+
+    /// Returns the classification of the current state.
+    pub fn current_state_sort(&self) -> Result<Blueprint::StateSort, Blueprint::ErrorType> {
+        self.blueprint.timed_state_sort_map(&self.current_state)
+    }
+
+    /// Processes a single input symbol that arrived `elapsed` after the previous one.
+    pub fn update_state(&mut self, character: &Blueprint::Alphabet, elapsed: Duration) -> Result<(), Blueprint::ErrorType> {
+        self.current_state = self.blueprint.transition_timed(&self.current_state, character, elapsed)?;
+        Ok(())
+    }
+
+    /// Processes a single timed input symbol and returns the new state classification.
+    pub fn update_sort_state(&mut self, character: &Blueprint::Alphabet, elapsed: Duration) -> Result<Blueprint::StateSort, Blueprint::ErrorType> {
+        self.update_state(character, elapsed)?;
+        self.current_state_sort()
+    }
+
+    /// Returns the automaton to the blueprint's initial state.
+    pub fn reset(&mut self) {
+        self.current_state = self.blueprint.initial_timed_state();
+    }
+
+    /// Returns a reference to the current state.
+    pub fn view_state(&self) -> &Blueprint::State {
+        &self.current_state
+    }
+
+    /// Consumes the automaton and returns the current state.
+    pub fn take_state(self) -> Blueprint::State {
+        self.current_state
+    }
+}
@@ -0,0 +1,103 @@
+//! Sliding-window blueprints that remember the most recent input symbols.
+//!
+//! Many recognizers, such as "ends with a given pattern", only depend on the last few
+//! symbols read. [`WindowBlueprint`] captures this whole class: its state is a bounded
+//! [`VecDeque`] holding the last `k` symbols, and a caller-supplied predicate classifies each
+//! window. This avoids hand-writing a state enum for every suffix pattern, and shows how
+//! states can carry arbitrary data.
+//!
+//! # Example
+//!
+//! ```
+//! use deterministic_automata::{BasicStateSort, InfallibleAutomatonBlueprint};
+//! use deterministic_automata::window_automaton::WindowBlueprint;
+//!
+//! let ends_with_ab = WindowBlueprint::new(2, |window| {
+//!     if window.iter().eq(['a', 'b'].iter()) { BasicStateSort::Accept } else { BasicStateSort::Reject }
+//! });
+//!
+//! assert_eq!(ends_with_ab.infallible_characterise(&['c', 'a', 'b']), BasicStateSort::Accept);
+//! assert_eq!(ends_with_ab.infallible_characterise(&['a', 'b', 'c']), BasicStateSort::Reject);
+//! ```
+
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+use crate::{BasicStateSort, InfallibleAutomatonBlueprint};
+
+/// A blueprint whose state is a window of the last `k` symbols, classified by a predicate.
+///
+/// Each transition appends the symbol read and drops the oldest symbol once the window holds
+/// more than `k`. Before `k` symbols have been read the window is shorter than `k`, so the
+/// predicate should handle short windows.
+///
+/// Transitions never fail, so this implements [`InfallibleAutomatonBlueprint`] and is
+/// therefore a deterministic blueprint with [`Infallible`](std::convert::Infallible) errors.
+///
+/// # Type Parameters
+///
+/// * `Alphabet` - Input symbol type, cloned into the window
+/// * `F` - Predicate classifying a window
+///
+/// # Construction
+///
+/// Use [`new`](Self::new) with the window length and predicate.
+#[derive(Clone)]
+pub struct WindowBlueprint<Alphabet, F>
+where
+    F: Fn(&VecDeque<Alphabet>) -> BasicStateSort
+{
+    length: usize,
+    predicate: F,
+    alphabet: PhantomData<fn(&Alphabet)>
+}
+
+impl<Alphabet, F> WindowBlueprint<Alphabet, F>
+where
+    F: Fn(&VecDeque<Alphabet>) -> BasicStateSort
+{
+    /// Creates a new sliding-window blueprint.
+    ///
+    /// # Parameters
+    ///
+    /// * `length` - The number of most recent symbols kept in the window
+    /// * `predicate` - Classifies the current window
+    pub fn new(length: usize, predicate: F) -> Self {
+        Self {
+            length,
+            predicate,
+            alphabet: PhantomData
+        }
+    }
+}
+
+impl<Alphabet, F> InfallibleAutomatonBlueprint for WindowBlueprint<Alphabet, F>
+where
+    Alphabet: Clone + PartialEq,
+    F: Fn(&VecDeque<Alphabet>) -> BasicStateSort
+{
+    type State = VecDeque<Alphabet>;
+
+    type Alphabet = Alphabet;
+
+    type StateSort = BasicStateSort;
+
+    fn initial(&self) -> Self::State {
+        VecDeque::with_capacity(self.length)
+    }
+
+    fn sort(&self, state: &Self::State) -> Self::StateSort {
+        (self.predicate)(state)
+    }
+
+    fn transition(&self, state: &Self::State, character: &Self::Alphabet) -> Self::State {
+        let mut window = state.clone();
+        if self.length > 0 {
+            if window.len() == self.length {
+                window.pop_front();
+            }
+            window.push_back(character.clone());
+        }
+        window
+    }
+}
@@ -1,5 +1,7 @@
+use std::ops::ControlFlow;
+
 use deterministic_automata::*;
-use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterState, TolerantCounterState};
 
 #[test]
 fn deterministic_automaton_creation_and_update_sort_state() -> Result<(), String> {
@@ -54,6 +56,7 @@ fn basic_state_sort_debug_format() {
 }
 
 #[test]
+#[allow(clippy::clone_on_copy)]
 fn basic_state_sort_clone_copy() {
     let accept = BasicStateSort::Accept;
     let accept_clone = accept.clone();
@@ -64,6 +67,7 @@ fn basic_state_sort_clone_copy() {
 }
 
 #[test]
+#[allow(clippy::let_unit_value, clippy::unit_cmp)]
 fn update_state_returns_unit() -> Result<(), String> {
     let blueprint = CounterAutomatonBlueprint::new('x', 'y');
     let mut automaton = DeterministicAutomaton::new(&blueprint);
@@ -73,4 +77,477 @@ fn update_state_returns_unit() -> Result<(), String> {
     assert_eq!(automaton.current_state_sort()?, BasicStateSort::Reject);
     
     Ok(())
-}
\ No newline at end of file
+}
+
+#[test]
+fn characterise_controlled_stops_on_break() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let input: Vec<char> = "abba".chars().collect();
+
+    let mut seen = Vec::new();
+    let sort = blueprint.characterise_controlled(&input, |sort| {
+        seen.push(*sort);
+        if *sort == BasicStateSort::Accept { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+    })?;
+
+    assert_eq!(sort, BasicStateSort::Accept);
+    assert_eq!(seen, vec![BasicStateSort::Reject, BasicStateSort::Accept]);
+
+    Ok(())
+}
+
+#[test]
+fn characterise_early_trap_skips_input_after_trap() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let is_trap = |state: &CounterState| matches!(state, CounterState::Reject);
+
+    // The second 'b' enters the trap, so the last two symbols are never read
+    let checks = std::cell::Cell::new(0);
+    let sort = blueprint.characterise_early_trap(&['a', 'b', 'b', 'a', 'b'], |state| {
+        checks.set(checks.get() + 1);
+        is_trap(state)
+    });
+    assert_eq!(sort?, BasicStateSort::Reject);
+    assert_eq!(checks.get(), 4);
+
+    // Without reaching a trap the result matches characterise
+    for word in [vec![], vec!['a', 'b'], vec!['a', 'a', 'b']] {
+        assert_eq!(blueprint.characterise_early_trap(&word, is_trap)?, blueprint.characterise(&word)?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn characterise_controlled_runs_to_completion() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    let mut steps = 0;
+    let sort = blueprint.characterise_controlled(&['a', 'a', 'b'], |_| {
+        steps += 1;
+        ControlFlow::Continue(())
+    })?;
+    assert_eq!(sort, BasicStateSort::Reject);
+    assert_eq!(steps, 3);
+
+    let empty = blueprint.characterise_controlled(&[], |_| ControlFlow::Break(()))?;
+    assert_eq!(empty, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+fn counter_value(automaton: &DeterministicAutomaton<'_, CounterAutomatonBlueprint<char>>) -> Option<usize> {
+    match automaton.view_state() {
+        CounterState::Start(n) | CounterState::End(n) => Some(*n),
+        CounterState::Reject => None,
+    }
+}
+
+#[test]
+fn view_state_interleaves_with_update_state() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let mut automaton = DeterministicAutomaton::new(&blueprint);
+
+    let mut counters = Vec::new();
+    for character in ['a', 'a', 'b'] {
+        let state = automaton.view_state();
+        counters.push(matches!(state, CounterState::Start(_)));
+        automaton.update_state(&character)?;
+        assert!(counter_value(&automaton).is_some());
+    }
+
+    assert_eq!(counters, vec![true, true, true]);
+    assert_eq!(counter_value(&automaton), Some(1));
+
+    Ok(())
+}
+
+#[test]
+fn characterise_zip_aligns_symbols_and_sorts() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    assert_eq!(blueprint.characterise_zip(&[])?, vec![]);
+    assert_eq!(blueprint.characterise_zip(&['a', 'a', 'b', 'b'])?, vec![
+        ('a', BasicStateSort::Reject),
+        ('a', BasicStateSort::Reject),
+        ('b', BasicStateSort::Reject),
+        ('b', BasicStateSort::Accept),
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn characterise_counted_counts_every_transition() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    assert_eq!(blueprint.characterise_counted(&[])?, (BasicStateSort::Accept, 0));
+    assert_eq!(blueprint.characterise_counted(&['a', 'a', 'b'])?, (BasicStateSort::Reject, 3));
+
+    Ok(())
+}
+
+#[test]
+fn state_path_records_counter_rising_and_falling() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    let path = blueprint.state_path(&['a', 'a', 'b', 'b', 'b'])?;
+    assert_eq!(path.len(), 6);
+    assert!(matches!(path[0], CounterState::Start(0)));
+    assert!(matches!(path[2], CounterState::Start(2)));
+    assert!(matches!(path[3], CounterState::End(1)));
+    assert!(matches!(path[4], CounterState::End(0)));
+    assert!(matches!(path[5], CounterState::Reject));
+
+    assert_eq!(blueprint.state_path(&[])?.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn deterministic_automaton_try_transition_does_not_consume() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let mut automaton = DeterministicAutomaton::new(&blueprint);
+    automaton.update_state(&'a')?;
+
+    assert_eq!(automaton.try_transition(&'b')?, BasicStateSort::Accept);
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Reject);
+    assert_eq!(automaton.update_sort_state(&'b')?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn accepts_empty_classifies_initial_state() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    assert!(blueprint.accepts_empty()?);
+    assert!(!blueprint.with_initial(CounterState::Start(1)).accepts_empty()?);
+    assert!(!CounterAutomatonBlueprint::with_tolerance('a', 'b', 1..=2).accepts_empty()?);
+
+    Ok(())
+}
+
+#[test]
+fn characterise_records_reads_each_record_from_initial_state() {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let log: Vec<Vec<char>> = ["ab", "aab", "", "ba", "aabb"].iter().map(|record| record.chars().collect()).collect();
+
+    let results = blueprint.characterise_records(log.iter().map(Vec::as_slice));
+    assert_eq!(results, vec![
+        Ok(BasicStateSort::Accept),
+        Ok(BasicStateSort::Reject),
+        Ok(BasicStateSort::Accept),
+        Ok(BasicStateSort::Reject),
+        Ok(BasicStateSort::Accept),
+    ]);
+}
+
+#[test]
+fn deterministic_automaton_reset_returns_to_initial_state() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let mut automaton = DeterministicAutomaton::new(&blueprint);
+    automaton.update_state(&'b')?;
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Reject);
+
+    automaton.reset();
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Accept);
+    assert_eq!(automaton.update_sort_state(&'a')?, BasicStateSort::Reject);
+
+    Ok(())
+}
+
+#[test]
+fn characterise_refs_matches_characterise() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    for word in ["", "ab", "aabb", "aab", "ba"] {
+        let owned: Vec<char> = word.chars().collect();
+        let references: Vec<&char> = owned.iter().collect();
+        assert_eq!(blueprint.characterise_refs(&references)?, blueprint.characterise(&owned)?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn characterise_rev_reads_back_to_front() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    for word in ["", "ab", "ba", "aabb", "bbaa", "abab"] {
+        let input: Vec<char> = word.chars().collect();
+        let reversed: Vec<char> = word.chars().rev().collect();
+        assert_eq!(blueprint.characterise_rev(&input)?, blueprint.characterise(&reversed)?);
+    }
+    assert_eq!(blueprint.characterise_rev(&['b', 'b', 'a', 'a'])?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn validate_state_defaults_to_state_sort_map() {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    assert_eq!(blueprint.validate_state(&CounterState::Start(3)), Ok(()));
+    assert_eq!(blueprint.validate_state(&CounterState::Reject), Ok(()));
+
+    let even_only = fn_automaton::FnBlueprint::new(
+        || 0,
+        |state: &u32| if state.is_multiple_of(2) { Ok(BasicStateSort::Accept) } else { Err(format!("Odd state {}", state)) },
+        |state: &u32, _: &char| Ok(state + 2),
+    );
+    assert!(even_only.validate_state(&1).is_err());
+    assert!(even_only.mutation_validate_state(&1).is_err());
+}
+
+#[test]
+fn validate_state_override_is_forwarded_by_wrappers() {
+    struct NonNegative;
+
+    impl DeterministicAutomatonBlueprint for NonNegative {
+        type State = i32;
+        type Alphabet = char;
+        type StateSort = BasicStateSort;
+        type ErrorType = String;
+
+        fn initial_state(&self) -> Self::State {
+            0
+        }
+
+        fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+            Ok(if *state == 0 { BasicStateSort::Accept } else { BasicStateSort::Reject })
+        }
+
+        fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+            Ok(if *character == '+' { state + 1 } else { state - 1 })
+        }
+
+        fn validate_state(&self, state: &Self::State) -> Result<(), Self::ErrorType> {
+            if *state >= 0 { Ok(()) } else { Err(format!("Negative state {}", state)) }
+        }
+    }
+
+    let blueprint = NonNegative;
+    assert_eq!(blueprint.state_sort_map(&-1), Ok(BasicStateSort::Reject));
+    assert_eq!(blueprint.validate_state(&-1), Err("Negative state -1".to_string()));
+    assert_eq!(blueprint.mutation_validate_state(&-1), Err("Negative state -1".to_string()));
+    assert_eq!(blueprint.with_initial(2).validate_state(&-1), Err("Negative state -1".to_string()));
+    assert_eq!(operations::MaxLengthBlueprint::new(&blueprint, 3).validate_state(&(-1, 0)), Err("Negative state -1".to_string()));
+    assert_eq!(operations::MinLengthBlueprint::new(&blueprint, 3).validate_state(&(-1, 0)), Err("Negative state -1".to_string()));
+    let closure = operations::prefix_closure(&blueprint, &[]).unwrap();
+    assert_eq!(closure.validate_state(&-1), Err("Negative state -1".to_string()));
+}
+
+#[test]
+fn characterise_partial_resumes_from_prefix() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    let mut automaton = blueprint.characterise_partial(&['a', 'a', 'b'])?;
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Reject);
+    assert_eq!(automaton.update_sort_state(&'b')?, BasicStateSort::Accept);
+
+    assert!(blueprint.characterise_partial(&['a', 'b']).is_ok());
+    assert!(CounterAutomatonBlueprint::new('a', 'b').characterise_partial(&['c']).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn deterministic_automaton_clone_forks_independently() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let mut automaton = blueprint.automaton();
+    automaton.update_state(&'a')?;
+
+    let mut fork = automaton.clone();
+    assert_eq!(fork.update_sort_state(&'b')?, BasicStateSort::Accept);
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Reject);
+
+    assert_eq!(automaton.update_sort_state(&'a')?, BasicStateSort::Reject);
+    assert_eq!(fork.current_state_sort()?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn deterministic_automaton_equality_compares_states() -> Result<(), String> {
+    let blueprint = AtMostTwo;
+    let mut automaton = blueprint.automaton();
+    let fork = automaton.clone();
+    assert!(automaton == fork);
+
+    automaton.update_state(&'a')?;
+    assert!(automaton != fork);
+
+    Ok(())
+}
+
+// Counts symbols up to three, accepting at most two
+struct AtMostTwo;
+
+impl DeterministicAutomatonBlueprint for AtMostTwo {
+    type State = u8;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        0
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(if *state <= 2 { BasicStateSort::Accept } else { BasicStateSort::Reject })
+    }
+
+    fn transition_map(&self, state: &Self::State, _: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok((*state + 1).min(3))
+    }
+}
+
+#[test]
+fn sort_histogram_counts_every_prefix() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    let histogram = blueprint.sort_histogram(&['a', 'b', 'a', 'b'])?;
+    assert_eq!(histogram.get(&BasicStateSort::Accept), Some(&2));
+    assert_eq!(histogram.get(&BasicStateSort::Reject), Some(&3));
+
+    let empty = blueprint.sort_histogram(&[])?;
+    assert_eq!(empty.len(), 1);
+    assert_eq!(empty.get(&BasicStateSort::Accept), Some(&1));
+
+    let detailed = CounterAutomatonBlueprint::new('a', 'b').detailed();
+    let histogram = detailed.mutation_sort_histogram(&['a', 'a', 'b', 'b'])?;
+    assert_eq!(histogram.values().sum::<usize>(), 5);
+    assert_eq!(histogram.len(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn basic_state_sort_round_trips_through_bool() {
+    assert_eq!(BasicStateSort::from(true), BasicStateSort::Accept);
+    assert_eq!(BasicStateSort::from(false), BasicStateSort::Reject);
+    assert!(bool::from(BasicStateSort::Accept));
+    assert!(!bool::from(BasicStateSort::Reject));
+
+    let accepted: bool = CounterAutomatonBlueprint::new('a', 'b').characterise(&['a', 'b']).unwrap().into();
+    assert!(accepted);
+}
+
+#[test]
+fn trace_diff_finds_first_disagreement() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let (accept, reject) = (BasicStateSort::Accept, BasicStateSort::Reject);
+
+    assert_eq!(blueprint.trace_diff(&['a', 'a', 'b', 'b'], &[reject, reject, reject, accept])?, None);
+    assert_eq!(blueprint.trace_diff(&['a', 'b'], &[reject, reject])?, Some(1));
+    assert_eq!(blueprint.trace_diff(&['b', 'a'], &[accept])?, Some(0));
+
+    // Only the common prefix is compared
+    assert_eq!(blueprint.trace_diff(&['a', 'b'], &[reject])?, None);
+    assert_eq!(blueprint.trace_diff(&['a'], &[reject, accept, accept])?, None);
+    assert_eq!(blueprint.trace_diff(&[], &[accept])?, None);
+
+    Ok(())
+}
+
+#[test]
+fn characterise_trace_record_keeps_input_and_sorts() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    let trace = blueprint.characterise_trace_record(&['a', 'a', 'b', 'b'])?;
+    assert_eq!(trace.symbols, vec!['a', 'a', 'b', 'b']);
+    assert_eq!(trace.sorts, blueprint.characterise_zip(&['a', 'a', 'b', 'b'])?.into_iter().map(|(_, sort)| sort).collect::<Vec<_>>());
+    assert_eq!(trace.final_sort(), &BasicStateSort::Accept);
+
+    let empty = blueprint.characterise_trace_record(&[])?;
+    assert_eq!(empty, RunTrace { initial: BasicStateSort::Accept, symbols: vec![], sorts: vec![] });
+    assert_eq!(empty.final_sort(), &blueprint.characterise(&[])?);
+
+    let odd = CounterAutomatonBlueprint::with_tolerance('a', 'b', 1..=1).characterise_trace_record(&[])?;
+    assert_eq!(odd.final_sort(), &BasicStateSort::Reject);
+
+    Ok(())
+}
+
+#[test]
+fn accepting_snapshots_keep_only_accepting_prefixes() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    let snapshots = blueprint.accepting_snapshots(&['a', 'b', 'a'])?;
+    assert_eq!(snapshots, vec![(0, CounterState::Start(0)), (2, CounterState::End(0))]);
+
+    let tolerant = CounterAutomatonBlueprint::with_tolerance('a', 'b', 1..=2);
+    let snapshots = tolerant.accepting_snapshots(&['a', 'a', 'a', 'b'])?;
+    assert_eq!(snapshots, vec![(1, TolerantCounterState::with_balance(1)), (2, TolerantCounterState::with_balance(2)), (4, TolerantCounterState::with_balance(2))]);
+
+    assert_eq!(blueprint.accepting_snapshots(&['b'])?, vec![(0, CounterState::Start(0))]);
+
+    Ok(())
+}
+
+#[test]
+fn basic_state_sort_defaults_to_reject() {
+    assert_eq!(BasicStateSort::default(), BasicStateSort::Reject);
+
+    #[derive(Default)]
+    struct Verdicts {
+        first: BasicStateSort,
+        second: BasicStateSort,
+    }
+    let verdicts = Verdicts::default();
+    assert_eq!((verdicts.first, verdicts.second), (BasicStateSort::Reject, BasicStateSort::Reject));
+}
+
+#[test]
+fn tri_state_sort_embeds_basic_sorts() {
+    assert_eq!(TriStateSort::from(BasicStateSort::Accept), TriStateSort::Accept);
+    assert_eq!(TriStateSort::from(BasicStateSort::Reject), TriStateSort::Reject);
+    assert_eq!(TriStateSort::Unknown.negate(), TriStateSort::Unknown);
+}
+
+#[test]
+fn validation_error_wraps_automaton_errors_with_question_mark() {
+    use deterministic_automata::mod_counter_example::ModSumBlueprint;
+
+    fn validate_then_step(blueprint: &ModSumBlueprint, word: &[u8], next: u8) -> Result<u64, ValidationError<String>> {
+        blueprint.validate(word)?;
+        let state = blueprint.transition_map(&blueprint.initial_state(), &next)?;
+        Ok(state)
+    }
+
+    let blueprint = ModSumBlueprint::new(3);
+    assert_eq!(validate_then_step(&blueprint, &[1, 2], 2), Ok(2));
+    assert_eq!(validate_then_step(&blueprint, &[1], 2), Err(ValidationError::Rejected));
+    assert!(matches!(validate_then_step(&blueprint, &[1, 2], 12), Err(ValidationError::Inner(_))));
+}
+
+#[test]
+fn characterise_decoded_separates_decode_and_run_errors() {
+    use deterministic_automata::mod_counter_example::ModSumBlueprint;
+
+    // Hex digits decode, but the digit-sum automaton only accepts decimal ones
+    let hex = |c: &char| c.to_digit(16).map(|digit| digit as u8).ok_or(*c);
+    let blueprint = ModSumBlueprint::new(3);
+
+    assert_eq!(blueprint.characterise_decoded(&['1', '2'], hex), Ok(BasicStateSort::Accept));
+    assert_eq!(blueprint.characterise_decoded(&['1', 'g', 'a'], hex), Err(DecodeOrRun::Decode('g')));
+    assert_eq!(
+        blueprint.characterise_decoded(&['1', 'a', 'g'], hex),
+        Err(DecodeOrRun::Run("Not a decimal digit: 10".to_string()))
+    );
+}
+
+#[test]
+fn characterise_decoded_reads_utf8_sequences() {
+    let blueprint = CounterAutomatonBlueprint::new('«', '»');
+    let utf8 = |bytes: &&[u8]| std::str::from_utf8(bytes).map(|s| s.chars().next().unwrap_or_default());
+
+    let balanced: Vec<&[u8]> = vec!["«".as_bytes(), "»".as_bytes()];
+    assert_eq!(blueprint.characterise_decoded(&balanced, utf8), Ok(BasicStateSort::Accept));
+
+    let malformed: Vec<&[u8]> = vec!["«".as_bytes(), &[0xC2]];
+    let error = blueprint.characterise_decoded(&malformed, utf8).unwrap_err();
+    assert!(matches!(error, DecodeOrRun::Decode(_)));
+    assert!(error.to_string().starts_with("decode error: "));
+}
@@ -1,5 +1,5 @@
 use deterministic_automata::*;
-use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterState};
+use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterOverflowPolicy, CounterSort, CounterState, TolerantCounterState};
 
 fn str_to_vec_char(s: &str) -> Vec<char> {
     s.chars().collect()
@@ -55,12 +55,8 @@ fn counter_automaton_large_inputs() -> Result<(), String> {
         large_valid.push('a');
         large_invalid.push('a');
     }
-    for _ in 0..large_n {
-        large_valid.push('b');
-    }
-    for _ in 0..(large_n - 1) {
-        large_invalid.push('b');
-    }
+    large_valid.extend(std::iter::repeat_n('b', large_n));
+    large_invalid.extend(std::iter::repeat_n('b', large_n - 1));
 
     assert_eq!(blueprint.characterise(&large_valid)?, BasicStateSort::Accept);
     assert_eq!(blueprint.characterise(&large_invalid)?, BasicStateSort::Reject);
@@ -129,4 +125,130 @@ fn counter_automaton_state_classification() -> Result<(), String> {
     assert_eq!(blueprint.state_sort_map(&CounterState::Reject)?, BasicStateSort::Reject);
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[test]
+fn counter_automaton_zero_tolerance() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::with_tolerance('a', 'b', 0..=0);
+
+    assert_eq!(blueprint.characterise(&str_to_vec_char(""))?, BasicStateSort::Accept);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("aabb"))?, BasicStateSort::Accept);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("ba"))?, BasicStateSort::Accept);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("abab"))?, BasicStateSort::Accept);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("aab"))?, BasicStateSort::Reject);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("abb"))?, BasicStateSort::Reject);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("acb"))?, BasicStateSort::Reject);
+
+    Ok(())
+}
+
+#[test]
+fn counter_automaton_range_tolerance() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::with_tolerance('a', 'b', 0..=2);
+
+    assert_eq!(blueprint.characterise(&str_to_vec_char("ab"))?, BasicStateSort::Accept);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("aab"))?, BasicStateSort::Accept);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("aa"))?, BasicStateSort::Accept);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("baaa"))?, BasicStateSort::Accept);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("aaa"))?, BasicStateSort::Reject);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("b"))?, BasicStateSort::Reject);
+
+    Ok(())
+}
+
+#[test]
+fn counter_automaton_tolerance_state() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::with_tolerance('a', 'b', -1..=1);
+
+    assert_eq!(blueprint.initial_state().balance(), Some(0));
+    assert_eq!(blueprint.transition_map(&TolerantCounterState::with_balance(0), &'b')?.balance(), Some(-1));
+    assert_eq!(blueprint.transition_map(&TolerantCounterState::with_balance(-1), &'a')?.balance(), Some(0));
+    assert_eq!(blueprint.transition_map(&TolerantCounterState::with_balance(0), &'c')?, TolerantCounterState::REJECT);
+    assert_eq!(blueprint.state_sort_map(&TolerantCounterState::with_balance(-1))?, BasicStateSort::Accept);
+    assert_eq!(blueprint.state_sort_map(&TolerantCounterState::with_balance(-2))?, BasicStateSort::Reject);
+    assert_eq!(blueprint.state_sort_map(&TolerantCounterState::REJECT)?, BasicStateSort::Reject);
+
+    Ok(())
+}
+
+#[test]
+fn counter_automaton_detailed_sorts() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b').detailed();
+
+    assert_eq!(blueprint.characterise(&str_to_vec_char(""))?, CounterSort::Balanced);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("aab"))?, CounterSort::PendingOpen(1));
+    assert_eq!(blueprint.characterise(&str_to_vec_char("aaa"))?, CounterSort::PendingOpen(3));
+    assert_eq!(blueprint.characterise(&str_to_vec_char("aabb"))?, CounterSort::Balanced);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("abb"))?, CounterSort::Rejected);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("ca"))?, CounterSort::Rejected);
+
+    Ok(())
+}
+
+#[test]
+fn counter_automaton_detailed_tolerance_reports_pending_close() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::with_tolerance('a', 'b', 0..=0).detailed();
+
+    assert_eq!(blueprint.characterise(&str_to_vec_char("bba"))?, CounterSort::PendingClose(1));
+    assert_eq!(blueprint.characterise(&str_to_vec_char("baa"))?, CounterSort::PendingOpen(1));
+    assert_eq!(blueprint.characterise(&str_to_vec_char("ba"))?, CounterSort::Balanced);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("bac"))?, CounterSort::Rejected);
+
+    Ok(())
+}
+
+#[test]
+fn counter_automaton_detailed_extreme_balances_fit_usize() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::with_tolerance('a', 'b', 0..=0).detailed();
+    let saturated = |balance: i64| usize::try_from(balance.unsigned_abs()).unwrap_or(usize::MAX);
+
+    assert_eq!(blueprint.state_sort_map(&TolerantCounterState::with_balance(i64::MIN))?, CounterSort::PendingClose(saturated(i64::MIN)));
+    assert_eq!(blueprint.state_sort_map(&TolerantCounterState::with_balance(i64::MAX))?, CounterSort::PendingOpen(saturated(i64::MAX)));
+
+    Ok(())
+}
+
+#[test]
+fn counter_automaton_overflow_errors_by_default() {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    assert!(matches!(blueprint.transition_map(&CounterState::Start(usize::MAX - 1), &'a'), Ok(CounterState::Start(n)) if n == usize::MAX));
+    assert_eq!(blueprint.transition_map(&CounterState::Start(usize::MAX), &'a').map(|_| ()), Err("Counter overflow".to_string()));
+
+    let tolerant = CounterAutomatonBlueprint::with_tolerance('a', 'b', 0..=0);
+    assert!(tolerant.transition_map(&TolerantCounterState::with_balance(i64::MAX), &'a').is_err());
+    assert!(tolerant.transition_map(&TolerantCounterState::with_balance(i64::MIN), &'b').is_err());
+}
+
+#[test]
+fn counter_automaton_overflow_saturates_when_configured() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b').with_overflow_policy(CounterOverflowPolicy::Saturate);
+
+    assert!(matches!(blueprint.transition_map(&CounterState::Start(usize::MAX), &'a')?, CounterState::Start(n) if n == usize::MAX));
+    let resumed = blueprint.with_initial(CounterState::Start(usize::MAX));
+    assert_eq!(resumed.characterise(&str_to_vec_char("aab"))?, BasicStateSort::Reject);
+
+    let tolerant = CounterAutomatonBlueprint::with_tolerance('a', 'b', 0..=i64::MAX).with_overflow_policy(CounterOverflowPolicy::Saturate);
+    assert_eq!(tolerant.transition_map(&TolerantCounterState::with_balance(i64::MAX), &'a')?.balance(), Some(i64::MAX));
+    assert_eq!(tolerant.transition_map(&TolerantCounterState::with_balance(i64::MIN), &'b')?.balance(), Some(i64::MIN));
+
+    Ok(())
+}
+
+#[test]
+fn cost_characterise_sums_costs_along_the_run() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let cost = |c: &char| match c { 'a' => 1, 'b' => 2, _ => 10 };
+
+    assert_eq!(blueprint.cost_characterise(&[], cost)?, (BasicStateSort::Accept, 0));
+    assert_eq!(blueprint.cost_characterise(&str_to_vec_char("aaabbb"), cost)?, (BasicStateSort::Accept, 9));
+    assert_eq!(blueprint.cost_characterise(&str_to_vec_char("aac"), cost)?, (BasicStateSort::Reject, 12));
+
+    let saturating = blueprint.cost_characterise(&str_to_vec_char("ab"), |_| u64::MAX)?;
+    assert_eq!(saturating, (BasicStateSort::Accept, u64::MAX));
+
+    let tolerant = CounterAutomatonBlueprint::with_tolerance('a', 'b', 0..=0);
+    assert_eq!(tolerant.cost_characterise(&str_to_vec_char("ba"), cost)?, (BasicStateSort::Accept, 3));
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use deterministic_automata::*;
+use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+use deterministic_automata::product_automaton::BasicUnionAutomatonBlueprint;
+
+#[test]
+fn counter_example_is_accepted() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let example = blueprint.example_accepted().unwrap();
+
+    assert_eq!(example, Vec::<char>::new());
+    assert_eq!(blueprint.characterise(&example)?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn counter_tolerance_examples_reach_range() -> Result<(), String> {
+    for range in [0..=0, 2..=5, -3..=-1, -4..=4] {
+        let blueprint = CounterAutomatonBlueprint::with_tolerance('a', 'b', range.clone());
+        let example = blueprint.example_accepted().unwrap();
+        assert_eq!(blueprint.characterise(&example)?, BasicStateSort::Accept, "range {:?}", range);
+    }
+
+    assert_eq!(CounterAutomatonBlueprint::with_tolerance('a', 'b', 2..=5).example_accepted(), Some(vec!['a', 'a']));
+    let empty_range = std::ops::RangeInclusive::new(3, 1);
+    assert_eq!(CounterAutomatonBlueprint::with_tolerance('a', 'b', empty_range).example_accepted(), None);
+
+    Ok(())
+}
+
+#[test]
+fn counter_tolerance_examples_are_bounded() -> Result<(), String> {
+    use deterministic_automata::counter_automaton_example::MAX_EXAMPLE_LENGTH;
+
+    let far = CounterAutomatonBlueprint::with_tolerance('(', ')', i64::MAX..=i64::MAX);
+    assert_eq!(far.example_accepted(), None);
+    let far_negative = CounterAutomatonBlueprint::with_tolerance('(', ')', i64::MIN..=i64::MIN);
+    assert_eq!(far_negative.example_accepted(), None);
+
+    let limit = i64::try_from(MAX_EXAMPLE_LENGTH).unwrap();
+    let at_limit = CounterAutomatonBlueprint::with_tolerance('(', ')', limit..=limit);
+    let example = at_limit.example_accepted().unwrap();
+    assert_eq!(example.len() as u64, MAX_EXAMPLE_LENGTH);
+    assert_eq!(at_limit.characterise(&example)?, BasicStateSort::Accept);
+    let beyond_limit = CounterAutomatonBlueprint::with_tolerance('(', ')', limit + 1..=limit + 1);
+    assert_eq!(beyond_limit.example_accepted(), None);
+
+    Ok(())
+}
+
+#[test]
+fn wrapper_examples_respect_constraints() -> Result<(), String> {
+    let two_firsts = CounterAutomatonBlueprint::with_tolerance('a', 'b', 2..=2);
+    let empty = CounterAutomatonBlueprint::new('a', 'b');
+
+    assert_eq!(two_firsts.max_length(1).example_accepted(), None);
+    assert_eq!(two_firsts.max_length(2).example_accepted(), Some(vec!['a', 'a']));
+    assert_eq!(empty.min_length(1).example_accepted(), None);
+
+    let union = BasicUnionAutomatonBlueprint::new(&two_firsts, &empty);
+    let example = union.example_accepted().unwrap();
+    assert_eq!(example, vec!['a', 'a']);
+    assert_eq!(union.characterise(&example)?, BasicStateSort::Accept);
+
+    Ok(())
+}
@@ -1,4 +1,6 @@
 use deterministic_automata::{BasicStateSort, DeterministicAutomatonBlueprint, MutationAutomatonBlueprint, DynamicAutomatonBlueprint};
+use deterministic_automata::dynamic_automaton::{BoxedBlueprint, dyn_intersection_all, dyn_majority_all, dyn_map_error, dyn_reduce, dyn_threshold_all, dyn_union_all, OrderedSort, Reduce};
+use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
 
 // Simple counting automaton that accepts if count >= 0
 struct CountingBlueprint;
@@ -180,4 +182,275 @@ fn test_deterministic_and_mutation_automata_interoperability() {
     // MutableCounterBlueprint: starts at 0, after '+' -> 1 -> Reject (since 1 != 0)  
     mut_automaton.update_state(&'+').unwrap();
     assert_eq!(mut_automaton.current_state_sort().unwrap(), BasicStateSort::Reject);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_dyn_intersection_all_accepts_iff_all_accept() {
+    let rules: Vec<Box<DynamicAutomatonBlueprint<char, BasicStateSort, String>>> = vec![
+        Box::new(CountingBlueprint),
+        Box::new(MutableCounterBlueprint::new('+', '-')),
+    ];
+    let all = dyn_intersection_all(rules);
+
+    assert_eq!(all.characterise(&[]).unwrap(), BasicStateSort::Accept);
+    assert_eq!(all.characterise(&['+', '-']).unwrap(), BasicStateSort::Accept);
+    assert_eq!(all.characterise(&['+']).unwrap(), BasicStateSort::Reject);
+    assert_eq!(all.characterise(&['-', '+']).unwrap(), BasicStateSort::Accept);
+    assert_eq!(all.characterise(&['-']).unwrap(), BasicStateSort::Reject);
+    assert!(all.characterise(&['x']).is_err());
+
+    let mut automaton = all.automaton();
+    automaton.update_state(&'+').unwrap();
+    assert_eq!(automaton.current_state_sort().unwrap(), BasicStateSort::Reject);
+    assert_eq!(automaton.update_sort_state(&'-').unwrap(), BasicStateSort::Accept);
+}
+
+#[test]
+fn test_dyn_intersection_all_empty_accepts_everything() {
+    let all = dyn_intersection_all::<char, String>(Vec::new());
+
+    assert_eq!(all.characterise(&[]).unwrap(), BasicStateSort::Accept);
+    assert_eq!(all.characterise(&['a', 'b', 'c']).unwrap(), BasicStateSort::Accept);
+}
+
+#[cfg(feature = "fingerprint")]
+#[test]
+fn test_state_fingerprint_detects_revisited_state() {
+    use deterministic_automata::dynamic_automaton::DynamicStatefulAutomatonBlueprint;
+
+    let deterministic = CountingBlueprint;
+    let mutation = MutableCounterBlueprint::new('+', '-');
+    let blueprints: Vec<&DynamicStatefulAutomatonBlueprint<char, BasicStateSort, String>> = vec![
+        &deterministic,
+        &mutation,
+    ];
+
+    for blueprint in blueprints {
+        let mut automaton = blueprint.stateful_automaton();
+        let initial = automaton.state_fingerprint();
+
+        automaton.update_state(&'+').unwrap();
+        assert_ne!(automaton.state_fingerprint(), initial);
+
+        automaton.update_state(&'-').unwrap();
+        assert_eq!(automaton.state_fingerprint(), initial);
+        assert_eq!(automaton.current_state_sort().unwrap(), BasicStateSort::Accept);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum RuleError {
+    Counter(String),
+}
+
+// Total automaton accepting words of even length
+struct EvenLength;
+
+impl deterministic_automata::InfallibleAutomatonBlueprint for EvenLength {
+    type State = bool;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+
+    fn initial(&self) -> Self::State {
+        true
+    }
+
+    fn sort(&self, state: &Self::State) -> Self::StateSort {
+        if *state { BasicStateSort::Accept } else { BasicStateSort::Reject }
+    }
+
+    fn transition(&self, state: &Self::State, _: &Self::Alphabet) -> Self::State {
+        !state
+    }
+}
+
+#[test]
+fn test_dyn_map_error_normalizes_error_types() {
+    let counting: Box<DynamicAutomatonBlueprint<char, BasicStateSort, String>> = Box::new(CountingBlueprint);
+    let even: Box<DynamicAutomatonBlueprint<char, BasicStateSort, std::convert::Infallible>> = Box::new(EvenLength);
+
+    let rules: Vec<Box<DynamicAutomatonBlueprint<char, BasicStateSort, RuleError>>> = vec![
+        dyn_map_error(counting, RuleError::Counter),
+        dyn_map_error(even, |never| match never {}),
+    ];
+
+    assert_eq!(rules[0].characterise(&['+', '-']), Ok(BasicStateSort::Accept));
+    assert_eq!(rules[1].characterise(&['+', '-', '+']), Ok(BasicStateSort::Reject));
+    assert!(matches!(rules[0].characterise(&['x']), Err(RuleError::Counter(_))));
+
+    let mut automaton = rules[0].automaton();
+    assert!(matches!(automaton.update_state(&'x'), Err(RuleError::Counter(_))));
+}
+
+#[test]
+fn test_dyn_reduce_basic_sort_matches_union_and_intersection() {
+    let union = dyn_reduce(Box::new(CounterAutomatonBlueprint::new('a', 'b')), Box::new(CounterAutomatonBlueprint::new('b', 'a')), Reduce::Max);
+    let intersection = dyn_reduce(Box::new(CounterAutomatonBlueprint::new('a', 'b')), Box::new(CounterAutomatonBlueprint::new('b', 'a')), Reduce::Min);
+
+    assert_eq!(union.characterise(&['a', 'b']).unwrap(), BasicStateSort::Accept);
+    assert_eq!(union.characterise(&['b', 'a']).unwrap(), BasicStateSort::Accept);
+    assert_eq!(union.characterise(&['a']).unwrap(), BasicStateSort::Reject);
+    assert_eq!(intersection.characterise(&['a', 'b']).unwrap(), BasicStateSort::Reject);
+    assert_eq!(intersection.characterise(&[]).unwrap(), BasicStateSort::Accept);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Level {
+    Low(char),
+    High
+}
+
+impl OrderedSort for Level {
+    fn rank(&self) -> u8 {
+        match self {
+            Level::Low(_) => 0,
+            Level::High => 1,
+        }
+    }
+}
+
+// Reports High once `trigger` has been read, otherwise Low tagged with `tag`
+struct LevelBlueprint {
+    trigger: char,
+    tag: char
+}
+
+impl DeterministicAutomatonBlueprint for LevelBlueprint {
+    type State = bool;
+    type Alphabet = char;
+    type StateSort = Level;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        false
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(if *state { Level::High } else { Level::Low(self.tag) })
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        if *character == '?' {
+            return Err("Unknown symbol".to_string());
+        }
+        Ok(*state || *character == self.trigger)
+    }
+}
+
+#[test]
+fn test_dyn_reduce_ordered_sort_prefers_first_on_ties() {
+    let max = dyn_reduce(Box::new(LevelBlueprint { trigger: 'x', tag: '1' }), Box::new(LevelBlueprint { trigger: 'y', tag: '2' }), Reduce::Max);
+    let min = dyn_reduce(Box::new(LevelBlueprint { trigger: 'x', tag: '1' }), Box::new(LevelBlueprint { trigger: 'y', tag: '2' }), Reduce::Min);
+
+    assert_eq!(max.characterise(&[]).unwrap(), Level::Low('1'));
+    assert_eq!(max.characterise(&['y']).unwrap(), Level::High);
+    assert_eq!(min.characterise(&['y']).unwrap(), Level::Low('1'));
+    assert_eq!(min.characterise(&['x']).unwrap(), Level::Low('2'));
+    assert_eq!(min.characterise(&['x', 'y']).unwrap(), Level::High);
+    assert!(max.characterise(&['?']).is_err());
+}
+
+#[test]
+fn test_dyn_union_all_mixes_component_types() {
+    let rules: Vec<Box<DynamicAutomatonBlueprint<char, BasicStateSort, String>>> = vec![
+        Box::new(CountingBlueprint),
+        Box::new(MutableCounterBlueprint::new('-', '+')),
+        Box::new(CounterAutomatonBlueprint::with_tolerance('-', '+', 1..=1)),
+    ];
+    let any = dyn_union_all(rules);
+
+    // Positive count accepted by the first component alone
+    assert_eq!(any.characterise(&['+']).unwrap(), BasicStateSort::Accept);
+    // Count of -2 rejected by all three
+    assert_eq!(any.characterise(&['+', '-', '-', '-']).unwrap(), BasicStateSort::Reject);
+    // Count of -1 accepted by the tolerant counter alone
+    assert_eq!(any.characterise(&['-']).unwrap(), BasicStateSort::Accept);
+    assert!(any.characterise(&['x']).is_err());
+
+    let none = dyn_union_all::<char, String>(vec![]);
+    assert_eq!(none.characterise(&[]).unwrap(), BasicStateSort::Reject);
+}
+
+#[test]
+fn test_characterise_with_reuses_one_runtime() {
+    let rules: Vec<Box<DynamicAutomatonBlueprint<char, BasicStateSort, String>>> = vec![
+        Box::new(CountingBlueprint),
+        Box::new(MutableCounterBlueprint::new('+', '-')),
+    ];
+    let both = dyn_intersection_all(rules);
+    let mut automaton = both.prepared_automaton();
+
+    assert_eq!(both.characterise_with(automaton.as_mut(), &['+', '+']).unwrap(), BasicStateSort::Reject);
+    assert_eq!(both.characterise_with(automaton.as_mut(), &['+', '-']).unwrap(), BasicStateSort::Accept);
+    // A failed run leaves no trace on the next one
+    assert!(both.characterise_with(automaton.as_mut(), &['+', 'x']).is_err());
+    assert_eq!(both.characterise_with(automaton.as_mut(), &[]).unwrap(), BasicStateSort::Accept);
+
+    for word in [&['+'][..], &['-', '+'], &['+', '+', '-', '-']] {
+        assert_eq!(both.characterise_with(automaton.as_mut(), word), both.characterise(word));
+    }
+}
+
+fn voting_rules() -> Vec<Box<DynamicAutomatonBlueprint<char, BasicStateSort, String>>> {
+    vec![
+        Box::new(CountingBlueprint),
+        Box::new(MutableCounterBlueprint::new('+', '-')),
+        Box::new(CounterAutomatonBlueprint::new('+', '-')),
+    ]
+}
+
+#[test]
+fn test_dyn_threshold_all_counts_accepting_components() {
+    // "+-" satisfies all three rules, "-+" all but a^n b^n, "+" only the non-negative count
+    let cases = [(&['+', '-'][..], 3), (&['-', '+'][..], 2), (&['+'][..], 1), (&['-'][..], 0)];
+    for threshold in 0..=4 {
+        let blueprint = dyn_threshold_all(voting_rules(), threshold);
+        for (word, accepting) in cases {
+            let expected = BasicStateSort::from(accepting >= threshold);
+            assert_eq!(blueprint.characterise(word).unwrap(), expected, "{:?} with threshold {}", word, threshold);
+        }
+    }
+    assert!(dyn_threshold_all(voting_rules(), 2).characterise(&['x']).is_err());
+}
+
+#[test]
+fn test_dyn_majority_all_needs_more_than_half() {
+    let majority = dyn_majority_all(voting_rules());
+    assert_eq!(majority.characterise(&['-', '+']).unwrap(), BasicStateSort::Accept);
+    assert_eq!(majority.characterise(&['+']).unwrap(), BasicStateSort::Reject);
+
+    let rules: Vec<Box<DynamicAutomatonBlueprint<char, BasicStateSort, String>>> = vec![
+        Box::new(CountingBlueprint),
+        Box::new(MutableCounterBlueprint::new('+', '-')),
+    ];
+    // Half of two is not a majority
+    assert_eq!(dyn_majority_all(rules).characterise(&['+']).unwrap(), BasicStateSort::Reject);
+}
+
+#[test]
+fn boxed_blueprint_composes_with_static_products() {
+    use deterministic_automata::product_automaton::BasicIntersectionAutomatonBlueprint;
+
+    let boxed = BoxedBlueprint::new(CountingBlueprint);
+    let balanced = CounterAutomatonBlueprint::new('+', '-');
+
+    // Balanced a^n b^n over '+' and '-' whose running count ends non-negative
+    let both = BasicIntersectionAutomatonBlueprint::new(&boxed, &balanced);
+    assert_eq!(both.characterise(&['+', '-']).unwrap(), BasicStateSort::Accept);
+    assert_eq!(both.characterise(&['+', '+', '-']).unwrap(), BasicStateSort::Reject);
+
+    // The blanket mutation implementation applies as well
+    assert_eq!(boxed.mutation_characterise(&['-']).unwrap(), BasicStateSort::Reject);
+}
+
+#[test]
+fn boxed_blueprint_reports_errors_on_the_failing_transition() {
+    let boxed = BoxedBlueprint::new(CountingBlueprint);
+
+    let state = boxed.transition_map(&boxed.initial_state(), &'+').unwrap();
+    let checkpoint = state.clone();
+    let state = boxed.transition_map(&state, &'-').unwrap();
+    assert_eq!(boxed.state_sort_map(&state).unwrap(), BasicStateSort::Accept);
+    assert_eq!(boxed.transition_map(&checkpoint, &'x').unwrap_err(), "Invalid character");
+    assert!(boxed.characterise(&['+', 'x', '-']).is_err());
+}
@@ -3,6 +3,9 @@ use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint
 use deterministic_automata::product_automaton::{BasicUnionAutomatonBlueprint, BasicIntersectionAutomatonBlueprint};
 use deterministic_automata::either_automaton::deterministic::Either;
 
+type CounterUnion<'a> = BasicUnionAutomatonBlueprint<'a, 'a, CounterAutomatonBlueprint<char>, CounterAutomatonBlueprint<char>, char, String>;
+type CounterIntersection<'a> = BasicIntersectionAutomatonBlueprint<'a, 'a, CounterAutomatonBlueprint<char>, CounterAutomatonBlueprint<char>, char, String>;
+
 fn str_to_vec_char(s: &str) -> Vec<char> {
     s.chars().collect()
 }
@@ -30,7 +33,7 @@ fn either_right_basic_functionality() -> Result<(), String> {
     let counter1 = CounterAutomatonBlueprint::new('a', 'b');
     let counter2 = CounterAutomatonBlueprint::new('x', 'y');
     let union = BasicUnionAutomatonBlueprint::new(&counter1, &counter2);
-    let either_blueprint: Either<CounterAutomatonBlueprint<char>, BasicUnionAutomatonBlueprint<'_, '_, CounterAutomatonBlueprint<char>, CounterAutomatonBlueprint<char>, char, String>> = Either::Right(union);
+    let either_blueprint: Either<CounterAutomatonBlueprint<char>, CounterUnion<'_>> = Either::Right(union);
     
     let mut automaton = DeterministicAutomaton::new(&either_blueprint);
     
@@ -61,7 +64,7 @@ fn either_characterise_right() -> Result<(), String> {
     let counter1 = CounterAutomatonBlueprint::new('a', 'b');
     let counter2 = CounterAutomatonBlueprint::new('x', 'y');
     let intersection = BasicIntersectionAutomatonBlueprint::new(&counter1, &counter2);
-    let either_blueprint: Either<CounterAutomatonBlueprint<char>, BasicIntersectionAutomatonBlueprint<'_, '_, CounterAutomatonBlueprint<char>, CounterAutomatonBlueprint<char>, char, String>> = Either::Right(intersection);
+    let either_blueprint: Either<CounterAutomatonBlueprint<char>, CounterIntersection<'_>> = Either::Right(intersection);
     
     assert_eq!(either_blueprint.characterise(&str_to_vec_char(""))?, BasicStateSort::Accept);
     assert_eq!(either_blueprint.characterise(&str_to_vec_char("ab"))?, BasicStateSort::Reject);
@@ -142,8 +145,8 @@ fn either_type_equality() {
     let union1 = BasicUnionAutomatonBlueprint::new(&counter3, &counter4);
     let union2 = BasicUnionAutomatonBlueprint::new(&counter3, &counter4);
     
-    let right1: Either<CounterAutomatonBlueprint<char>, BasicUnionAutomatonBlueprint<'_, '_, CounterAutomatonBlueprint<char>, CounterAutomatonBlueprint<char>, char, String>> = Either::Right(union1);
-    let right2: Either<CounterAutomatonBlueprint<char>, BasicUnionAutomatonBlueprint<'_, '_, CounterAutomatonBlueprint<char>, CounterAutomatonBlueprint<char>, char, String>> = Either::Right(union2);
+    let right1: Either<CounterAutomatonBlueprint<char>, CounterUnion<'_>> = Either::Right(union1);
+    let right2: Either<CounterAutomatonBlueprint<char>, CounterUnion<'_>> = Either::Right(union2);
     
     assert_eq!(right1, right2);
 }
@@ -172,4 +175,127 @@ fn either_debug_format() {
     
     assert!(left_debug.contains("Left"));
     assert!(right_debug.contains("Right"));
-}
\ No newline at end of file
+}
+
+#[derive(Debug, PartialEq)]
+struct UnexpectedSymbol(char);
+
+/// Accepts words consisting only of `'a'`, rejecting `'#'` with an error type that has no `Default`.
+struct OnlyA;
+
+impl DeterministicAutomatonBlueprint for OnlyA {
+    type State = bool;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+    type ErrorType = UnexpectedSymbol;
+
+    fn initial_state(&self) -> Self::State {
+        true
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(if *state { BasicStateSort::Accept } else { BasicStateSort::Reject })
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        if *character == '#' {
+            Err(UnexpectedSymbol('#'))
+        } else {
+            Ok(*state && *character == 'a')
+        }
+    }
+}
+
+#[test]
+fn either_automaton_runs_without_default_error() {
+    let either_blueprint: Either<OnlyA, OnlyA> = Either::Left(OnlyA);
+    let mut automaton = either_blueprint.either_automaton();
+
+    assert_eq!(automaton.current_state_sort(), Ok(BasicStateSort::Accept));
+    assert_eq!(automaton.update_sort_state(&'a'), Ok(BasicStateSort::Accept));
+    assert_eq!(automaton.update_sort_state(&'b'), Ok(BasicStateSort::Reject));
+    assert_eq!(automaton.update_state(&'#'), Err(UnexpectedSymbol('#')));
+}
+
+#[test]
+fn either_automaton_matches_blueprint_runtime() -> Result<(), String> {
+    let either_blueprint: Either<CounterAutomatonBlueprint<char>, CounterAutomatonBlueprint<char>> = Either::Right(CounterAutomatonBlueprint::new('x', 'y'));
+    let mut combined = either_blueprint.either_automaton();
+    let mut generic = DeterministicAutomaton::new(&either_blueprint);
+
+    for symbol in str_to_vec_char("xxyzy") {
+        assert_eq!(combined.update_sort_state(&symbol)?, generic.update_sort_state(&symbol)?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn either_supports_provided_blueprint_methods() -> Result<(), String> {
+    let either_blueprint: Either<CounterAutomatonBlueprint<char>, CounterAutomatonBlueprint<char>> = Either::Left(CounterAutomatonBlueprint::new('a', 'b'));
+    let input = str_to_vec_char("aab");
+
+    assert_eq!(either_blueprint.characterise_zip(&input)?, vec![
+        ('a', BasicStateSort::Reject),
+        ('a', BasicStateSort::Reject),
+        ('b', BasicStateSort::Reject),
+    ]);
+    assert_eq!(either_blueprint.characterise_counted(&input)?, (BasicStateSort::Reject, 3));
+    assert_eq!(either_blueprint.state_path(&input)?.len(), 4);
+    assert_eq!(either_blueprint.characterise_rev(&str_to_vec_char("ba"))?, BasicStateSort::Accept);
+
+    let mut automaton = either_blueprint.characterise_partial(&input)?;
+    assert_eq!(automaton.try_transition(&'b')?, BasicStateSort::Accept);
+    assert_eq!(automaton.update_sort_state(&'b')?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn either_automaton_try_transition_does_not_consume() -> Result<(), String> {
+    let either_blueprint: Either<CounterAutomatonBlueprint<char>, CounterAutomatonBlueprint<char>> = Either::Right(CounterAutomatonBlueprint::new('a', 'b'));
+    let mut automaton = either_blueprint.either_automaton();
+    automaton.update_state(&'a')?;
+
+    assert_eq!(automaton.try_transition(&'b')?, BasicStateSort::Accept);
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Reject);
+
+    Ok(())
+}
+
+#[test]
+fn either_automaton_reset_returns_to_initial_state() -> Result<(), String> {
+    let either_blueprint: Either<CounterAutomatonBlueprint<char>, CounterAutomatonBlueprint<char>> = Either::Left(CounterAutomatonBlueprint::new('a', 'b'));
+    let mut automaton = either_blueprint.either_automaton();
+    automaton.update_state(&'a')?;
+
+    automaton.reset();
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn product_with_runtime_chosen_either_component() -> Result<(), String> {
+    let fixed = CounterAutomatonBlueprint::new('a', 'b');
+    let brackets = CounterAutomatonBlueprint::new('(', ')');
+    let counter_ab = CounterAutomatonBlueprint::new('a', 'b');
+    let counter_xy = CounterAutomatonBlueprint::new('x', 'y');
+    let union = BasicUnionAutomatonBlueprint::new(&counter_ab, &counter_xy);
+
+    let choose = |use_brackets: bool| -> Either<CounterAutomatonBlueprint<char>, CounterUnion<'_>> {
+        if use_brackets { Either::Left(brackets.clone()) } else { Either::Right(union.clone()) }
+    };
+
+    let chosen = choose(false);
+    let product = deterministic_automata::product_automaton::ProductAutomatonBlueprint::new(&fixed, &chosen);
+    assert_eq!(product.characterise(&str_to_vec_char("ab"))?, (BasicStateSort::Accept, BasicStateSort::Accept));
+    assert_eq!(product.characterise(&str_to_vec_char("a"))?, (BasicStateSort::Reject, BasicStateSort::Reject));
+
+    let chosen = choose(true);
+    let intersection = BasicIntersectionAutomatonBlueprint::new(&fixed, &chosen);
+    assert_eq!(intersection.characterise(&str_to_vec_char(""))?, BasicStateSort::Accept);
+    assert_eq!(intersection.characterise(&str_to_vec_char("ab"))?, BasicStateSort::Reject);
+
+    Ok(())
+}
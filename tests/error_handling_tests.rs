@@ -79,7 +79,7 @@ fn error_propagation_in_product_automaton() {
     let bad_blueprint = FailingBlueprint::new(true, false);
     let product = ProductAutomatonBlueprint::new(&good_blueprint, &bad_blueprint);
     
-    let result = product.characterise(&vec![]);
+    let result = product.characterise(&[]);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err(), "State sort validation failed");
 }
@@ -90,7 +90,7 @@ fn error_propagation_in_union_automaton() {
     let bad_blueprint = FailingBlueprint::new(true, false);
     let union = BasicUnionAutomatonBlueprint::new(&good_blueprint, &bad_blueprint);
     
-    let result = union.characterise(&vec![]);
+    let result = union.characterise(&[]);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err(), "State sort validation failed");
 }
@@ -101,7 +101,7 @@ fn error_propagation_in_intersection_automaton() {
     let bad_blueprint = FailingBlueprint::new(false, true);
     let intersection = BasicIntersectionAutomatonBlueprint::new(&good_blueprint, &bad_blueprint);
     
-    let result = intersection.characterise(&vec!['a']);
+    let result = intersection.characterise(&['a']);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err(), "Transition validation failed");
 }
@@ -183,4 +183,41 @@ fn graceful_error_handling_invalid_states() {
     let result = blueprint.state_sort_map(&999);
     assert!(result.is_err());
     assert!(result.unwrap_err().contains("Invalid state"));
-}
\ No newline at end of file
+}
+
+#[test]
+fn validate_distinguishes_rejection_from_errors() {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    assert_eq!(blueprint.validate(&['a', 'b']), Ok(()));
+    assert_eq!(blueprint.validate(&['b']), Err(ValidationError::Rejected));
+
+    let failing = FailingBlueprint::new(false, true);
+    assert_eq!(failing.validate(&['a']), Err(ValidationError::Inner("Transition validation failed".to_string())));
+    assert_eq!(failing.validate(&[]), Ok(()));
+
+    let mutation_failing = FailingBlueprint::new(true, false);
+    assert_eq!(mutation_failing.mutation_validate(&[]), Err(ValidationError::Inner("State sort validation failed".to_string())));
+}
+
+#[test]
+fn validation_error_display_and_source() {
+    use std::error::Error;
+    use std::fmt;
+
+    #[derive(Debug)]
+    struct Broken;
+
+    impl fmt::Display for Broken {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "broken")
+        }
+    }
+
+    impl Error for Broken {}
+
+    assert_eq!(ValidationError::<Broken>::Rejected.to_string(), "input rejected");
+    assert_eq!(ValidationError::Inner(Broken).to_string(), "automaton error: broken");
+    assert!(ValidationError::<Broken>::Rejected.source().is_none());
+    assert!(ValidationError::Inner(Broken).source().is_some());
+}
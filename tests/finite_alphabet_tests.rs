@@ -0,0 +1,32 @@
+use deterministic_automata::FiniteAlphabet;
+
+#[test]
+fn finite_alphabet_builtin_impls_list_each_value_once() {
+    assert_eq!(<()>::alphabet(), vec![()]);
+    assert_eq!(bool::alphabet(), vec![false, true]);
+
+    let bytes = u8::alphabet();
+    assert_eq!(bytes.len(), 256);
+    assert!(bytes.windows(2).all(|pair| pair[0] < pair[1]));
+
+    assert_eq!(i8::alphabet().len(), 256);
+    assert_eq!(Option::<bool>::alphabet(), vec![None, Some(false), Some(true)]);
+}
+
+#[test]
+fn finite_alphabet_user_enum() {
+    #[derive(Clone, PartialEq, Debug)]
+    enum Direction {
+        Left,
+        Right,
+    }
+
+    impl FiniteAlphabet for Direction {
+        fn alphabet() -> Vec<Self> {
+            vec![Direction::Left, Direction::Right]
+        }
+    }
+
+    assert_eq!(Direction::alphabet(), vec![Direction::Left, Direction::Right]);
+    assert_eq!(Option::<Direction>::alphabet().len(), 3);
+}
@@ -0,0 +1,35 @@
+use deterministic_automata::*;
+use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+use deterministic_automata::fmt::format_trace;
+
+#[test]
+fn format_trace_aligns_columns_to_widest_cell() {
+    let blueprint = CounterAutomatonBlueprint::new("open", "close");
+    let trace = blueprint.characterise_trace_record(&["open", "open", "close", "close"]).unwrap();
+
+    assert_eq!(format_trace(&trace.symbols, &trace.sorts), "\
+open   | open   | close  | close
+Reject | Reject | Reject | Accept");
+}
+
+#[test]
+fn format_trace_renders_characterise_zip_output() {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let (symbols, sorts): (Vec<char>, Vec<BasicStateSort>) = blueprint.characterise_zip(&['a', 'b', 'b']).unwrap().into_iter().unzip();
+
+    assert_eq!(format_trace(&symbols, &sorts), "\
+a      | b      | b
+Reject | Accept | Reject");
+}
+
+#[test]
+fn format_trace_leaves_missing_cells_blank() {
+    assert_eq!(format_trace(&['x', 'y'], &[TriStateSort::Unknown]), "x       | y\nUnknown |");
+    assert_eq!(format_trace::<char, BasicStateSort>(&[], &[]), "\n");
+}
+
+#[test]
+fn state_sorts_display_their_names() {
+    assert_eq!(BasicStateSort::Accept.to_string(), "Accept");
+    assert_eq!(TriStateSort::Unknown.to_string(), "Unknown");
+}
@@ -0,0 +1,116 @@
+use deterministic_automata::*;
+use deterministic_automata::fn_automaton::{FnBlueprint, FnMutationBlueprint};
+use deterministic_automata::product_automaton::MutationBasicUnionAutomatonBlueprint;
+use deterministic_automata::product_automaton::BasicIntersectionAutomatonBlueprint;
+use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+
+fn str_to_vec_char(s: &str) -> Vec<char> {
+    s.chars().collect()
+}
+
+#[test]
+fn fn_blueprint_basic_functionality() -> Result<(), String> {
+    let even_length = FnBlueprint::new(
+        || true,
+        |even| Ok::<_, String>(if *even { BasicStateSort::Accept } else { BasicStateSort::Reject }),
+        |even, _: &char| Ok(!even),
+    );
+
+    assert_eq!(even_length.characterise(&str_to_vec_char(""))?, BasicStateSort::Accept);
+    assert_eq!(even_length.characterise(&str_to_vec_char("abc"))?, BasicStateSort::Reject);
+    assert_eq!(even_length.characterise(&str_to_vec_char("abcd"))?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn fn_blueprint_propagates_errors() {
+    let only_a = FnBlueprint::new(
+        || 0usize,
+        |_| Ok(BasicStateSort::Accept),
+        |count, character: &char| if *character == 'a' { Ok(count + 1) } else { Err(format!("Unexpected {}", character)) },
+    );
+
+    assert_eq!(only_a.characterise(&['a', 'a']), Ok(BasicStateSort::Accept));
+    assert_eq!(only_a.characterise(&['a', 'b']), Err("Unexpected b".to_string()));
+}
+
+#[test]
+fn fn_blueprint_composes_with_products() -> Result<(), String> {
+    let counter = CounterAutomatonBlueprint::new('a', 'b');
+    let even_length = FnBlueprint::new(
+        || true,
+        |even| Ok::<_, String>(if *even { BasicStateSort::Accept } else { BasicStateSort::Reject }),
+        |even, _: &char| Ok(!even),
+    );
+    let intersection = BasicIntersectionAutomatonBlueprint::new(&counter, &even_length);
+
+    assert_eq!(intersection.characterise(&str_to_vec_char("aabb"))?, BasicStateSort::Accept);
+    assert_eq!(intersection.characterise(&str_to_vec_char("aab"))?, BasicStateSort::Reject);
+
+    Ok(())
+}
+
+#[test]
+fn fn_mutation_blueprint_updates_in_place() -> Result<(), String> {
+    let buffer = FnMutationBlueprint::new(
+        Vec::new,
+        |symbols: &Vec<char>| Ok::<_, String>(if symbols.len() == 2 { BasicStateSort::Accept } else { BasicStateSort::Reject }),
+        |symbols, character: &char| {
+            symbols.push(*character);
+            Ok(())
+        },
+    );
+
+    let mut automaton = MutationAutomaton::new(&buffer);
+    automaton.update_state(&'x')?;
+    automaton.update_state(&'y')?;
+    assert_eq!(automaton.view_state(), &vec!['x', 'y']);
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn fn_mutation_blueprint_composes_with_mutation_products() -> Result<(), String> {
+    let counter = CounterAutomatonBlueprint::new('a', 'b');
+    let single_symbol = FnMutationBlueprint::new(
+        || 0usize,
+        |count| Ok::<_, String>(if *count == 1 { BasicStateSort::Accept } else { BasicStateSort::Reject }),
+        |count, _: &char| {
+            *count += 1;
+            Ok(())
+        },
+    );
+    let union = MutationBasicUnionAutomatonBlueprint::new(&counter, &single_symbol);
+
+    assert_eq!(union.mutation_characterise(&str_to_vec_char("c"))?, BasicStateSort::Accept);
+    assert_eq!(union.mutation_characterise(&str_to_vec_char("ab"))?, BasicStateSort::Accept);
+    assert_eq!(union.mutation_characterise(&str_to_vec_char("ba"))?, BasicStateSort::Reject);
+
+    Ok(())
+}
+
+#[test]
+fn fn_blueprints_clone_without_cloneable_errors() {
+    #[derive(Debug, PartialEq)]
+    struct Unexpected(char);
+
+    let blueprint = FnBlueprint::new(
+        || 0u32,
+        |state: &u32| Ok::<_, Unexpected>(BasicStateSort::from(*state == 1)),
+        |state: &u32, symbol: &char| if *symbol == 'x' { Ok(state + 1) } else { Err(Unexpected(*symbol)) },
+    );
+    let copy = blueprint.clone();
+    assert_eq!(copy.characterise(&['x']), Ok(BasicStateSort::Accept));
+    assert_eq!(copy.characterise(&['y']), Err(Unexpected('y')));
+
+    let counter = FnMutationBlueprint::new(
+        || 0u32,
+        |state: &u32| Ok::<_, Unexpected>(BasicStateSort::from(*state == 1)),
+        |state: &mut u32, symbol: &char| if *symbol == 'x' { *state += 1; Ok(()) } else { Err(Unexpected(*symbol)) },
+    );
+    let copy = counter.clone();
+    assert_eq!(copy.mutation_characterise(&['x']), Ok(BasicStateSort::Accept));
+    assert_eq!(copy.mutation_characterise(&['y']), Err(Unexpected('y')));
+}
@@ -0,0 +1,64 @@
+use std::convert::Infallible;
+
+use deterministic_automata::*;
+use deterministic_automata::product_automaton::BasicIntersectionAutomatonBlueprint;
+
+// Accepts words with an even number of occurrences of the given symbol
+struct EvenCount {
+    symbol: char,
+}
+
+impl InfallibleAutomatonBlueprint for EvenCount {
+    type State = bool;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+
+    fn initial(&self) -> Self::State {
+        true
+    }
+
+    fn sort(&self, state: &Self::State) -> Self::StateSort {
+        if *state { BasicStateSort::Accept } else { BasicStateSort::Reject }
+    }
+
+    fn transition(&self, state: &Self::State, character: &Self::Alphabet) -> Self::State {
+        if *character == self.symbol { !state } else { *state }
+    }
+}
+
+fn str_to_vec_char(s: &str) -> Vec<char> {
+    s.chars().collect()
+}
+
+#[test]
+fn infallible_characterise_basic_functionality() {
+    let blueprint = EvenCount { symbol: 'a' };
+
+    assert_eq!(blueprint.infallible_characterise(&str_to_vec_char("")), BasicStateSort::Accept);
+    assert_eq!(blueprint.infallible_characterise(&str_to_vec_char("a")), BasicStateSort::Reject);
+    assert_eq!(blueprint.infallible_characterise(&str_to_vec_char("abab")), BasicStateSort::Accept);
+}
+
+#[test]
+fn infallible_blueprint_is_deterministic_blueprint() {
+    let blueprint = EvenCount { symbol: 'a' };
+
+    let result: Result<BasicStateSort, Infallible> = blueprint.characterise(&str_to_vec_char("aab"));
+    assert_eq!(result, Ok(BasicStateSort::Accept));
+
+    let mut automaton = DeterministicAutomaton::new(&blueprint);
+    assert_eq!(automaton.update_sort_state(&'a'), Ok(BasicStateSort::Reject));
+    assert!(!*automaton.view_state());
+
+    assert_eq!(blueprint.mutation_characterise(&str_to_vec_char("a")), Ok(BasicStateSort::Reject));
+}
+
+#[test]
+fn infallible_blueprints_compose_in_products() {
+    let even_a = EvenCount { symbol: 'a' };
+    let even_b = EvenCount { symbol: 'b' };
+    let intersection = BasicIntersectionAutomatonBlueprint::new(&even_a, &even_b);
+
+    assert_eq!(intersection.characterise(&str_to_vec_char("abab")), Ok(BasicStateSort::Accept));
+    assert_eq!(intersection.characterise(&str_to_vec_char("aab")), Ok(BasicStateSort::Reject));
+}
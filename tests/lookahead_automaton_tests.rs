@@ -0,0 +1,78 @@
+use deterministic_automata::*;
+
+fn str_to_vec_char(s: &str) -> Vec<char> {
+    s.chars().collect()
+}
+
+// Accepts words where every 'a' is immediately followed by 'b', consuming "ab" pairs at once
+struct PairedA;
+
+impl LookaheadBlueprint for PairedA {
+    type State = bool;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+    type ErrorType = String;
+
+    fn lookahead(&self) -> usize {
+        2
+    }
+
+    fn initial_lookahead_state(&self) -> Self::State {
+        true
+    }
+
+    fn lookahead_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(if *state { BasicStateSort::Accept } else { BasicStateSort::Reject })
+    }
+
+    fn transition_lookahead(&self, state: &Self::State, window: &[Self::Alphabet]) -> Result<(Self::State, usize), Self::ErrorType> {
+        match window {
+            ['a', 'b', ..] => Ok((*state, 2)),
+            ['a', ..] => Ok((false, 1)),
+            ['b' | 'c', ..] => Ok((*state, 1)),
+            [other, ..] => Err(format!("Unexpected {}", other)),
+            [] => Err("Empty window".to_string()),
+        }
+    }
+}
+
+#[test]
+fn lookahead_consumes_multiple_symbols() {
+    assert_eq!(PairedA.characterise_lookahead(&str_to_vec_char("")), Ok(BasicStateSort::Accept));
+    assert_eq!(PairedA.characterise_lookahead(&str_to_vec_char("abcab")), Ok(BasicStateSort::Accept));
+    assert_eq!(PairedA.characterise_lookahead(&str_to_vec_char("abca")), Ok(BasicStateSort::Reject));
+    assert_eq!(PairedA.characterise_lookahead(&str_to_vec_char("acb")), Ok(BasicStateSort::Reject));
+    assert_eq!(PairedA.characterise_lookahead(&str_to_vec_char("abx")), Err("Unexpected x".to_string()));
+}
+
+// Reports zero consumption to check that processing still advances
+struct Stalling;
+
+impl LookaheadBlueprint for Stalling {
+    type State = usize;
+    type Alphabet = u8;
+    type StateSort = usize;
+    type ErrorType = String;
+
+    fn lookahead(&self) -> usize {
+        0
+    }
+
+    fn initial_lookahead_state(&self) -> Self::State {
+        0
+    }
+
+    fn lookahead_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(*state)
+    }
+
+    fn transition_lookahead(&self, state: &Self::State, window: &[Self::Alphabet]) -> Result<(Self::State, usize), Self::ErrorType> {
+        assert_eq!(window.len(), 1);
+        Ok((state + 1, 0))
+    }
+}
+
+#[test]
+fn lookahead_clamps_consumption_to_make_progress() {
+    assert_eq!(Stalling.characterise_lookahead(&[1, 2, 3]), Ok(3));
+}
@@ -0,0 +1,71 @@
+use deterministic_automata::*;
+use deterministic_automata::mod_counter_example::ModSumBlueprint;
+
+fn digits(n: u64) -> Vec<u8> {
+    n.to_string().bytes().map(|byte| byte - b'0').collect()
+}
+
+#[test]
+fn mod_sum_recognizes_multiples_of_three() -> Result<(), String> {
+    let blueprint = ModSumBlueprint::new(3);
+
+    for n in 0..300 {
+        let expected = if n % 3 == 0 { BasicStateSort::Accept } else { BasicStateSort::Reject };
+        assert_eq!(blueprint.characterise(&digits(n))?, expected, "{}", n);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn mod_sum_accepts_empty_sequence() -> Result<(), String> {
+    assert_eq!(ModSumBlueprint::new(7).characterise(&[])?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn mod_sum_tracks_digit_sum_not_value() -> Result<(), String> {
+    let blueprint = ModSumBlueprint::new(4);
+
+    // 13 is not a multiple of 4, but its digit sum is
+    assert_eq!(blueprint.characterise(&[1, 3])?, BasicStateSort::Accept);
+    assert_eq!(blueprint.state_path(&[9, 9, 2])?, vec![0, 1, 2, 0]);
+
+    Ok(())
+}
+
+#[test]
+fn mod_sum_rejects_non_digits() {
+    let blueprint = ModSumBlueprint::new(3);
+
+    assert_eq!(blueprint.characterise(&[1, 10]), Err("Not a decimal digit: 10".to_string()));
+}
+
+#[test]
+fn mod_sum_large_modulus_does_not_overflow() -> Result<(), String> {
+    let blueprint = ModSumBlueprint::new(u64::MAX - 1);
+
+    assert_eq!(blueprint.transition_map(&(u64::MAX - 2), &9)?, 8);
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn mod_sum_zero_modulus_panics() {
+    ModSumBlueprint::new(0);
+}
+
+#[test]
+fn mod_sum_reduces_out_of_range_states() -> Result<(), String> {
+    let blueprint = ModSumBlueprint::new(3);
+
+    assert_eq!(blueprint.with_initial(u64::MAX).characterise(&[1])?, BasicStateSort::Reject);
+    assert_eq!(blueprint.with_initial(u64::MAX).characterise(&[])?, BasicStateSort::Accept);
+    assert_eq!(blueprint.with_initial(4).characterise(&[2])?, BasicStateSort::Accept);
+
+    let large = ModSumBlueprint::new(u64::MAX - 1);
+    assert_eq!(large.with_initial(u64::MAX).characterise(&[9])?, large.with_initial(1).characterise(&[9])?);
+    Ok(())
+}
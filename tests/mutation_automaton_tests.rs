@@ -1,5 +1,10 @@
+use std::ops::ControlFlow;
+
 use deterministic_automata::*;
 use deterministic_automata::{MutationAutomatonBlueprint, MutationAutomaton};
+use deterministic_automata::mutation_automaton::{CachedMutationAutomaton, Deterministicize};
+use deterministic_automata::product_automaton::ProductAutomatonBlueprint;
+use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
 
 struct MutableCounterBlueprint {
     increment_char: char,
@@ -123,4 +128,453 @@ fn mutation_automaton_multiple_transitions() -> Result<(), String> {
     }
     
     Ok(())
-}
\ No newline at end of file
+}
+
+#[test]
+fn deterministicize_matches_mutation_characterise() -> Result<(), String> {
+    let blueprint = MutableCounterBlueprint::new('+', '-');
+    let deterministic = Deterministicize::new(&blueprint);
+
+    for word in ["", "+", "+-", "++-", "-+", "+-+-"] {
+        let input: Vec<char> = word.chars().collect();
+        assert_eq!(deterministic.characterise(&input)?, blueprint.mutation_characterise(&input)?);
+    }
+
+    assert!(deterministic.characterise(&['x']).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn deterministicize_transition_leaves_input_state_untouched() -> Result<(), String> {
+    let blueprint = MutableCounterBlueprint::new('+', '-');
+    let deterministic = Deterministicize::new(&blueprint);
+
+    let state = 3;
+    assert_eq!(deterministic.transition_map(&state, &'+')?, 4);
+    assert_eq!(state, 3);
+
+    Ok(())
+}
+
+#[test]
+fn deterministicize_in_deterministic_product() -> Result<(), String> {
+    let blueprint = MutableCounterBlueprint::new('a', 'b');
+    let deterministic = Deterministicize::new(&blueprint);
+    let counter = CounterAutomatonBlueprint::new('a', 'b');
+    let product = ProductAutomatonBlueprint::new(&deterministic, &counter);
+
+    assert_eq!(product.characterise(&['a', 'b'])?, (BasicStateSort::Accept, BasicStateSort::Accept));
+    assert_eq!(product.characterise(&['b', 'a'])?, (BasicStateSort::Accept, BasicStateSort::Reject));
+
+    Ok(())
+}
+
+#[test]
+fn mutation_characterise_controlled_stops_on_break() -> Result<(), String> {
+    let blueprint = MutableCounterBlueprint::new('+', '-');
+
+    let sort = blueprint.mutation_characterise_controlled(&['+', '-', '-', 'x'], |sort| {
+        if *sort == BasicStateSort::Accept { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+    })?;
+    assert_eq!(sort, BasicStateSort::Accept);
+
+    let result = blueprint.mutation_characterise_controlled(&['+', 'x'], |_| ControlFlow::Continue(()));
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+/// Counts '+' and '-' but underflows into an absorbing dead state, counting its transitions.
+struct UnderflowBlueprint {
+    transitions: std::cell::Cell<usize>,
+}
+
+impl MutationAutomatonBlueprint for UnderflowBlueprint {
+    type State = Option<u32>;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+    type ErrorType = String;
+
+    fn initial_mutation_state(&self) -> Self::State {
+        Some(0)
+    }
+
+    fn mutation_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(if *state == Some(0) { BasicStateSort::Accept } else { BasicStateSort::Reject })
+    }
+
+    fn mutation_transition_map(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        self.transitions.set(self.transitions.get() + 1);
+        *state = match (*state, character) {
+            (None, _) => None,
+            (Some(count), '+') => Some(count + 1),
+            (Some(count), '-') => count.checked_sub(1),
+            (Some(_), _) => return Err(format!("Invalid character: {}", character)),
+        };
+        Ok(())
+    }
+}
+
+#[test]
+fn mutation_characterise_early_trap_stops_in_absorbing_state() -> Result<(), String> {
+    let blueprint = UnderflowBlueprint { transitions: std::cell::Cell::new(0) };
+
+    // Underflowing is absorbing, so nothing after the first '-' is read
+    let sort = blueprint.mutation_characterise_early_trap(&['-', '+', '+', 'x'], |state| state.is_none())?;
+    assert_eq!(sort, BasicStateSort::Reject);
+    assert_eq!(blueprint.transitions.get(), 1);
+    assert_eq!(blueprint.mutation_characterise(&['-', '+', '+', 'x'])?, sort);
+
+    let result = blueprint.mutation_characterise_early_trap(&['+', 'x'], |state| state.is_none());
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn mutation_characterise_zip_aligns_symbols_and_sorts() -> Result<(), String> {
+    let blueprint = MutableCounterBlueprint::new('+', '-');
+
+    assert_eq!(blueprint.mutation_characterise_zip(&['+', '-', '-'])?, vec![
+        ('+', BasicStateSort::Reject),
+        ('-', BasicStateSort::Accept),
+        ('-', BasicStateSort::Reject),
+    ]);
+    assert!(blueprint.mutation_characterise_zip(&['+', 'x']).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn mutation_characterise_refs_matches_mutation_characterise() -> Result<(), String> {
+    let blueprint = MutableCounterBlueprint::new('+', '-');
+    let (plus, minus) = ('+', '-');
+
+    assert_eq!(blueprint.mutation_characterise_refs(&[&plus, &minus])?, BasicStateSort::Accept);
+    assert_eq!(blueprint.mutation_characterise_refs(&[&plus])?, BasicStateSort::Reject);
+    assert!(blueprint.mutation_characterise_refs(&[&'x']).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn mutation_automaton_modify_state_bypasses_transitions() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let mut automaton = MutationAutomaton::new(&blueprint);
+
+    automaton.update_state(&'a')?;
+    automaton.update_state(&'c')?;
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Reject);
+
+    automaton.modify_state(|state| *state = blueprint.initial_state());
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Accept);
+    assert_eq!(automaton.update_sort_state(&'a')?, BasicStateSort::Reject);
+    assert_eq!(automaton.update_sort_state(&'b')?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn mutation_characterise_rev_reads_back_to_front() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    assert_eq!(blueprint.mutation_characterise_rev(&['b', 'a'])?, BasicStateSort::Accept);
+    assert_eq!(blueprint.mutation_characterise_rev(&['a', 'b'])?, BasicStateSort::Reject);
+
+    Ok(())
+}
+
+#[test]
+fn mutation_characterise_partial_resumes_from_prefix() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+
+    let mut automaton = blueprint.mutation_characterise_partial(&['a'])?;
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Reject);
+    assert_eq!(automaton.update_sort_state(&'b')?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn mutation_automaton_clone_forks_independently() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let mut automaton = MutationAutomaton::new(&blueprint);
+    automaton.update_state(&'a')?;
+
+    let mut fork = automaton.clone();
+    assert_eq!(fork.update_sort_state(&'b')?, BasicStateSort::Accept);
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Reject);
+    assert_eq!(automaton.update_sort_state(&'c')?, BasicStateSort::Reject);
+    assert_eq!(fork.current_state_sort()?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn mutation_automaton_equality_compares_states() -> Result<(), String> {
+    let blueprint = MutableCounterBlueprint::new('+', '-');
+    let mut first = MutationAutomaton::new(&blueprint);
+    let mut second = MutationAutomaton::new(&blueprint);
+    assert!(first == second);
+
+    first.update_state(&'+')?;
+    assert!(first != second);
+    second.update_state(&'+')?;
+    assert!(first == second);
+
+    // Only states participate, so automata over different blueprint instances can be equal
+    let other_blueprint = MutableCounterBlueprint::new('x', 'y');
+    let mut other = MutationAutomaton::new(&other_blueprint);
+    other.update_state(&'x')?;
+    assert!(first == other);
+
+    Ok(())
+}
+
+#[test]
+fn mutation_characterise_counted_counts_every_transition() -> Result<(), String> {
+    let blueprint = MutableCounterBlueprint::new('+', '-');
+
+    assert_eq!(blueprint.mutation_characterise_counted(&['+', '-'])?, (BasicStateSort::Accept, 2));
+    assert!(blueprint.mutation_characterise_counted(&['+', 'x']).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn mutation_state_path_records_each_state() -> Result<(), String> {
+    let blueprint = MutableCounterBlueprint::new('+', '-');
+
+    assert_eq!(blueprint.mutation_state_path(&['+', '+', '-'])?, vec![0, 1, 2, 1]);
+    assert!(blueprint.mutation_state_path(&['+', 'x']).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn mutation_automaton_try_transition_leaves_state_untouched() -> Result<(), String> {
+    let blueprint = MutableCounterBlueprint::new('+', '-');
+    let mut automaton = MutationAutomaton::new(&blueprint);
+    automaton.update_state(&'+')?;
+
+    assert_eq!(automaton.try_transition(&'-')?, BasicStateSort::Accept);
+    assert_eq!(automaton.try_transition(&'+')?, BasicStateSort::Reject);
+    assert!(automaton.try_transition(&'x').is_err());
+    assert_eq!(*automaton.view_state(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn mutation_accepts_empty_classifies_initial_state() -> Result<(), String> {
+    let blueprint = MutableCounterBlueprint::new('+', '-');
+
+    assert!(blueprint.mutation_accepts_empty()?);
+    assert!(!blueprint.mutation_with_initial(2).mutation_accepts_empty()?);
+
+    Ok(())
+}
+
+#[test]
+fn mutation_characterise_records_isolates_errors() -> Result<(), String> {
+    let blueprint = MutableCounterBlueprint::new('+', '-');
+    let records: [&[char]; 3] = [&['+', '-'], &['+', 'x'], &['+']];
+
+    let results = blueprint.mutation_characterise_records(records);
+    assert_eq!(results[0], Ok(BasicStateSort::Accept));
+    assert!(results[1].is_err());
+    assert_eq!(results[2], Ok(BasicStateSort::Reject));
+
+    let mut automaton = MutationAutomaton::new(&blueprint);
+    automaton.update_state(&'+')?;
+    automaton.reset();
+    assert_eq!(*automaton.view_state(), 0);
+
+    Ok(())
+}
+
+struct CountingSortBlueprint {
+    sort_calls: std::cell::Cell<usize>,
+}
+
+impl MutationAutomatonBlueprint for CountingSortBlueprint {
+    type State = i32;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+    type ErrorType = String;
+
+    fn initial_mutation_state(&self) -> Self::State {
+        0
+    }
+
+    fn mutation_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        self.sort_calls.set(self.sort_calls.get() + 1);
+        if *state < 0 {
+            return Err("Negative count".to_string());
+        }
+        Ok(if *state == 0 { BasicStateSort::Accept } else { BasicStateSort::Reject })
+    }
+
+    fn mutation_transition_map(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        *state += if *character == 'a' { 1 } else { -1 };
+        Ok(())
+    }
+}
+
+#[test]
+fn cached_mutation_automaton_recomputes_only_after_changes() -> Result<(), String> {
+    let blueprint = CountingSortBlueprint { sort_calls: std::cell::Cell::new(0) };
+    let mut automaton = CachedMutationAutomaton::new(&blueprint);
+
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Accept);
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Accept);
+    assert_eq!(blueprint.sort_calls.get(), 1);
+
+    assert_eq!(automaton.update_sort_state(&'a')?, BasicStateSort::Reject);
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Reject);
+    assert_eq!(blueprint.sort_calls.get(), 2);
+
+    automaton.modify_state(|state| *state = 0);
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Accept);
+    assert_eq!(blueprint.sort_calls.get(), 3);
+
+    automaton.reset();
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Accept);
+    assert_eq!(blueprint.sort_calls.get(), 4);
+    Ok(())
+}
+
+#[test]
+fn cached_mutation_automaton_does_not_cache_errors() {
+    let blueprint = CountingSortBlueprint { sort_calls: std::cell::Cell::new(0) };
+    let mut automaton = CachedMutationAutomaton::new(&blueprint);
+
+    assert!(automaton.update_sort_state(&'b').is_err());
+    assert!(automaton.current_state_sort().is_err());
+    assert_eq!(blueprint.sort_calls.get(), 2);
+    assert_eq!(automaton.take_state(), -1);
+}
+
+#[test]
+fn mutation_trace_diff_stops_at_first_disagreement() -> Result<(), String> {
+    let blueprint = MutableCounterBlueprint::new('+', '-');
+    let (accept, reject) = (BasicStateSort::Accept, BasicStateSort::Reject);
+
+    assert_eq!(blueprint.mutation_trace_diff(&['+', '-'], &[reject, accept])?, None);
+    assert_eq!(blueprint.mutation_trace_diff(&['+', '-', '-'], &[reject, accept, accept])?, Some(2));
+    // The invalid symbol is never read
+    assert_eq!(blueprint.mutation_trace_diff(&['-', 'x'], &[accept, accept])?, Some(0));
+    assert!(blueprint.mutation_trace_diff(&['+', 'x'], &[reject, reject]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn mutation_accepting_snapshots_record_balanced_points() -> Result<(), String> {
+    let blueprint = MutableCounterBlueprint::new('+', '-');
+
+    assert_eq!(blueprint.mutation_accepting_snapshots(&['+', '-', '-', '+'])?, vec![(0, 0), (2, 0), (4, 0)]);
+    assert!(blueprint.mutation_accepting_snapshots(&['+', 'x']).is_err());
+
+    Ok(())
+}
+
+/// A state that counts how often it is cloned, standing in for an expensive buffer.
+#[derive(Debug, PartialEq)]
+struct LoggedState {
+    symbols: Vec<char>,
+    clones: std::rc::Rc<std::cell::Cell<usize>>
+}
+
+impl Clone for LoggedState {
+    fn clone(&self) -> Self {
+        self.clones.set(self.clones.get() + 1);
+        Self { symbols: self.symbols.clone(), clones: self.clones.clone() }
+    }
+}
+
+/// Records every symbol read, accepting after an even number of them.
+struct SymbolLog {
+    clones: std::rc::Rc<std::cell::Cell<usize>>,
+    in_place: bool
+}
+
+impl DeterministicAutomatonBlueprint for SymbolLog {
+    type State = LoggedState;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        LoggedState { symbols: Vec::new(), clones: self.clones.clone() }
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(BasicStateSort::from(state.symbols.len() % 2 == 0))
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        let mut next = state.clone();
+        next.symbols.push(*character);
+        Ok(next)
+    }
+
+    fn transition_in_place(&self, state: &mut Self::State, character: &Self::Alphabet) -> Result<(), Self::ErrorType> {
+        if self.in_place {
+            state.symbols.push(*character);
+            Ok(())
+        } else {
+            *state = self.transition_map(state, character)?;
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn transition_in_place_override_avoids_clones_in_mutation_runs() -> Result<(), String> {
+    let word: Vec<char> = "abcdefgh".chars().collect();
+
+    let naive = SymbolLog { clones: Default::default(), in_place: false };
+    assert_eq!(naive.mutation_characterise(&word)?, BasicStateSort::Accept);
+    assert_eq!(naive.clones.get(), word.len());
+
+    let in_place = SymbolLog { clones: Default::default(), in_place: true };
+    assert_eq!(in_place.mutation_characterise(&word)?, BasicStateSort::Accept);
+    assert_eq!(in_place.clones.get(), 0);
+
+    // Both paths reach the same state
+    let mut naive_automaton = naive.mutation_automaton();
+    let mut in_place_automaton = in_place.mutation_automaton();
+    for character in &word {
+        naive_automaton.update_state(character)?;
+        in_place_automaton.update_state(character)?;
+    }
+    assert_eq!(naive_automaton.view_state().symbols, in_place_automaton.view_state().symbols);
+
+    Ok(())
+}
+
+#[test]
+fn transition_in_place_override_is_reached_through_wrappers() -> Result<(), String> {
+    use deterministic_automata::either_automaton::deterministic::Either;
+    use deterministic_automata::operations::ComplementAutomatonBlueprint;
+
+    let word: Vec<char> = "abcdefgh".chars().collect();
+    let in_place = SymbolLog { clones: Default::default(), in_place: true };
+
+    let complement = ComplementAutomatonBlueprint::new(&in_place);
+    assert_eq!(complement.mutation_characterise(&word)?, BasicStateSort::Reject);
+    assert_eq!(in_place.clones.get(), 0);
+
+    let either: Either<SymbolLog, SymbolLog> = Either::Left(SymbolLog { clones: in_place.clones.clone(), in_place: true });
+    assert_eq!(either.mutation_characterise(&word)?, BasicStateSort::Accept);
+    assert_eq!(in_place.clones.get(), 0);
+
+    // Only the stored initial state is cloned, once at the start of the run
+    let resumed = in_place.with_initial(in_place.initial_state());
+    assert_eq!(resumed.mutation_characterise(&word)?, BasicStateSort::Accept);
+    assert_eq!(in_place.clones.get(), 1);
+
+    Ok(())
+}
@@ -202,4 +202,33 @@ fn mutation_either_debug_format() {
     
     assert!(left_debug.contains("Left"));
     assert!(right_debug.contains("Right"));
-}
\ No newline at end of file
+}
+
+#[test]
+fn mutation_either_automaton_runs_chosen_side() -> Result<(), String> {
+    let blueprint: Either<SimpleMutationBlueprint, SimpleMutationBlueprint> = Either::Right(SimpleMutationBlueprint::new('(', ')'));
+    let mut automaton = blueprint.either_automaton();
+
+    assert_eq!(automaton.update_sort_state(&'(')?, BasicStateSort::Reject);
+    assert_eq!(automaton.update_sort_state(&')')?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn mutation_either_supports_provided_blueprint_methods() -> Result<(), String> {
+    let blueprint: Either<SimpleMutationBlueprint, SimpleMutationBlueprint> = Either::Left(SimpleMutationBlueprint::new('+', '-'));
+
+    assert_eq!(blueprint.mutation_characterise_zip(&['+', '-'])?, vec![
+        ('+', BasicStateSort::Reject),
+        ('-', BasicStateSort::Accept),
+    ]);
+    assert_eq!(blueprint.mutation_characterise_counted(&['+', '-'])?, (BasicStateSort::Accept, 2));
+    assert_eq!(blueprint.mutation_state_path(&['+', '-'])?.len(), 3);
+
+    let automaton = blueprint.either_automaton();
+    assert_eq!(automaton.try_transition(&'+')?, BasicStateSort::Reject);
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Accept);
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use deterministic_automata::*;
 use deterministic_automata::MutationAutomatonBlueprint;
+use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterState};
 use deterministic_automata::product_automaton::{MutationProductAutomatonBlueprint, MutationBasicUnionAutomatonBlueprint, MutationBasicIntersectionAutomatonBlueprint};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -256,4 +257,57 @@ fn mutation_union_vs_intersection_comparison() -> Result<(), String> {
     }
     
     Ok(())
-}
\ No newline at end of file
+}
+
+#[test]
+fn mutation_product_automaton_component_states() -> Result<(), String> {
+    let blueprint_a = MutableCounterBlueprint::new('a', 'b');
+    let blueprint_b = MutableCounterBlueprint::new('b', 'a');
+    let product = MutationProductAutomatonBlueprint::new(&blueprint_a, &blueprint_b);
+
+    let mut automaton = product.mutation_automaton();
+    for character in str_to_vec_char("aab") {
+        automaton.update_state(&character)?;
+    }
+
+    assert_eq!(MutationProductAutomatonBlueprint::component_states(&automaton), (&1, &-1));
+    assert_eq!(MutationProductAutomatonBlueprint::take_component_states(automaton), (1, -1));
+
+    Ok(())
+}
+
+#[test]
+fn mutation_product_automaton_final_counter_states() -> Result<(), String> {
+    let blueprint_a = CounterAutomatonBlueprint::new('a', 'b');
+    let blueprint_b = CounterAutomatonBlueprint::new('a', 'b');
+    let product = MutationProductAutomatonBlueprint::new(&blueprint_a, &blueprint_b);
+
+    let mut automaton = product.mutation_automaton();
+    for character in str_to_vec_char("aaabb") {
+        automaton.update_state(&character)?;
+    }
+
+    let (first, second) = MutationProductAutomatonBlueprint::take_component_states(automaton);
+    assert!(matches!(first, CounterState::End(1)));
+    assert!(matches!(second, CounterState::End(1)));
+
+    Ok(())
+}
+
+#[test]
+fn mutation_product_automaton_divergence_index() -> Result<(), String> {
+    let blueprint_a = MutableCounterBlueprint::new('a', 'b');
+    let blueprint_b = SimpleAcceptBlueprint::new(vec!['a', 'b']);
+    let product = MutationProductAutomatonBlueprint::new(&blueprint_a, &blueprint_b);
+
+    assert_eq!(product.divergence_index(&str_to_vec_char(""))?, None);
+    assert_eq!(product.divergence_index(&str_to_vec_char("ab"))?, Some(1));
+    assert_eq!(product.divergence_index(&str_to_vec_char("xab"))?, Some(1));
+
+    let mirrored_a = MutableCounterBlueprint::new('a', 'b');
+    let mirrored_b = MutableCounterBlueprint::new('b', 'a');
+    let mirrored = MutationProductAutomatonBlueprint::new(&mirrored_a, &mirrored_b);
+    assert_eq!(mirrored.divergence_index(&str_to_vec_char("abba"))?, None);
+
+    Ok(())
+}
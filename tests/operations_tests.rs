@@ -0,0 +1,858 @@
+use deterministic_automata::*;
+use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterState};
+use deterministic_automata::product_automaton::{BasicUnionAutomatonBlueprint, BasicIntersectionAutomatonBlueprint};
+use deterministic_automata::operations::{agree, are_isomorphic, ErrorsAsRejectBlueprint, compile_to_table, complement, tri_complement, derivative, equivalence_classes, intersection_nonempty_bounded, is_live, is_prefix_closed, live_next_symbols, only_left, only_right, predecessors, prefix_closure, repeat, split_characterise, state_count, tee, trap_states, TaggedAlphabet};
+use deterministic_automata::fn_automaton::FnBlueprint;
+use deterministic_automata::mod_counter_example::ModSumBlueprint;
+
+struct PartialBlueprint;
+
+impl DeterministicAutomatonBlueprint for PartialBlueprint {
+    type State = usize;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        0
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(if *state % 2 == 0 { BasicStateSort::Accept } else { BasicStateSort::Reject })
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        match character {
+            'a' => Ok(state + 1),
+            _ => Err(format!("No transition on {}", character)),
+        }
+    }
+}
+
+fn str_to_vec_char(s: &str) -> Vec<char> {
+    s.chars().collect()
+}
+
+#[test]
+fn complement_flips_classification() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let not_blueprint = complement(&blueprint);
+
+    assert_eq!(not_blueprint.characterise(&str_to_vec_char(""))?, BasicStateSort::Reject);
+    assert_eq!(not_blueprint.characterise(&str_to_vec_char("aabb"))?, BasicStateSort::Reject);
+    assert_eq!(not_blueprint.characterise(&str_to_vec_char("aab"))?, BasicStateSort::Accept);
+    assert_eq!(not_blueprint.characterise(&str_to_vec_char("ba"))?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn complement_twice_is_identity() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let not_blueprint = complement(&blueprint);
+    let not_not_blueprint = complement(&not_blueprint);
+
+    for word in ["", "ab", "aab", "abab", "ba"] {
+        let input = str_to_vec_char(word);
+        assert_eq!(not_not_blueprint.characterise(&input)?, blueprint.characterise(&input)?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn complement_de_morgan_with_union() -> Result<(), String> {
+    let blueprint_a = CounterAutomatonBlueprint::new('a', 'b');
+    let blueprint_b = CounterAutomatonBlueprint::new('b', 'a');
+
+    let union = BasicUnionAutomatonBlueprint::new(&blueprint_a, &blueprint_b);
+    let not_union = complement(&union);
+
+    let not_a = complement(&blueprint_a);
+    let not_b = complement(&blueprint_b);
+    let intersection_of_complements = BasicIntersectionAutomatonBlueprint::new(&not_a, &not_b);
+
+    for word in ["", "ab", "ba", "aabb", "bbaa", "abab", "a", "b"] {
+        let input = str_to_vec_char(word);
+        assert_eq!(not_union.characterise(&input)?, intersection_of_complements.characterise(&input)?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn complement_inherits_partial_errors() {
+    let blueprint = PartialBlueprint;
+    let not_blueprint = complement(&blueprint);
+
+    assert_eq!(not_blueprint.characterise(&['a']), Ok(BasicStateSort::Accept));
+    assert_eq!(not_blueprint.characterise(&['a', 'b']), Err("No transition on b".to_string()));
+}
+
+#[test]
+fn with_initial_overrides_only_initial_state() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let resumed = blueprint.with_initial(CounterState::End(2));
+
+    assert_eq!(resumed.characterise(&str_to_vec_char(""))?, BasicStateSort::Reject);
+    assert_eq!(resumed.characterise(&str_to_vec_char("bb"))?, BasicStateSort::Accept);
+    assert_eq!(resumed.characterise(&str_to_vec_char("abb"))?, BasicStateSort::Reject);
+
+    assert_eq!(blueprint.characterise(&str_to_vec_char(""))?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn mutation_with_initial_overrides_only_initial_state() -> Result<(), String> {
+    let blueprint = PartialBlueprint;
+    let resumed = blueprint.mutation_with_initial(3);
+
+    assert_eq!(resumed.mutation_characterise(&['a'])?, BasicStateSort::Accept);
+    assert_eq!(*resumed.mutation_automaton().view_state(), 3);
+    assert!(resumed.mutation_characterise(&['b']).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn agree_compares_final_verdicts() -> Result<(), String> {
+    let original = CounterAutomatonBlueprint::new('a', 'b');
+    let not_not = complement(&original);
+    let refactored = complement(&not_not);
+
+    for word in ["", "ab", "aabb", "aab", "ba", "abab"] {
+        assert!(agree(&original, &refactored, &str_to_vec_char(word))?);
+    }
+
+    let other = CounterAutomatonBlueprint::new('a', 'c');
+    assert!(!agree(&original, &other, &str_to_vec_char("ab"))?);
+    assert!(agree(&original, &other, &str_to_vec_char("aa"))?);
+
+    Ok(())
+}
+
+#[test]
+fn agree_short_circuits_on_error() {
+    let partial = PartialBlueprint;
+    let not_partial = complement(&partial);
+
+    assert_eq!(agree(&partial, &not_partial, &['a']), Ok(false));
+    assert_eq!(agree(&partial, &not_partial, &['b', 'a']), Err("No transition on b".to_string()));
+}
+
+#[test]
+fn tee_reports_both_verdicts_independently() {
+    let partial = PartialBlueprint;
+    let counter = CounterAutomatonBlueprint::new('a', 'b');
+
+    assert_eq!(tee(&partial, &counter, &['a', 'a']), (Ok(BasicStateSort::Accept), Ok(BasicStateSort::Reject)));
+
+    // An error in the first automaton does not stop the second
+    let (first, second) = tee(&partial, &counter, &['a', 'b']);
+    assert_eq!(first, Err("No transition on b".to_string()));
+    assert_eq!(second, Ok(BasicStateSort::Accept));
+
+    let (first, second) = tee(&counter, &partial, &['c']);
+    assert_eq!(first, Ok(BasicStateSort::Reject));
+    assert_eq!(second, Err("No transition on c".to_string()));
+}
+
+#[test]
+fn max_length_rejects_long_words() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let bounded = blueprint.max_length(4);
+
+    assert_eq!(bounded.characterise(&str_to_vec_char(""))?, BasicStateSort::Accept);
+    assert_eq!(bounded.characterise(&str_to_vec_char("aabb"))?, BasicStateSort::Accept);
+    assert_eq!(bounded.characterise(&str_to_vec_char("aab"))?, BasicStateSort::Reject);
+    assert_eq!(bounded.characterise(&str_to_vec_char("aaabbb"))?, BasicStateSort::Reject);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("aaabbb"))?, BasicStateSort::Accept);
+
+    Ok(())
+}
+
+#[test]
+fn max_length_stops_stepping_inner_after_limit() {
+    let blueprint = PartialBlueprint;
+    let bounded = blueprint.max_length(2);
+
+    assert_eq!(bounded.characterise(&['a', 'a', 'b']), Ok(BasicStateSort::Reject));
+    assert_eq!(bounded.characterise(&['a', 'b']), Err("No transition on b".to_string()));
+}
+
+#[test]
+fn min_length_rejects_short_words_despite_inner_acceptance() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let at_least_four = blueprint.min_length(4);
+
+    assert_eq!(blueprint.characterise(&str_to_vec_char(""))?, BasicStateSort::Accept);
+    assert_eq!(at_least_four.characterise(&str_to_vec_char(""))?, BasicStateSort::Reject);
+    assert_eq!(at_least_four.characterise(&str_to_vec_char("ab"))?, BasicStateSort::Reject);
+    assert_eq!(at_least_four.characterise(&str_to_vec_char("aabb"))?, BasicStateSort::Accept);
+    assert_eq!(at_least_four.characterise(&str_to_vec_char("aaabbb"))?, BasicStateSort::Accept);
+    assert_eq!(at_least_four.characterise(&str_to_vec_char("aaabb"))?, BasicStateSort::Reject);
+
+    Ok(())
+}
+
+#[test]
+fn non_empty_rejects_only_the_empty_word() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let non_empty = blueprint.non_empty();
+    let at_least_one = blueprint.min_length(1);
+
+    assert!(!non_empty.accepts_empty()?);
+    for word in ["ab", "aabb", "a", "ba", "abab"] {
+        let input = str_to_vec_char(word);
+        assert_eq!(non_empty.characterise(&input)?, blueprint.characterise(&input)?);
+        assert_eq!(non_empty.characterise(&input)?, at_least_one.characterise(&input)?);
+    }
+
+    let partial = PartialBlueprint;
+    assert_eq!(partial.non_empty().characterise(&['b']), Err("No transition on b".to_string()));
+
+    Ok(())
+}
+
+#[test]
+fn optional_adds_only_the_empty_word() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let at_least_two = blueprint.min_length(2);
+    let optional = at_least_two.optional();
+
+    assert!(optional.accepts_empty()?);
+    for word in ["ab", "aabb", "a", "ba", "abab"] {
+        let input = str_to_vec_char(word);
+        assert_eq!(optional.characterise(&input)?, at_least_two.characterise(&input)?);
+    }
+    assert_eq!(optional.example_accepted(), Some(vec![]));
+
+    // Undoes non_empty on a language that contained the empty word
+    let round_trip = blueprint.non_empty();
+    let round_trip = round_trip.optional();
+    for word in ["", "ab", "aab"] {
+        let input = str_to_vec_char(word);
+        assert_eq!(round_trip.characterise(&input)?, blueprint.characterise(&input)?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn case_insensitive_accepts_any_case_of_lowercase_language() -> Result<(), String> {
+    let lowercase_only = CounterAutomatonBlueprint::new('a', 'b');
+    let any_case = lowercase_only.case_insensitive();
+
+    for word in ["ab", "AB", "aB", "AaBb", ""] {
+        assert_eq!(any_case.characterise(&str_to_vec_char(word))?, BasicStateSort::Accept, "{}", word);
+    }
+    assert_eq!(lowercase_only.characterise(&str_to_vec_char("AB"))?, BasicStateSort::Reject);
+    assert_eq!(any_case.characterise(&str_to_vec_char("AAB"))?, BasicStateSort::Reject);
+
+    // Symbols the base automaton accepts verbatim are kept
+    let uppercase_only = CounterAutomatonBlueprint::new('A', 'B');
+    assert_eq!(uppercase_only.case_insensitive().characterise(&str_to_vec_char("AB"))?, BasicStateSort::Accept);
+    assert_eq!(uppercase_only.case_insensitive().characterise(&str_to_vec_char("ab"))?, BasicStateSort::Reject);
+
+    // Errors from either reading are propagated
+    assert_eq!(PartialBlueprint.case_insensitive().characterise(&['A']), Err("No transition on A".to_string()));
+
+    Ok(())
+}
+
+#[test]
+fn min_and_max_length_give_exact_length() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let at_least_four = blueprint.min_length(4);
+    let exactly_four = at_least_four.max_length(4);
+
+    assert_eq!(exactly_four.characterise(&str_to_vec_char("ab"))?, BasicStateSort::Reject);
+    assert_eq!(exactly_four.characterise(&str_to_vec_char("aabb"))?, BasicStateSort::Accept);
+    assert_eq!(exactly_four.characterise(&str_to_vec_char("aaabbb"))?, BasicStateSort::Reject);
+
+    Ok(())
+}
+
+// Accepts exactly the single-symbol words "a" and "b", and the empty word
+struct AtMostOneSymbol;
+
+impl InfallibleAutomatonBlueprint for AtMostOneSymbol {
+    type State = u8;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+
+    fn initial(&self) -> Self::State {
+        0
+    }
+
+    fn sort(&self, state: &Self::State) -> Self::StateSort {
+        if *state <= 1 { BasicStateSort::Accept } else { BasicStateSort::Reject }
+    }
+
+    fn transition(&self, state: &Self::State, character: &Self::Alphabet) -> Self::State {
+        if *state == 0 && (*character == 'a' || *character == 'b') { 1 } else { 2 }
+    }
+}
+
+#[test]
+fn repeat_recognizes_exact_number_of_words() {
+    let exactly_one = AtMostOneSymbol.min_length(1);
+    let three_symbols = repeat(&exactly_one, 3);
+
+    assert_eq!(three_symbols.characterise(&str_to_vec_char("aba")), Ok(BasicStateSort::Accept));
+    assert_eq!(three_symbols.characterise(&str_to_vec_char("bbb")), Ok(BasicStateSort::Accept));
+    assert_eq!(three_symbols.characterise(&str_to_vec_char("ab")), Ok(BasicStateSort::Reject));
+    assert_eq!(three_symbols.characterise(&str_to_vec_char("abab")), Ok(BasicStateSort::Reject));
+    assert_eq!(three_symbols.characterise(&str_to_vec_char("acb")), Ok(BasicStateSort::Reject));
+}
+
+#[test]
+fn repeat_with_nullable_inner_language() {
+    let twice = repeat(&AtMostOneSymbol, 2);
+
+    assert_eq!(twice.characterise(&str_to_vec_char("")), Ok(BasicStateSort::Accept));
+    assert_eq!(twice.characterise(&str_to_vec_char("a")), Ok(BasicStateSort::Accept));
+    assert_eq!(twice.characterise(&str_to_vec_char("ab")), Ok(BasicStateSort::Accept));
+    assert_eq!(twice.characterise(&str_to_vec_char("abb")), Ok(BasicStateSort::Reject));
+}
+
+#[test]
+fn repeat_zero_times_accepts_only_empty_word() {
+    let never = repeat(&AtMostOneSymbol, 0);
+
+    assert_eq!(never.characterise(&str_to_vec_char("")), Ok(BasicStateSort::Accept));
+    assert_eq!(never.characterise(&str_to_vec_char("a")), Ok(BasicStateSort::Reject));
+}
+
+#[test]
+fn compile_to_table_matches_original_automaton() -> Result<(), String> {
+    let alphabet = ['a', 'b', 'c'];
+    let Ok(table) = compile_to_table(&AtMostOneSymbol, &alphabet);
+
+    assert_eq!(table.state_count(), 3);
+
+    for word in ["", "a", "b", "c", "ab", "ba"] {
+        let ids: Vec<usize> = word.chars().map(|c| alphabet.iter().position(|s| *s == c).unwrap()).collect();
+        assert_eq!(table.classify_ids(&ids), AtMostOneSymbol.infallible_characterise(&str_to_vec_char(word)));
+        assert_eq!(table.characterise(&ids)?, table.classify_ids(&ids));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn compile_to_table_reports_out_of_range_symbols_as_errors() -> Result<(), String> {
+    let Ok(table) = compile_to_table(&AtMostOneSymbol, &['a']);
+
+    assert_eq!(table.characterise(&[0]), Ok(BasicStateSort::Accept));
+    assert_eq!(table.characterise(&[1]), Err("Symbol 1 out of range".to_string()));
+
+    Ok(())
+}
+
+#[test]
+fn compile_to_table_propagates_automaton_errors() {
+    assert_eq!(compile_to_table(&PartialBlueprint, &['b']).map(|table| table.state_count()), Err("No transition on b".to_string()));
+}
+
+#[test]
+fn is_prefix_closed_detects_gaps() {
+    let exactly_one = AtMostOneSymbol.min_length(1);
+
+    assert_eq!(is_prefix_closed(&AtMostOneSymbol, &['a', 'b', 'c']), Ok(true));
+    assert_eq!(is_prefix_closed(&exactly_one, &['a', 'b', 'c']), Ok(false));
+    assert_eq!(is_prefix_closed(&PartialBlueprint, &['b']), Err("No transition on b".to_string()));
+}
+
+#[test]
+fn prefix_closure_accepts_extendable_inputs() {
+    let exactly_one = AtMostOneSymbol.min_length(1);
+    let two_symbols = repeat(&exactly_one, 2);
+    let Ok(partial) = prefix_closure(&two_symbols, &['a', 'b', 'c']);
+
+    assert_eq!(partial.characterise(&str_to_vec_char("")), Ok(BasicStateSort::Accept));
+    assert_eq!(partial.characterise(&str_to_vec_char("a")), Ok(BasicStateSort::Accept));
+    assert_eq!(partial.characterise(&str_to_vec_char("ab")), Ok(BasicStateSort::Accept));
+    assert_eq!(partial.characterise(&str_to_vec_char("abb")), Ok(BasicStateSort::Reject));
+    assert_eq!(partial.characterise(&str_to_vec_char("c")), Ok(BasicStateSort::Reject));
+    assert_eq!(is_prefix_closed(&partial, &['a', 'b', 'c']), Ok(true));
+}
+
+#[test]
+fn equivalence_classes_groups_indistinguishable_states() -> Result<(), String> {
+    // Counts symbols modulo 6 but only tests divisibility by 3
+    let mod_six = FnBlueprint::new(
+        || 0u8,
+        |count| Ok::<_, String>(if count % 3 == 0 { BasicStateSort::Accept } else { BasicStateSort::Reject }),
+        |count, _: &char| Ok((count + 1) % 6),
+    );
+
+    assert_eq!(equivalence_classes(&mod_six, &[0, 1, 2, 3, 4, 5], &['a'])?, vec![vec![0, 3], vec![1, 4], vec![2, 5]]);
+    // Successors are explored even when not requested, and duplicates are dropped
+    assert_eq!(equivalence_classes(&mod_six, &[4, 1, 4], &['a'])?, vec![vec![4, 1]]);
+
+    Ok(())
+}
+
+#[test]
+fn equivalence_classes_separates_states_with_equal_sorts() -> Result<(), String> {
+    // Only 0 accepts, so every residue needs a different number of symbols to reach acceptance
+    let mod_six = FnBlueprint::new(
+        || 0u8,
+        |count| Ok::<_, String>(if *count == 0 { BasicStateSort::Accept } else { BasicStateSort::Reject }),
+        |count, _: &char| Ok((count + 1) % 6),
+    );
+
+    assert_eq!(equivalence_classes(&mod_six, &[1, 2, 3], &['a'])?, vec![vec![1], vec![2], vec![3]]);
+    assert!(equivalence_classes(&PartialBlueprint, &[0], &['b']).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn intersection_nonempty_bounded_finds_shortest_common_word() -> Result<(), String> {
+    let ab = CounterAutomatonBlueprint::new('a', 'b');
+    let balance_zero = CounterAutomatonBlueprint::with_tolerance('a', 'b', 0..=0);
+    let three_or_more = ab.min_length(3);
+
+    assert_eq!(intersection_nonempty_bounded(&ab, &balance_zero, &['a', 'b'], 4)?, Some(vec![]));
+    assert_eq!(intersection_nonempty_bounded(&ab, &three_or_more, &['a', 'b'], 4)?, Some(str_to_vec_char("aabb")));
+    // The only common words are longer than the bound
+    assert_eq!(intersection_nonempty_bounded(&ab, &three_or_more, &['a', 'b'], 3)?, None);
+
+    Ok(())
+}
+
+#[test]
+fn intersection_nonempty_bounded_propagates_errors() {
+    let ab = CounterAutomatonBlueprint::new('a', 'b');
+
+    assert_eq!(intersection_nonempty_bounded(&PartialBlueprint, &ab, &['a'], 3), Ok(Some(vec![])));
+    assert!(intersection_nonempty_bounded(&PartialBlueprint, &complement(&ab), &['b'], 3).is_err());
+}
+
+#[test]
+fn tagged_alphabet_routes_symbols_to_components() -> Result<(), String> {
+    use deterministic_automata::product_automaton::ProductAutomatonBlueprint;
+    use TaggedAlphabet::{Left, Right};
+
+    let letters = CounterAutomatonBlueprint::new('a', 'b');
+    let digits = CounterAutomatonBlueprint::new(0u8, 1u8);
+    let (left, right) = (only_left(&letters), only_right(&digits));
+    let product = ProductAutomatonBlueprint::new(&left, &right);
+
+    // Interleaving does not matter, since each component only sees its own symbols
+    assert_eq!(product.characterise(&[Left('a'), Right(0), Left('b'), Right(1)])?, (BasicStateSort::Accept, BasicStateSort::Accept));
+    assert_eq!(product.characterise(&[Left('a'), Right(0), Right(1)])?, (BasicStateSort::Reject, BasicStateSort::Accept));
+    assert_eq!(product.characterise(&[Right(1), Left('a'), Left('b')])?, (BasicStateSort::Accept, BasicStateSort::Reject));
+
+    Ok(())
+}
+
+#[test]
+fn tagged_alphabet_foreign_symbols_are_self_loops() -> Result<(), String> {
+    let lifted = only_left::<_, char>(&PartialBlueprint);
+
+    // PartialBlueprint errors on 'b', but a right-tagged 'b' never reaches it
+    assert_eq!(lifted.characterise(&[TaggedAlphabet::Left('a'), TaggedAlphabet::Right('b')])?, BasicStateSort::Reject);
+    assert!(lifted.characterise(&[TaggedAlphabet::Left('b')]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn derivative_accepts_completing_suffixes() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let after_aa = derivative(&blueprint, &str_to_vec_char("aa"))?;
+
+    assert_eq!(after_aa.characterise(&str_to_vec_char("bb"))?, BasicStateSort::Accept);
+    assert_eq!(after_aa.characterise(&str_to_vec_char("b"))?, BasicStateSort::Reject);
+    assert_eq!(after_aa.characterise(&str_to_vec_char("abbb"))?, BasicStateSort::Accept);
+    assert_eq!(after_aa.characterise(&str_to_vec_char(""))?, BasicStateSort::Reject);
+
+    // Every suffix of the quotient completes the prefix
+    for suffix in ["bb", "abbb", "aabbbb"] {
+        let word: Vec<char> = "aa".chars().chain(suffix.chars()).collect();
+        assert_eq!(blueprint.characterise(&word)?, after_aa.characterise(&str_to_vec_char(suffix))?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn derivative_by_empty_prefix_is_identity_and_errors_propagate() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let same = derivative(&blueprint, &[])?;
+
+    for word in ["", "ab", "ba", "aabb"] {
+        assert_eq!(same.characterise(&str_to_vec_char(word))?, blueprint.characterise(&str_to_vec_char(word))?);
+    }
+    assert!(derivative(&PartialBlueprint, &['a', 'b']).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn trap_states_finds_unrecoverable_states() -> Result<(), String> {
+    // Counts symbols up to 3, where 3 is absorbing; only 1 accepts
+    let saturating = FnBlueprint::new(
+        || 0u8,
+        |count| Ok::<_, String>(if *count == 1 { BasicStateSort::Accept } else { BasicStateSort::Reject }),
+        |count, _: &char| Ok((count + 1).min(3)),
+    );
+
+    // 0 rejects but can still reach 1, so only 2 and 3 are traps
+    assert_eq!(trap_states(&saturating, &[0], &['a'])?, vec![2, 3]);
+    assert_eq!(trap_states(&saturating, &[3], &['a'])?, vec![3]);
+
+    Ok(())
+}
+
+#[test]
+fn trap_states_empty_when_acceptance_stays_reachable() -> Result<(), String> {
+    let cyclic = FnBlueprint::new(
+        || 0u8,
+        |count| Ok::<_, String>(if *count == 0 { BasicStateSort::Accept } else { BasicStateSort::Reject }),
+        |count, _: &char| Ok((count + 1) % 4),
+    );
+
+    assert!(trap_states(&cyclic, &[0], &['a'])?.is_empty());
+    assert!(trap_states(&PartialBlueprint, &[0], &['b']).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn predecessors_brute_forces_the_supplied_domains() -> Result<(), String> {
+    let partial = PartialBlueprint;
+
+    assert_eq!(predecessors(&partial, &[0, 1, 2, 3], &['a'], &2)?, vec![(1, 'a')]);
+    assert_eq!(predecessors(&partial, &[1, 2, 3], &['a'], &0)?, vec![]);
+    assert_eq!(predecessors(&partial, &[0], &['a', 'b'], &1), Err("No transition on b".to_string()));
+
+    let divisible = ModSumBlueprint::new(2);
+    assert_eq!(predecessors(&divisible, &[0, 1], &[0, 1, 2], &1)?, vec![(0, 1), (1, 0), (1, 2)]);
+
+    Ok(())
+}
+
+#[test]
+fn is_live_detects_viable_prefixes() -> Result<(), String> {
+    let balanced = CounterAutomatonBlueprint::new('a', 'b');
+    let alphabet = ['a', 'b'];
+
+    for prefix in ["", "aaa", "aab", "ab"] {
+        let state = balanced.characterise_partial(&str_to_vec_char(prefix))?.take_state();
+        assert!(is_live(&balanced, &state, &alphabet)?, "{}", prefix);
+    }
+    for prefix in ["b", "aba", "abb"] {
+        let state = balanced.characterise_partial(&str_to_vec_char(prefix))?.take_state();
+        assert_eq!(state, CounterState::Reject);
+        assert!(!is_live(&balanced, &state, &alphabet)?, "{}", prefix);
+    }
+
+    // Over an empty alphabet only the state itself is reachable
+    let partial = PartialBlueprint;
+    assert!(!is_live(&partial, &1, &[])?);
+    assert!(is_live(&partial, &1, &['a'])?);
+    assert_eq!(is_live(&partial, &1, &['b']), Err("No transition on b".to_string()));
+
+    Ok(())
+}
+
+/// Accepts after an even number of 'a's, rejects after an odd number, and is undecided
+/// once anything else has been read.
+struct TriParityBlueprint;
+
+impl DeterministicAutomatonBlueprint for TriParityBlueprint {
+    type State = Option<usize>;
+    type Alphabet = char;
+    type StateSort = TriStateSort;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        Some(0)
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(match state {
+            Some(count) if count % 2 == 0 => TriStateSort::Accept,
+            Some(_) => TriStateSort::Reject,
+            None => TriStateSort::Unknown,
+        })
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok(match (state, character) {
+            (Some(count), 'a') => Some(count + 1),
+            _ => None,
+        })
+    }
+}
+
+#[test]
+fn tri_complement_swaps_decided_verdicts_and_keeps_unknown() {
+    let negated = tri_complement(&TriParityBlueprint);
+
+    assert_eq!(negated.characterise(&[]).unwrap(), TriStateSort::Reject);
+    assert_eq!(negated.characterise(&['a']).unwrap(), TriStateSort::Accept);
+    assert_eq!(negated.characterise(&['a', 'b']).unwrap(), TriStateSort::Unknown);
+}
+
+#[test]
+fn tri_complement_twice_is_identity() {
+    let negated = tri_complement(&TriParityBlueprint);
+    let restored = tri_complement(&negated);
+
+    for word in [vec![], vec!['a'], vec!['a', 'a'], vec!['b'], vec!['a', 'b', 'a']] {
+        assert_eq!(restored.characterise(&word).unwrap(), TriParityBlueprint.characterise(&word).unwrap());
+    }
+    for sort in [TriStateSort::Accept, TriStateSort::Reject, TriStateSort::Unknown] {
+        assert_eq!(sort.negate().negate(), sort);
+    }
+}
+
+#[test]
+fn state_count_counts_reachable_states_only() {
+    // Only even residues are reachable with even digits
+    assert_eq!(state_count(&ModSumBlueprint::new(6), &[2, 4], None).unwrap(), Some(3));
+    assert_eq!(state_count(&ModSumBlueprint::new(6), &[], None).unwrap(), Some(1));
+}
+
+#[test]
+fn state_count_stops_at_cap() {
+    let balanced = CounterAutomatonBlueprint::new('a', 'b');
+    assert_eq!(state_count(&balanced, &['a', 'b'], Some(50)).unwrap(), None);
+
+    // A cap equal to the state count is not exceeded
+    assert_eq!(state_count(&ModSumBlueprint::new(4), &[1], Some(4)).unwrap(), Some(4));
+    assert_eq!(state_count(&ModSumBlueprint::new(4), &[1], Some(3)).unwrap(), None);
+    assert_eq!(state_count(&ModSumBlueprint::new(4), &[1], Some(0)).unwrap(), None);
+}
+
+#[test]
+fn state_count_propagates_transition_errors() {
+    assert!(state_count(&PartialBlueprint, &['a', 'b'], Some(10)).is_err());
+}
+
+#[test]
+fn live_next_symbols_excludes_dooming_symbols() {
+    let balanced = CounterAutomatonBlueprint::new('a', 'b');
+    let alphabet = ['a', 'b', 'c'];
+
+    assert_eq!(live_next_symbols(&balanced, &CounterState::Start(0), &alphabet).unwrap(), vec!['a']);
+    // After "aa" both another 'a' and a closing 'b' remain viable, after "aab" only 'b'
+    assert_eq!(live_next_symbols(&balanced, &CounterState::Start(2), &alphabet).unwrap(), vec!['a', 'b']);
+    assert_eq!(live_next_symbols(&balanced, &CounterState::End(1), &alphabet).unwrap(), vec!['b']);
+    assert!(live_next_symbols(&balanced, &CounterState::Reject, &alphabet).unwrap().is_empty());
+}
+
+#[test]
+fn live_next_symbols_propagates_transition_errors() {
+    assert!(live_next_symbols(&PartialBlueprint, &0, &['a', 'b']).is_err());
+}
+
+/// Accepts exactly the two-byte header `[0x4D, 0x5A]`; the state is the number of header
+/// bytes matched, or `None` after a mismatch.
+struct TwoByteHeader;
+
+impl DeterministicAutomatonBlueprint for TwoByteHeader {
+    type State = Option<usize>;
+    type Alphabet = u8;
+    type StateSort = BasicStateSort;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        Some(0)
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(BasicStateSort::from(*state == Some(2)))
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        const HEADER: [u8; 2] = [0x4D, 0x5A];
+        Ok(state.filter(|&matched| HEADER.get(matched) == Some(character)).map(|matched| matched + 1))
+    }
+}
+
+#[test]
+fn split_characterise_checks_header_and_body_separately() {
+    let header = TwoByteHeader;
+    let body = ModSumBlueprint::new(3);
+
+    assert_eq!(split_characterise(&header, &body, &[0x4D, 0x5A, 1, 2], 2).unwrap(), BasicStateSort::Accept);
+    assert_eq!(split_characterise(&header, &body, &[0x4D, 0x5A], 2).unwrap(), BasicStateSort::Accept);
+    assert_eq!(split_characterise(&header, &body, &[0x4D, 0x5A, 1], 2).unwrap(), BasicStateSort::Reject);
+    // Header bytes in the body do not count towards the header
+    assert_eq!(split_characterise(&header, &body, &[0x4D, 1, 0x5A], 2).unwrap(), BasicStateSort::Reject);
+    assert_eq!(split_characterise(&header, &body, &[0x4D], 2).unwrap(), BasicStateSort::Reject);
+}
+
+#[test]
+fn split_characterise_skips_body_after_rejected_header() {
+    let header = TwoByteHeader;
+    let body = ModSumBlueprint::new(3);
+
+    // 0x4D is not a digit, so the body would fail if it were run
+    assert_eq!(split_characterise(&header, &body, &[0x00, 0x00, 0x4D], 2), Ok(BasicStateSort::Reject));
+    assert!(split_characterise(&header, &body, &[0x4D, 0x5A, 0x4D], 2).is_err());
+}
+
+#[test]
+fn errors_as_reject_converts_transition_errors() {
+    let blueprint = PartialBlueprint;
+    let lenient = blueprint.errors_as_reject();
+
+    assert_eq!(lenient.characterise(&str_to_vec_char("aa")), Ok(BasicStateSort::Accept));
+    assert_eq!(lenient.characterise(&str_to_vec_char("ab")), Ok(BasicStateSort::Reject));
+    // The reject state is permanent, even on symbols the wrapped automaton accepts
+    assert_eq!(lenient.characterise(&str_to_vec_char("aba")), Ok(BasicStateSort::Reject));
+    assert_eq!(lenient.characterise(&str_to_vec_char("abaa")), Ok(BasicStateSort::Reject));
+}
+
+#[test]
+fn errors_as_reject_propagates_filtered_out_errors() {
+    let blueprint = PartialBlueprint;
+    let selective = ErrorsAsRejectBlueprint::with_filter(&blueprint, |error: &String| error.ends_with('b'));
+
+    assert_eq!(selective.characterise(&str_to_vec_char("aab")), Ok(BasicStateSort::Reject));
+    assert_eq!(selective.characterise(&str_to_vec_char("aac")), Err("No transition on c".to_string()));
+    // Once rejected, later symbols are not fed to the wrapped automaton
+    assert_eq!(selective.characterise(&str_to_vec_char("abc")), Ok(BasicStateSort::Reject));
+}
+
+#[test]
+fn errors_as_reject_filter_can_capture_configuration() {
+    let blueprint = PartialBlueprint;
+    let foreign: std::collections::HashSet<char> = ['x', 'y'].into();
+    let selective = ErrorsAsRejectBlueprint::with_filter(&blueprint, |error: &String| {
+        error.chars().last().is_some_and(|symbol| foreign.contains(&symbol))
+    });
+
+    assert_eq!(selective.characterise(&str_to_vec_char("ay")), Ok(BasicStateSort::Reject));
+    assert_eq!(selective.characterise(&str_to_vec_char("ab")), Err("No transition on b".to_string()));
+}
+
+#[test]
+fn errors_as_reject_in_intersection_with_larger_alphabet() {
+    let only_a = PartialBlueprint;
+    let lenient = only_a.errors_as_reject();
+    let balanced = CounterAutomatonBlueprint::new('a', 'b');
+    let both = BasicIntersectionAutomatonBlueprint::new(&lenient, &balanced);
+
+    assert_eq!(both.characterise(&str_to_vec_char("")), Ok(BasicStateSort::Accept));
+    assert_eq!(both.characterise(&str_to_vec_char("ab")), Ok(BasicStateSort::Reject));
+    assert_eq!(both.characterise(&str_to_vec_char("aa")), Ok(BasicStateSort::Reject));
+}
+
+/// Length modulo `modulus`, accepting the residues in `accepting`.
+struct ResidueBlueprint {
+    modulus: usize,
+    accepting: Vec<usize>
+}
+
+impl DeterministicAutomatonBlueprint for ResidueBlueprint {
+    type State = usize;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        0
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(if self.accepting.contains(state) { BasicStateSort::Accept } else { BasicStateSort::Reject })
+    }
+
+    fn transition_map(&self, state: &Self::State, _character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok((state + 1) % self.modulus)
+    }
+}
+
+#[test]
+fn are_isomorphic_accepts_relabelled_automata() -> Result<(), String> {
+    let even = ResidueBlueprint { modulus: 2, accepting: vec![0] };
+    let copy = ResidueBlueprint { modulus: 2, accepting: vec![0] };
+    let table = compile_to_table(&even, &['a', 'b'])?;
+    let relabelled = FnBlueprint::new(
+        || 10usize,
+        |state: &usize| Ok(if *state == 10 { BasicStateSort::Accept } else { BasicStateSort::Reject }),
+        |state: &usize, _: &char| Ok(if *state == 10 { 11 } else { 10 })
+    );
+
+    assert!(are_isomorphic(&even, &copy, &['a', 'b'])?);
+    assert!(are_isomorphic(&even, &relabelled, &['a', 'b'])?);
+    assert!(are_isomorphic(&table, &compile_to_table(&relabelled, &['a', 'b'])?, &[0, 1])?);
+    Ok(())
+}
+
+#[test]
+fn are_isomorphic_distinguishes_equivalent_but_unminimized_automata() -> Result<(), String> {
+    // Same language (even length), but the second has redundant states
+    let minimal = ResidueBlueprint { modulus: 2, accepting: vec![0] };
+    let redundant = ResidueBlueprint { modulus: 4, accepting: vec![0, 2] };
+
+    assert_eq!(minimal.characterise(&str_to_vec_char("abab")), redundant.characterise(&str_to_vec_char("abab")));
+    assert!(!are_isomorphic(&minimal, &redundant, &['a'])?);
+    assert!(!are_isomorphic(&redundant, &minimal, &['a'])?);
+    Ok(())
+}
+
+#[test]
+fn are_isomorphic_compares_classifications() -> Result<(), String> {
+    let even = ResidueBlueprint { modulus: 2, accepting: vec![0] };
+    let odd = ResidueBlueprint { modulus: 2, accepting: vec![1] };
+
+    assert!(!are_isomorphic(&even, &odd, &['a'])?);
+    Ok(())
+}
+
+#[test]
+fn are_isomorphic_propagates_errors() {
+    let partial = PartialBlueprint;
+    let even = ResidueBlueprint { modulus: 2, accepting: vec![0] };
+
+    assert_eq!(are_isomorphic(&partial, &even, &['b']), Err("No transition on b".to_string()));
+}
+
+#[test]
+fn track_peak_records_maximum_open_count() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let opened = blueprint.track_peak(|state| match state {
+        CounterState::Start(count) => *count,
+        _ => 0
+    });
+
+    for (word, peak) in [("", 0), ("aab", 2), ("aaabbb", 3), ("aba", 1)] {
+        let input = str_to_vec_char(word);
+        let run = opened.characterise_partial(&input)?;
+        assert_eq!(run.current_state_sort()?, blueprint.characterise(&input)?);
+        assert_eq!(run.view_state().1, peak);
+    }
+    Ok(())
+}
+
+#[test]
+fn track_peak_keeps_peak_after_metric_falls() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let remaining = blueprint.track_peak(|state| match state {
+        CounterState::Start(count) | CounterState::End(count) => *count,
+        _ => 0
+    });
+
+    let run = remaining.characterise_partial(&str_to_vec_char("aaaabbbb"))?;
+    assert_eq!(run.view_state(), &(CounterState::End(0), 4));
+    Ok(())
+}
+
+#[test]
+fn track_peak_propagates_errors() {
+    let blueprint = PartialBlueprint;
+    let peak = blueprint.track_peak(|state| *state);
+
+    assert_eq!(peak.characterise(&str_to_vec_char("aa")), Ok(BasicStateSort::Accept));
+    assert_eq!(peak.characterise(&str_to_vec_char("ab")), Err("No transition on b".to_string()));
+}
@@ -0,0 +1,186 @@
+use deterministic_automata::*;
+use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
+use deterministic_automata::primitives::{AtLeastKBlueprint, AtMostKBlueprint, ContainsSubsequenceBlueprint, EvenLengthBlueprint, ExactlyKBlueprint, LengthModBlueprint, NoRepeatsBlueprint, NoRepeatsState};
+use deterministic_automata::product_automaton::BasicIntersectionAutomatonBlueprint;
+
+fn str_to_vec_char(s: &str) -> Vec<char> {
+    s.chars().collect()
+}
+
+#[test]
+fn no_repeats_accepts_alternating_words() {
+    let blueprint: NoRepeatsBlueprint<char> = NoRepeatsBlueprint::new();
+
+    let Ok(sort) = blueprint.characterise(&str_to_vec_char("abab"));
+    assert_eq!(sort, BasicStateSort::Accept);
+    let Ok(sort) = blueprint.characterise(&str_to_vec_char(""));
+    assert_eq!(sort, BasicStateSort::Accept);
+    let Ok(sort) = blueprint.characterise(&str_to_vec_char("abcba"));
+    assert_eq!(sort, BasicStateSort::Accept);
+}
+
+#[test]
+fn no_repeats_rejects_adjacent_equal_symbols() {
+    let blueprint: NoRepeatsBlueprint<char> = NoRepeatsBlueprint::new();
+
+    let Ok(sort) = blueprint.characterise(&str_to_vec_char("aabb"));
+    assert_eq!(sort, BasicStateSort::Reject);
+    // Rejection is permanent even if later symbols alternate
+    let Ok(sort) = blueprint.characterise(&str_to_vec_char("abbab"));
+    assert_eq!(sort, BasicStateSort::Reject);
+    let Ok(state) = blueprint.transition_map(&NoRepeatsState::Last(Some('a')), &'a');
+    assert_eq!(state, NoRepeatsState::Repeated);
+}
+
+#[test]
+fn no_repeats_composes_by_intersection() -> Result<(), String> {
+    let no_repeats = NoRepeatsBlueprint::new();
+    let counter = CounterAutomatonBlueprint::new('a', 'b');
+    let both = BasicIntersectionAutomatonBlueprint::new(&no_repeats, &counter);
+
+    assert_eq!(both.characterise(&str_to_vec_char("ab"))?, BasicStateSort::Accept);
+    assert_eq!(both.characterise(&str_to_vec_char("aabb"))?, BasicStateSort::Reject);
+
+    Ok(())
+}
+
+#[test]
+fn occurrence_bounds_classify_by_count() {
+    let at_most: AtMostKBlueprint<char> = AtMostKBlueprint::new('x', 1);
+    let exactly: ExactlyKBlueprint<char> = ExactlyKBlueprint::new('x', 1);
+    let at_least: AtLeastKBlueprint<char> = AtLeastKBlueprint::new('x', 1);
+
+    for (word, expected) in [("", [true, false, false]), ("yxy", [true, true, true]), ("xyx", [false, false, true])] {
+        let input = str_to_vec_char(word);
+        let Ok(at_most_sort) = at_most.characterise(&input);
+        let Ok(exactly_sort) = exactly.characterise(&input);
+        let Ok(at_least_sort) = at_least.characterise(&input);
+        assert_eq!([at_most_sort, exactly_sort, at_least_sort].map(bool::from), expected, "{:?}", word);
+    }
+}
+
+#[test]
+fn occurrence_counts_saturate_past_the_threshold() {
+    let at_most: AtMostKBlueprint<char> = AtMostKBlueprint::new('x', 2);
+    let Ok(path) = at_most.state_path(&str_to_vec_char("xxxxx"));
+    assert_eq!(path, vec![0, 1, 2, 3, 3, 3]);
+
+    let at_least: AtLeastKBlueprint<char> = AtLeastKBlueprint::new('x', 2);
+    let Ok(path) = at_least.state_path(&str_to_vec_char("xxxx"));
+    assert_eq!(path, vec![0, 1, 2, 2, 2]);
+
+    let none: ExactlyKBlueprint<char> = ExactlyKBlueprint::new('x', 0);
+    let Ok(sort) = none.characterise(&str_to_vec_char("yy"));
+    assert_eq!(sort, BasicStateSort::Accept);
+}
+
+#[test]
+fn occurrence_bounds_compose_by_intersection() {
+    let at_most_one_a = AtMostKBlueprint::<char, String>::new('a', 1);
+    let counter = CounterAutomatonBlueprint::new('a', 'b');
+    let both = BasicIntersectionAutomatonBlueprint::new(&at_most_one_a, &counter);
+
+    assert_eq!(both.characterise(&str_to_vec_char("ab")), Ok(BasicStateSort::Accept));
+    assert_eq!(both.characterise(&str_to_vec_char("aabb")), Ok(BasicStateSort::Reject));
+}
+
+#[test]
+fn even_length_tracks_parity_only() {
+    let blueprint: EvenLengthBlueprint<char> = EvenLengthBlueprint::new();
+
+    let Ok(sort) = blueprint.characterise(&str_to_vec_char(""));
+    assert_eq!(sort, BasicStateSort::Accept);
+    let Ok(sort) = blueprint.characterise(&str_to_vec_char("xyz"));
+    assert_eq!(sort, BasicStateSort::Reject);
+    let Ok(sort) = blueprint.characterise(&str_to_vec_char("xyzw"));
+    assert_eq!(sort, BasicStateSort::Accept);
+}
+
+#[test]
+fn even_length_intersects_with_counter() {
+    let counter = CounterAutomatonBlueprint::new('a', 'b');
+    let even = EvenLengthBlueprint::<char, String>::new();
+    let both = BasicIntersectionAutomatonBlueprint::new(&counter, &even);
+
+    // Every a^n b^n word has even length, so the intersection agrees with the counter
+    for word in ["", "ab", "aabb", "aaabbb", "a", "aab", "ba", "abab"] {
+        let word = str_to_vec_char(word);
+        assert_eq!(both.characterise(&word).unwrap(), counter.characterise(&word).unwrap());
+    }
+
+    // With a tolerance the parity constraint becomes decisive
+    let nearly_balanced = CounterAutomatonBlueprint::with_tolerance('a', 'b', 0..=1);
+    let both = BasicIntersectionAutomatonBlueprint::new(&nearly_balanced, &even);
+    assert_eq!(both.characterise(&str_to_vec_char("a")).unwrap(), BasicStateSort::Reject);
+    assert_eq!(both.characterise(&str_to_vec_char("aabb")).unwrap(), BasicStateSort::Accept);
+    assert_eq!(nearly_balanced.characterise(&str_to_vec_char("aab")).unwrap(), BasicStateSort::Accept);
+    assert_eq!(both.characterise(&str_to_vec_char("aab")).unwrap(), BasicStateSort::Reject);
+}
+
+#[test]
+fn contains_subsequence_allows_gaps_unlike_substring() {
+    let blueprint: ContainsSubsequenceBlueprint<char> = ContainsSubsequenceBlueprint::new(str_to_vec_char("ace"));
+
+    // Contiguous occurrence
+    let Ok(sort) = blueprint.characterise(&str_to_vec_char("xacex"));
+    assert_eq!(sort, BasicStateSort::Accept);
+    // Not a substring, but a subsequence
+    let Ok(sort) = blueprint.characterise(&str_to_vec_char("abcde"));
+    assert_eq!(sort, BasicStateSort::Accept);
+    // All symbols present but out of order
+    let Ok(sort) = blueprint.characterise(&str_to_vec_char("eca"));
+    assert_eq!(sort, BasicStateSort::Reject);
+    let Ok(sort) = blueprint.characterise(&str_to_vec_char("ac"));
+    assert_eq!(sort, BasicStateSort::Reject);
+}
+
+#[test]
+fn contains_subsequence_state_is_matched_prefix_length() {
+    let blueprint: ContainsSubsequenceBlueprint<char> = ContainsSubsequenceBlueprint::new(str_to_vec_char("ab"));
+
+    let Ok(path) = blueprint.state_path(&str_to_vec_char("xxab"));
+    assert_eq!(path, vec![0, 0, 0, 1, 2]);
+    // Matching saturates once the pattern is complete
+    let Ok(path) = blueprint.state_path(&str_to_vec_char("abab"));
+    assert_eq!(path.last(), Some(&2));
+
+    let empty: ContainsSubsequenceBlueprint<char> = ContainsSubsequenceBlueprint::new(vec![]);
+    let Ok(sort) = empty.characterise(&[]);
+    assert_eq!(sort, BasicStateSort::Accept);
+}
+
+#[test]
+fn length_mod_accepts_multiples_of_modulus() {
+    let blueprint: LengthModBlueprint<char> = LengthModBlueprint::new(3);
+
+    for (word, expected) in [("", BasicStateSort::Accept), ("ab", BasicStateSort::Reject), ("abc", BasicStateSort::Accept), ("abcd", BasicStateSort::Reject), ("abcdef", BasicStateSort::Accept)] {
+        let Ok(sort) = blueprint.characterise(&str_to_vec_char(word));
+        assert_eq!(sort, expected, "{word}");
+    }
+
+    // A modulus of two agrees with the even-length primitive
+    let mod_two: LengthModBlueprint<char> = LengthModBlueprint::new(2);
+    let even: EvenLengthBlueprint<char> = EvenLengthBlueprint::new();
+    for word in ["", "a", "ab", "abc"] {
+        assert_eq!(mod_two.characterise(&str_to_vec_char(word)), even.characterise(&str_to_vec_char(word)));
+    }
+}
+
+#[test]
+fn length_mod_intersects_with_counter() {
+    // Balanced a^n b^n words whose length is a multiple of four: n must be even
+    let counter = CounterAutomatonBlueprint::new('a', 'b');
+    let fours = LengthModBlueprint::<char, String>::new(4);
+    let both = BasicIntersectionAutomatonBlueprint::new(&counter, &fours);
+
+    assert_eq!(both.characterise(&str_to_vec_char("aabb")).unwrap(), BasicStateSort::Accept);
+    assert_eq!(both.characterise(&str_to_vec_char("ab")).unwrap(), BasicStateSort::Reject);
+    assert_eq!(both.characterise(&str_to_vec_char("aaabbb")).unwrap(), BasicStateSort::Reject);
+    assert_eq!(both.characterise(&str_to_vec_char("aaaabbbb")).unwrap(), BasicStateSort::Accept);
+}
+
+#[test]
+#[should_panic]
+fn length_mod_rejects_zero_modulus() {
+    let _: LengthModBlueprint<char> = LengthModBlueprint::new(0);
+}
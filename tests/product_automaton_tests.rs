@@ -1,6 +1,8 @@
 use deterministic_automata::*;
-use deterministic_automata::counter_automaton_example::CounterAutomatonBlueprint;
-use deterministic_automata::product_automaton::{ProductAutomatonBlueprint, BasicUnionAutomatonBlueprint, BasicIntersectionAutomatonBlueprint};
+use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterState};
+use deterministic_automata::product_automaton::{ProductAutomatonBlueprint, BasicUnionAutomatonBlueprint, BasicIntersectionAutomatonBlueprint, LangExpr};
+use deterministic_automata::product_automaton::{FallbackBlueprint, FallbackState, OwnedBasicIntersectionAutomatonBlueprint, OwnedBasicUnionAutomatonBlueprint, OwnedProductAutomatonBlueprint, ShuffleBlueprint, Lattice, LatticeJoinProductBlueprint, LatticeMeetProductBlueprint};
+use deterministic_automata::operations::complement;
 
 fn str_to_vec_char(s: &str) -> Vec<char> {
     s.chars().collect()
@@ -155,4 +157,410 @@ fn union_vs_intersection_comparison() -> Result<(), String> {
     }
     
     Ok(())
-}
\ No newline at end of file
+}
+
+#[test]
+fn product_automaton_split_partial_reset() -> Result<(), String> {
+    let blueprint_a = CounterAutomatonBlueprint::new('a', 'b');
+    let blueprint_b = CounterAutomatonBlueprint::new('a', 'b');
+    let product = ProductAutomatonBlueprint::new(&blueprint_a, &blueprint_b);
+    let mut automaton = product.automaton();
+
+    let checkpoint = automaton.split().1.clone();
+    for character in str_to_vec_char("aab") {
+        automaton.update_state(&character)?;
+    }
+
+    *automaton.split().1 = checkpoint;
+    let (first, second) = automaton.split();
+    assert!(matches!(first, CounterState::End(1)));
+    assert!(matches!(second, CounterState::Start(0)));
+
+    automaton.update_state(&'b')?;
+    assert_eq!(automaton.current_state_sort()?, (BasicStateSort::Accept, BasicStateSort::Reject));
+
+    Ok(())
+}
+
+#[test]
+fn lang_expr_matches_nested_combinators() -> Result<(), String> {
+    let ab = CounterAutomatonBlueprint::new('a', 'b');
+    let ac = CounterAutomatonBlueprint::new('a', 'c');
+    let bc = CounterAutomatonBlueprint::new('b', 'c');
+
+    let expression = LangExpr::atom(&ab).and(&ac).or(&bc).not().build();
+
+    let intersection = BasicIntersectionAutomatonBlueprint::new(&ab, &ac);
+    let union = BasicUnionAutomatonBlueprint::new(&intersection, &bc);
+    let nested = complement(&union);
+
+    for word in ["", "ab", "ac", "bc", "aabb", "abc", "ba"] {
+        let input = str_to_vec_char(word);
+        assert_eq!(expression.characterise(&input)?, nested.characterise(&input)?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn lang_expr_combines_sub_expressions() -> Result<(), String> {
+    let ab = CounterAutomatonBlueprint::new('a', 'b');
+    let ba = CounterAutomatonBlueprint::new('b', 'a');
+
+    // ¬(ab ∨ ba) built by De Morgan as ¬ab ∧ ¬ba
+    let expression = LangExpr::atom(&ab).not().and_expr(LangExpr::atom(&ba).not()).build();
+    let direct = LangExpr::atom(&ab).or(&ba).not().build();
+
+    for word in ["", "ab", "ba", "aabb", "bbaa", "abab", "a"] {
+        let input = str_to_vec_char(word);
+        assert_eq!(expression.characterise(&input)?, direct.characterise(&input)?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn product_automaton_reduce_sort_custom_logic() -> Result<(), String> {
+    let ab = CounterAutomatonBlueprint::new('a', 'b');
+    let ba = CounterAutomatonBlueprint::new('b', 'a');
+    let exactly_one = ProductAutomatonBlueprint::new(&ab, &ba).reduce_sort(|x, y| match (x, y) {
+        (BasicStateSort::Accept, BasicStateSort::Reject) | (BasicStateSort::Reject, BasicStateSort::Accept) => BasicStateSort::Accept,
+        _ => BasicStateSort::Reject,
+    });
+
+    assert_eq!(exactly_one.characterise(&str_to_vec_char(""))?, BasicStateSort::Reject);
+    assert_eq!(exactly_one.characterise(&str_to_vec_char("ab"))?, BasicStateSort::Accept);
+    assert_eq!(exactly_one.characterise(&str_to_vec_char("ba"))?, BasicStateSort::Accept);
+    assert_eq!(exactly_one.characterise(&str_to_vec_char("abc"))?, BasicStateSort::Reject);
+
+    Ok(())
+}
+
+#[test]
+fn product_combinators_format_nested_structure() {
+    let ab = CounterAutomatonBlueprint::new('a', 'b');
+    let ba = CounterAutomatonBlueprint::new('b', 'a');
+    let not_ba = complement(&ba);
+    let union = BasicUnionAutomatonBlueprint::new(&ab, &not_ba);
+    let intersection = BasicIntersectionAutomatonBlueprint::new(&union, &ab);
+
+    let formatted = format!("{:?}", intersection);
+    assert!(formatted.starts_with("BasicIntersectionAutomatonBlueprint"));
+    assert!(formatted.contains("BasicUnionAutomatonBlueprint"));
+    assert!(formatted.contains("ComplementAutomatonBlueprint"));
+    assert!(formatted.contains("first: 'b', second: 'a'"));
+
+    let reduced = ProductAutomatonBlueprint::new(&ab, &ba).reduce_sort(|x, _| x);
+    let formatted = format!("{:?}", reduced);
+    assert!(formatted.starts_with("ReducedProductAutomatonBlueprint { first: CounterAutomatonBlueprint"));
+    assert!(formatted.ends_with(", .. }"));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Confidence {
+    Low,
+    Medium,
+    High,
+}
+
+impl Lattice for Confidence {
+    fn meet(self, other: Self) -> Self {
+        self.min(other)
+    }
+
+    fn join(self, other: Self) -> Self {
+        self.max(other)
+    }
+
+    fn bottom() -> Self {
+        Confidence::Low
+    }
+
+    fn top() -> Self {
+        Confidence::High
+    }
+}
+
+/// Grows more confident with each occurrence of its marker symbol
+struct MarkerConfidence {
+    marker: char,
+}
+
+impl DeterministicAutomatonBlueprint for MarkerConfidence {
+    type State = usize;
+    type Alphabet = char;
+    type StateSort = Confidence;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        0
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(match state {
+            0 => Confidence::Low,
+            1 => Confidence::Medium,
+            _ => Confidence::High,
+        })
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        Ok(if *character == self.marker { (state + 1).min(2) } else { *state })
+    }
+}
+
+#[test]
+fn lattice_products_combine_three_level_confidence() -> Result<(), String> {
+    let exclamations = MarkerConfidence { marker: '!' };
+    let questions = MarkerConfidence { marker: '?' };
+    let join = LatticeJoinProductBlueprint::new(&exclamations, &questions);
+    let meet = LatticeMeetProductBlueprint::new(&exclamations, &questions);
+
+    assert_eq!(join.characterise(&[])?, Confidence::bottom());
+    assert_eq!(meet.characterise(&[])?, Confidence::Low);
+    assert_eq!(join.characterise(&['!', '!', '?'])?, Confidence::High);
+    assert_eq!(meet.characterise(&['!', '!', '?'])?, Confidence::Medium);
+    assert_eq!(meet.characterise(&['!', '!', '?', '?'])?, Confidence::top());
+
+    Ok(())
+}
+
+#[test]
+fn lattice_products_agree_with_boolean_products() -> Result<(), String> {
+    let ab = CounterAutomatonBlueprint::new('a', 'b');
+    let ac = CounterAutomatonBlueprint::new('a', 'c');
+    let (join, union) = (LatticeJoinProductBlueprint::new(&ab, &ac), BasicUnionAutomatonBlueprint::new(&ab, &ac));
+    let (meet, intersection) = (LatticeMeetProductBlueprint::new(&ab, &ac), BasicIntersectionAutomatonBlueprint::new(&ab, &ac));
+
+    for word in ["", "ab", "ac", "aab", "ba"] {
+        let input: Vec<char> = word.chars().collect();
+        assert_eq!(join.characterise(&input)?, union.characterise(&input)?);
+        assert_eq!(meet.characterise(&input)?, intersection.characterise(&input)?);
+    }
+
+    assert_eq!(BasicStateSort::bottom().join(BasicStateSort::top()), BasicStateSort::Accept);
+    assert_eq!(BasicStateSort::bottom().meet(BasicStateSort::top()), BasicStateSort::Reject);
+
+    Ok(())
+}
+
+/// Counts symbols up to a limit, failing on the symbol that would exceed it.
+struct BoundedLength {
+    name: &'static str,
+    limit: usize,
+    accept_even: bool
+}
+
+impl DeterministicAutomatonBlueprint for BoundedLength {
+    type State = usize;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        0
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(BasicStateSort::from((state % 2 == 0) == self.accept_even))
+    }
+
+    fn transition_map(&self, state: &Self::State, _character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        if *state < self.limit { Ok(state + 1) } else { Err(format!("{} exceeded {}", self.name, self.limit)) }
+    }
+}
+
+#[test]
+fn fallback_uses_primary_verdict_until_it_errors() {
+    let strict = BoundedLength { name: "strict", limit: 2, accept_even: true };
+    let lenient = BoundedLength { name: "lenient", limit: 10, accept_even: false };
+    let resilient = FallbackBlueprint::new(&strict, &lenient);
+
+    // Primary healthy: its verdict wins even though the fallback disagrees
+    assert_eq!(resilient.characterise(&str_to_vec_char("ab")).unwrap(), BasicStateSort::Accept);
+    assert_eq!(resilient.characterise(&str_to_vec_char("a")).unwrap(), BasicStateSort::Reject);
+
+    // Primary fails on the third symbol; the fallback has tracked all four
+    let mut automaton = resilient.automaton();
+    for character in str_to_vec_char("abcd") {
+        automaton.update_state(&character).unwrap();
+    }
+    assert_eq!(automaton.view_state(), &FallbackState::Fallback(4));
+    assert_eq!(automaton.current_state_sort().unwrap(), BasicStateSort::Reject);
+}
+
+#[test]
+fn fallback_errors_only_when_both_components_fail() {
+    let short = BoundedLength { name: "short", limit: 1, accept_even: true };
+    let also_short = BoundedLength { name: "also short", limit: 1, accept_even: true };
+    let long = BoundedLength { name: "long", limit: 2, accept_even: true };
+
+    // Fallback fails after the primary
+    let resilient = FallbackBlueprint::new(&short, &long);
+    assert_eq!(resilient.characterise(&str_to_vec_char("ab")).unwrap(), BasicStateSort::Accept);
+    assert_eq!(resilient.characterise(&str_to_vec_char("abc")), Err("long exceeded 2".to_string()));
+
+    // Both fail on the same symbol: the fallback's error is reported
+    let same = FallbackBlueprint::new(&short, &also_short);
+    assert_eq!(same.characterise(&str_to_vec_char("ab")), Err("also short exceeded 1".to_string()));
+
+    // Fallback fails first and is dropped; the primary's later error is final
+    let reversed = FallbackBlueprint::new(&long, &short);
+    let mut automaton = reversed.automaton();
+    automaton.update_state(&'a').unwrap();
+    automaton.update_state(&'b').unwrap();
+    assert_eq!(automaton.view_state(), &FallbackState::Primary(2, None));
+    assert_eq!(automaton.update_state(&'c'), Err("long exceeded 2".to_string()));
+}
+
+#[test]
+fn shuffle_accepts_interleavings_of_both_languages() {
+    let ab = CounterAutomatonBlueprint::new('a', 'b');
+    let cd = CounterAutomatonBlueprint::new('c', 'd');
+    let shuffle = ShuffleBlueprint::new(&ab, &cd);
+
+    for word in ["", "ab", "cd", "abcd", "cdab", "acbd", "cabd", "aacbbd"] {
+        assert_eq!(shuffle.characterise(&str_to_vec_char(word)).unwrap(), BasicStateSort::Accept, "{word}");
+    }
+    for word in ["a", "abdc", "acdbb", "ba"] {
+        assert_eq!(shuffle.characterise(&str_to_vec_char(word)).unwrap(), BasicStateSort::Reject, "{word}");
+    }
+}
+
+#[test]
+fn shuffle_state_stays_bounded_for_finite_components() {
+    use deterministic_automata::mod_counter_example::ModSumBlueprint;
+
+    let even = ModSumBlueprint::new(2);
+    let triple = ModSumBlueprint::new(3);
+    let shuffle = ShuffleBlueprint::new(&even, &triple);
+
+    let state = shuffle.state_path(&[1; 20]).unwrap().pop().unwrap();
+    assert!(state.len() <= 6);
+
+    // 2 + 3: split as {1, 1} and {1, 1, 1}
+    assert_eq!(shuffle.characterise(&[1; 5]).unwrap(), BasicStateSort::Accept);
+    assert_eq!(shuffle.characterise(&[1]).unwrap(), BasicStateSort::Reject);
+}
+
+/// A counter automaton that fails on symbols other than its own two.
+struct StrictCounter {
+    inner: CounterAutomatonBlueprint<char>,
+    symbols: [char; 2]
+}
+
+impl StrictCounter {
+    fn new(first: char, second: char) -> Self {
+        Self { inner: CounterAutomatonBlueprint::new(first, second), symbols: [first, second] }
+    }
+}
+
+impl DeterministicAutomatonBlueprint for StrictCounter {
+    type State = CounterState;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        self.inner.initial_state()
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        self.inner.state_sort_map(state)
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        if !self.symbols.contains(character) {
+            return Err(format!("Foreign symbol {}", character));
+        }
+        self.inner.transition_map(state, character)
+    }
+}
+
+#[test]
+fn shuffle_drops_branches_that_error_on_foreign_symbols() {
+    let ab = StrictCounter::new('a', 'b');
+    let cd = StrictCounter::new('c', 'd');
+    let shuffle = ShuffleBlueprint::new(&ab, &cd);
+
+    assert_eq!(shuffle.characterise(&str_to_vec_char("acbd")), Ok(BasicStateSort::Accept));
+    assert_eq!(shuffle.characterise(&str_to_vec_char("cadb")), Ok(BasicStateSort::Accept));
+    assert_eq!(shuffle.characterise(&str_to_vec_char("acb")), Ok(BasicStateSort::Reject));
+
+    // Only a symbol foreign to both components fails every branch
+    assert_eq!(shuffle.characterise(&str_to_vec_char("acx")), Err("Foreign symbol x".to_string()));
+}
+
+#[test]
+fn shuffle_states_compare_as_sets() {
+    let ab = CounterAutomatonBlueprint::new('a', 'b');
+    let cd = CounterAutomatonBlueprint::new('c', 'd');
+    let shuffle = ShuffleBlueprint::new(&ab, &cd);
+
+    let ac = shuffle.state_path(&str_to_vec_char("ac")).unwrap().pop().unwrap();
+    let ca = shuffle.state_path(&str_to_vec_char("ca")).unwrap().pop().unwrap();
+    assert_eq!(ac, ca);
+}
+
+#[test]
+fn shuffle_example_concatenates_component_examples() {
+    let ab = CounterAutomatonBlueprint::new('a', 'b');
+    let cd = CounterAutomatonBlueprint::new('c', 'd');
+    let shuffle = ShuffleBlueprint::new(&ab, &cd);
+
+    let example = shuffle.example_accepted().unwrap();
+    assert_eq!(shuffle.characterise(&example).unwrap(), BasicStateSort::Accept);
+}
+
+type OwnedCounterIntersection = OwnedBasicIntersectionAutomatonBlueprint<CounterAutomatonBlueprint<char>, CounterAutomatonBlueprint<char>, char, String>;
+
+/// Builds the intersection from components that do not outlive the call.
+fn balanced_in_both(first: (char, char), second: (char, char)) -> OwnedCounterIntersection {
+    let a = CounterAutomatonBlueprint::new(first.0, first.1);
+    let b = CounterAutomatonBlueprint::new(second.0, second.1);
+    OwnedBasicIntersectionAutomatonBlueprint::new(a, b)
+}
+
+#[test]
+fn owned_intersection_returned_from_factory() {
+    let both = balanced_in_both(('a', 'b'), ('a', 'b'));
+    assert_eq!(both.characterise(&str_to_vec_char("aabb")).unwrap(), BasicStateSort::Accept);
+
+    let ab = CounterAutomatonBlueprint::new('a', 'b');
+    let ac = CounterAutomatonBlueprint::new('a', 'c');
+    let borrowed = BasicIntersectionAutomatonBlueprint::new(&ab, &ac);
+    let owned = balanced_in_both(('a', 'b'), ('a', 'c'));
+    for word in ["", "a", "ab", "ac", "abc"] {
+        let word = str_to_vec_char(word);
+        assert_eq!(owned.characterise(&word).unwrap(), borrowed.characterise(&word).unwrap());
+    }
+
+    let (first, second) = owned.into_parts();
+    assert_eq!((first, second), (ab, ac));
+}
+
+#[test]
+fn owned_union_and_product_match_borrowed_versions() {
+    let ab = CounterAutomatonBlueprint::new('a', 'b');
+    let ac = CounterAutomatonBlueprint::new('a', 'c');
+    let owned_union = OwnedBasicUnionAutomatonBlueprint::new(ab.clone(), ac.clone());
+    let owned_product = OwnedProductAutomatonBlueprint::new(ab.clone(), ac.clone());
+    let union = BasicUnionAutomatonBlueprint::new(&ab, &ac);
+    let product = ProductAutomatonBlueprint::new(&ab, &ac);
+
+    for word in ["", "a", "ab", "ac", "abc", "aacc"] {
+        let word = str_to_vec_char(word);
+        assert_eq!(owned_union.characterise(&word).unwrap(), union.characterise(&word).unwrap());
+        assert_eq!(owned_product.characterise(&word).unwrap(), product.characterise(&word).unwrap());
+    }
+}
+
+struct Recognizers {
+    strict: OwnedCounterIntersection
+}
+
+#[test]
+fn owned_blueprint_stored_in_struct() {
+    let recognizers = Recognizers { strict: balanced_in_both(('x', 'y'), ('x', 'y')) };
+    assert_eq!(recognizers.strict.characterise(&str_to_vec_char("xy")).unwrap(), BasicStateSort::Accept);
+}
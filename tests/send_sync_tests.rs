@@ -0,0 +1,69 @@
+use deterministic_automata::*;
+use deterministic_automata::counter_automaton_example::{CounterAutomatonBlueprint, CounterState};
+use deterministic_automata::product_automaton::{
+    ProductAutomatonBlueprint, BasicUnionAutomatonBlueprint, BasicIntersectionAutomatonBlueprint,
+    MutationProductAutomatonBlueprint, MutationBasicUnionAutomatonBlueprint, MutationBasicIntersectionAutomatonBlueprint,
+};
+use deterministic_automata::either_automaton::{deterministic, mutation};
+use deterministic_automata::mutation_automaton::Deterministicize;
+use deterministic_automata::operations::{ComplementAutomatonBlueprint, WithInitialBlueprint, MutationWithInitialBlueprint};
+
+fn assert_send<T: Send>() {}
+fn assert_sync<T: Sync>() {}
+
+fn assert_send_sync<T: Send + Sync>() {
+    assert_send::<T>();
+    assert_sync::<T>();
+}
+
+type Counter = CounterAutomatonBlueprint<char>;
+
+#[test]
+fn counter_automaton_is_send_sync() {
+    assert_send_sync::<Counter>();
+    assert_send_sync::<CounterState>();
+}
+
+#[test]
+fn product_blueprints_are_send_sync() {
+    assert_send_sync::<ProductAutomatonBlueprint<'static, 'static, Counter, Counter, char, String>>();
+    assert_send_sync::<BasicUnionAutomatonBlueprint<'static, 'static, Counter, Counter, char, String>>();
+    assert_send_sync::<BasicIntersectionAutomatonBlueprint<'static, 'static, Counter, Counter, char, String>>();
+    assert_send_sync::<MutationProductAutomatonBlueprint<'static, 'static, Counter, Counter, char, String>>();
+    assert_send_sync::<MutationBasicUnionAutomatonBlueprint<'static, 'static, Counter, Counter, char, String>>();
+    assert_send_sync::<MutationBasicIntersectionAutomatonBlueprint<'static, 'static, Counter, Counter, char, String>>();
+}
+
+#[test]
+fn adapter_blueprints_are_send_sync() {
+    assert_send_sync::<deterministic::Either<Counter, Counter>>();
+    assert_send_sync::<mutation::Either<Counter, Counter>>();
+    assert_send_sync::<Deterministicize<'static, Counter>>();
+    assert_send_sync::<ComplementAutomatonBlueprint<'static, Counter>>();
+    assert_send_sync::<WithInitialBlueprint<'static, Counter>>();
+    assert_send_sync::<MutationWithInitialBlueprint<'static, Counter>>();
+}
+
+#[test]
+fn runtime_automata_are_send_sync() {
+    assert_send_sync::<DeterministicAutomaton<'static, Counter>>();
+    assert_send_sync::<MutationAutomaton<'static, Counter>>();
+}
+
+#[test]
+fn runtime_automaton_moves_across_threads() -> Result<(), String> {
+    let blueprint = CounterAutomatonBlueprint::new('a', 'b');
+    let mut automaton = blueprint.automaton();
+    automaton.update_state(&'a')?;
+
+    let sort = std::thread::scope(|scope| {
+        scope.spawn(move || {
+            automaton.update_state(&'b')?;
+            automaton.current_state_sort()
+        }).join().unwrap()
+    })?;
+
+    assert_eq!(sort, BasicStateSort::Accept);
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+#![cfg(feature = "testing")]
+
+use std::cell::Cell;
+
+use deterministic_automata::*;
+use deterministic_automata::recognizes;
+use deterministic_automata::testing::{assert_deterministic, assert_mutation_deterministic, check_against_oracle, WordGenerator};
+
+struct ParityBlueprint;
+
+impl DeterministicAutomatonBlueprint for ParityBlueprint {
+    type State = bool;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        true
+    }
+
+    fn state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(if *state { BasicStateSort::Accept } else { BasicStateSort::Reject })
+    }
+
+    fn transition_map(&self, state: &Self::State, character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        match character {
+            'a' => Ok(!*state),
+            'b' => Ok(*state),
+            _ => Err(format!("Invalid character: {}", character)),
+        }
+    }
+}
+
+/// Flips its answer on every call, violating determinism.
+struct FlakyBlueprint {
+    calls: Cell<usize>
+}
+
+impl DeterministicAutomatonBlueprint for FlakyBlueprint {
+    type State = usize;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+    type ErrorType = String;
+
+    fn initial_state(&self) -> Self::State {
+        0
+    }
+
+    fn state_sort_map(&self, _state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(BasicStateSort::Accept)
+    }
+
+    fn transition_map(&self, state: &Self::State, _character: &Self::Alphabet) -> Result<Self::State, Self::ErrorType> {
+        self.calls.set(self.calls.get() + 1);
+        Ok(state + self.calls.get())
+    }
+}
+
+#[test]
+fn assert_deterministic_accepts_deterministic_blueprint() {
+    let blueprint = ParityBlueprint;
+    let mut generator = WordGenerator::new(7);
+
+    for _ in 0..50 {
+        let word = generator.next_word(&['a', 'b'], 10);
+        let state = blueprint.characterise_partial(&word).unwrap().take_state();
+        for symbol in ['a', 'b', 'c'] {
+            assert_deterministic(&blueprint, &state, &symbol);
+            assert_mutation_deterministic(&blueprint, &state, &symbol);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn assert_deterministic_rejects_nondeterministic_blueprint() {
+    let blueprint = FlakyBlueprint { calls: Cell::new(0) };
+    assert_deterministic(&blueprint, &0, &'a');
+}
+
+#[test]
+fn word_generator_is_reproducible_and_bounded() {
+    let mut first = WordGenerator::new(123);
+    let mut second = WordGenerator::new(123);
+
+    for _ in 0..50 {
+        let word = first.next_word(&['x', 'y', 'z'], 5);
+        assert!(word.len() <= 5);
+        assert!(word.iter().all(|symbol| ['x', 'y', 'z'].contains(symbol)));
+        assert_eq!(word, second.next_word(&['x', 'y', 'z'], 5));
+    }
+
+    assert!(WordGenerator::new(0).next_word::<char>(&[], 5).is_empty());
+}
+
+#[test]
+fn check_against_oracle_agrees_with_correct_predicate() -> Result<(), String> {
+    let blueprint = ParityBlueprint;
+    let mut generator = WordGenerator::new(99);
+    let words: Vec<Vec<char>> = (0..100).map(|_| generator.next_word(&['a', 'b'], 12)).collect();
+
+    let even_as = |word: &[char]| word.iter().filter(|symbol| **symbol == 'a').count() % 2 == 0;
+    assert!(check_against_oracle(&blueprint, even_as, &words)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn check_against_oracle_reports_mismatches_in_order() -> Result<(), String> {
+    let blueprint = ParityBlueprint;
+    let words = vec![vec![], vec!['a'], vec!['b'], vec!['a', 'a']];
+
+    // Accepts everything, so disagrees exactly on the odd words
+    assert_eq!(check_against_oracle(&blueprint, |_| true, &words)?, vec![vec!['a']]);
+    assert!(check_against_oracle(&blueprint, |_| true, &[vec!['c']]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn recognizes_checks_char_words() {
+    recognizes!(ParityBlueprint, {
+        "" => Accept,
+        "a" => Reject,
+        "abab" => Accept,
+        "bbb" => Accept,
+    });
+}
+
+#[test]
+fn recognizes_checks_converted_words() {
+    recognizes!(ParityBlueprint, |count: usize| vec!['a'; count], {
+        0 => Accept,
+        3 => Reject,
+        4 => BasicStateSort::Accept,
+    });
+}
+
+#[test]
+#[should_panic(expected = "was not classified as Accept")]
+fn recognizes_panics_on_mismatch() {
+    recognizes!(ParityBlueprint, { "a" => Accept });
+}
+
+#[test]
+#[should_panic(expected = "characterising \"c\" failed: \"Invalid character: c\"")]
+fn recognizes_panics_on_error() {
+    recognizes!(ParityBlueprint, { "c" => Reject });
+}
+
+#[test]
+fn recognizes_evaluates_each_word_once() {
+    let evaluations = std::cell::Cell::new(0);
+    let word = |count: usize| {
+        evaluations.set(evaluations.get() + 1);
+        count
+    };
+
+    recognizes!(ParityBlueprint, |count: usize| vec!['a'; count], {
+        word(2) => Accept,
+        word(1) => Reject,
+    });
+    assert_eq!(evaluations.get(), 2);
+}
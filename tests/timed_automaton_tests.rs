@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use deterministic_automata::*;
+
+// Accepts keystroke sequences where no gap between keys exceeds the timeout; a late key
+// resets the sequence, so only the keys after the last timeout count
+struct Debounce {
+    timeout: Duration,
+    required: usize,
+}
+
+impl TimedAutomatonBlueprint for Debounce {
+    type State = usize;
+    type Alphabet = char;
+    type StateSort = BasicStateSort;
+    type ErrorType = String;
+
+    fn initial_timed_state(&self) -> Self::State {
+        0
+    }
+
+    fn timed_state_sort_map(&self, state: &Self::State) -> Result<Self::StateSort, Self::ErrorType> {
+        Ok(BasicStateSort::from(*state >= self.required))
+    }
+
+    fn transition_timed(&self, state: &Self::State, character: &Self::Alphabet, elapsed: Duration) -> Result<Self::State, Self::ErrorType> {
+        if !character.is_ascii_alphabetic() {
+            return Err(format!("Not a key: {}", character));
+        }
+        Ok(if elapsed > self.timeout { 1 } else { state + 1 })
+    }
+}
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+#[test]
+fn timed_characterise_applies_timeouts() {
+    let blueprint = Debounce { timeout: ms(100), required: 3 };
+
+    assert_eq!(blueprint.characterise_timed(&[]), Ok(BasicStateSort::Reject));
+    assert_eq!(blueprint.characterise_timed(&[('a', ms(0)), ('b', ms(50)), ('c', ms(100))]), Ok(BasicStateSort::Accept));
+    assert_eq!(blueprint.characterise_timed(&[('a', ms(0)), ('b', ms(50)), ('c', ms(101))]), Ok(BasicStateSort::Reject));
+    assert_eq!(blueprint.characterise_timed(&[('a', ms(0)), ('1', ms(10))]), Err("Not a key: 1".to_string()));
+}
+
+#[test]
+fn timed_automaton_steps_with_elapsed_time() -> Result<(), String> {
+    let blueprint = Debounce { timeout: ms(100), required: 2 };
+    let mut automaton = blueprint.timed_automaton();
+
+    assert_eq!(automaton.update_sort_state(&'a', ms(0))?, BasicStateSort::Reject);
+    assert_eq!(automaton.update_sort_state(&'b', ms(500))?, BasicStateSort::Reject);
+    assert_eq!(*automaton.view_state(), 1);
+    assert_eq!(automaton.update_sort_state(&'c', ms(20))?, BasicStateSort::Accept);
+
+    automaton.reset();
+    assert_eq!(automaton.current_state_sort()?, BasicStateSort::Reject);
+    automaton.update_state(&'d', ms(0))?;
+    assert_eq!(automaton.take_state(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn timed_automaton_clone_forks_independently() -> Result<(), String> {
+    let blueprint = Debounce { timeout: ms(100), required: 2 };
+    let mut automaton = blueprint.timed_automaton();
+    automaton.update_state(&'a', ms(0))?;
+
+    let mut fork = automaton.clone();
+    assert_eq!(fork.update_sort_state(&'b', ms(10))?, BasicStateSort::Accept);
+    assert_eq!(*automaton.view_state(), 1);
+    assert_eq!(format!("{:?}", automaton), "TimedAutomaton { current_state: 1, .. }");
+
+    Ok(())
+}
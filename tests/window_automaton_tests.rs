@@ -0,0 +1,46 @@
+use std::collections::VecDeque;
+
+use deterministic_automata::*;
+use deterministic_automata::window_automaton::WindowBlueprint;
+
+fn str_to_vec_char(s: &str) -> Vec<char> {
+    s.chars().collect()
+}
+
+fn ends_with(pattern: &'static str) -> WindowBlueprint<char, impl Fn(&VecDeque<char>) -> BasicStateSort> {
+    WindowBlueprint::new(pattern.chars().count(), move |window: &VecDeque<char>| {
+        if window.iter().copied().eq(pattern.chars()) { BasicStateSort::Accept } else { BasicStateSort::Reject }
+    })
+}
+
+#[test]
+fn window_blueprint_recognizes_suffix() {
+    let blueprint = ends_with("ab");
+
+    assert_eq!(blueprint.infallible_characterise(&str_to_vec_char("")), BasicStateSort::Reject);
+    assert_eq!(blueprint.infallible_characterise(&str_to_vec_char("ab")), BasicStateSort::Accept);
+    assert_eq!(blueprint.infallible_characterise(&str_to_vec_char("cab")), BasicStateSort::Accept);
+    assert_eq!(blueprint.infallible_characterise(&str_to_vec_char("abc")), BasicStateSort::Reject);
+    assert_eq!(blueprint.characterise(&str_to_vec_char("aab")), Ok(BasicStateSort::Accept));
+}
+
+#[test]
+fn window_blueprint_keeps_only_last_symbols() {
+    let blueprint = ends_with("abc");
+    let mut automaton = blueprint.automaton();
+
+    for character in str_to_vec_char("xyzabc") {
+        automaton.update_state(&character).unwrap();
+        assert!(automaton.view_state().len() <= 3);
+    }
+    assert_eq!(automaton.view_state().iter().collect::<String>(), "abc");
+}
+
+#[test]
+fn window_blueprint_zero_length_window_stays_empty() {
+    let blueprint = WindowBlueprint::new(0, |window: &VecDeque<char>| {
+        if window.is_empty() { BasicStateSort::Accept } else { BasicStateSort::Reject }
+    });
+
+    assert_eq!(blueprint.infallible_characterise(&str_to_vec_char("abc")), BasicStateSort::Accept);
+}